@@ -86,6 +86,11 @@ const statusConfig: Record<
     variant: "secondary",
     icon: ClockIcon,
   },
+  [AgreementStatus.PendingCoSignerSign]: {
+    label: "Awaiting Co-signer Signature",
+    variant: "secondary",
+    icon: ClockIcon,
+  },
   [AgreementStatus.PendingPayment]: {
     label: "Awaiting Payment",
     variant: "secondary",
@@ -0,0 +1,525 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error, Address,
+    BytesN, Env, String, Symbol, Vec,
+};
+
+// -----------------------------
+// Cross-contract: RentalAgreement
+// -----------------------------
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AgreementStatus {
+    PendingLandlordApproval,
+    Rejected,
+    Draft,
+    PendingTenantSign,
+    PendingLandlordSign,
+    PendingCoSignerSign,
+    PendingPayment,
+    Active,
+    Completed,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentalAgreement {
+    pub id: BytesN<32>,
+    pub property_id: BytesN<32>,
+    pub landlord: Address,
+    pub tenant: Address,
+    pub monthly_rent: i128,
+    pub security_deposit: i128,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub status: AgreementStatus,
+    pub landlord_signed: bool,
+    pub landlord_signed_at: u64,
+    pub tenant_signed: bool,
+    pub tenant_signed_at: u64,
+    pub deposit_paid: bool,
+    pub deposit_paid_at: u64,
+    pub total_rent_paid: i128,
+    pub months_paid: u32,
+    pub created_at: u64,
+    pub completed_at: u64,
+    pub notice_period_days: u32,
+    pub notice_served_at: u64,
+    pub notice_served_by: Option<Address>,
+    pub document_hash: BytesN<32>,
+    pub co_signer: Option<Address>,
+    pub subletting_allowed: bool,
+    pub custom_clauses: Vec<String>,
+}
+
+#[contractclient(name = "RentalAgreementClient")]
+pub trait RentalAgreementContract {
+    fn get_agreement(agreement_id: BytesN<32>) -> RentalAgreement;
+}
+
+// -----------------------------
+// Cross-contract: EscrowManager
+// -----------------------------
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowAccount {
+    pub agreement_id: BytesN<32>,
+    pub landlord: Address,
+    pub tenant: Address,
+    pub security_deposit_amount: i128,
+    pub security_deposit_held: i128,
+    pub monthly_rent_amount: i128,
+    pub total_rent_received: i128,
+    pub total_rent_released: i128,
+    pub is_deposit_released: bool,
+    pub deposit_released_at: u64,
+    pub created_at: u64,
+}
+
+#[contractclient(name = "EscrowManagerClient")]
+pub trait EscrowManagerContract {
+    fn get_escrow(agreement_id: BytesN<32>) -> EscrowAccount;
+}
+
+// -----------------------------
+// Analytics contract
+// -----------------------------
+
+const MAX_TOP_LANDLORDS: u32 = 50;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    ContractPaused = 1,
+    Unauthorized = 2,
+    AlreadyInitialized = 3,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformSummary {
+    pub total_agreements: u32,
+    pub total_active_agreements: u32,
+    pub total_rent_collected: i128,
+    pub total_deposits_held: i128,
+    pub last_synced_at: u64,
+}
+
+/// What an agreement last contributed to the platform counters, so a
+/// re-sync only applies the difference.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SyncedAgreement {
+    pub landlord: Address,
+    pub is_active: bool,
+    pub rent_paid: i128,
+    pub deposit_held: i128,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Paused,
+    AgreementContract,
+    EscrowContract,
+    TotalAgreements,
+    TotalActiveAgreements,
+    TotalRentCollected,
+    TotalDepositsHeld,
+    LastSyncedAt,
+    Synced(BytesN<32>),
+    LandlordRevenue(Address),
+    TopLandlords,
+}
+
+#[contract]
+pub struct Analytics;
+
+#[contractimpl]
+impl Analytics {
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        agreement_contract: Address,
+        escrow_contract: Address,
+    ) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.storage()
+            .instance()
+            .set(&DataKey::AgreementContract, &agreement_contract);
+        env.storage()
+            .instance()
+            .set(&DataKey::EscrowContract, &escrow_contract);
+
+        env.events().publish(
+            (Symbol::new(&env, "Initialized"),),
+            (
+                admin,
+                agreement_contract,
+                escrow_contract,
+                env.ledger().timestamp(),
+            ),
+        );
+    }
+
+    pub fn pause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.events()
+            .publish((Symbol::new(&env, "Paused"),), env.ledger().timestamp());
+    }
+
+    pub fn unpause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.events()
+            .publish((Symbol::new(&env, "Unpaused"),), env.ledger().timestamp());
+    }
+
+    // Soroban contracts can't subscribe to each other's events, so the admin
+    // re-syncs agreements after key events. Safe to call repeatedly.
+    pub fn sync_agreement(env: Env, agreement_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let agreement = Self::agreement_client(&env).get_agreement(&agreement_id);
+        let deposit_held = if agreement.deposit_paid {
+            Self::escrow_client(&env)
+                .get_escrow(&agreement_id)
+                .security_deposit_held
+        } else {
+            0
+        };
+        let current = SyncedAgreement {
+            landlord: agreement.landlord.clone(),
+            is_active: agreement.status == AgreementStatus::Active,
+            rent_paid: agreement.total_rent_paid,
+            deposit_held,
+        };
+
+        let key = DataKey::Synced(agreement_id.clone());
+        let previous: Option<SyncedAgreement> = env.storage().persistent().get(&key);
+        let (was_active, prev_rent, prev_deposit) = match &previous {
+            Some(p) => (p.is_active, p.rent_paid, p.deposit_held),
+            None => {
+                Self::add_u32(&env, DataKey::TotalAgreements, 1);
+                (false, 0, 0)
+            }
+        };
+
+        if current.is_active != was_active {
+            Self::add_u32(
+                &env,
+                DataKey::TotalActiveAgreements,
+                if current.is_active { 1 } else { -1 },
+            );
+        }
+
+        let rent_delta = current.rent_paid - prev_rent;
+        if rent_delta != 0 {
+            Self::add_i128(&env, DataKey::TotalRentCollected, rent_delta);
+            Self::add_landlord_revenue(&env, &current.landlord, rent_delta);
+        }
+        Self::add_i128(
+            &env,
+            DataKey::TotalDepositsHeld,
+            current.deposit_held - prev_deposit,
+        );
+
+        env.storage().persistent().set(&key, &current);
+        env.storage()
+            .instance()
+            .set(&DataKey::LastSyncedAt, &env.ledger().timestamp());
+
+        env.events().publish(
+            (Symbol::new(&env, "AgreementSynced"),),
+            (agreement_id, current.is_active, current.rent_paid),
+        );
+    }
+
+    pub fn get_platform_summary(env: Env) -> PlatformSummary {
+        let instance = env.storage().instance();
+        PlatformSummary {
+            total_agreements: instance.get(&DataKey::TotalAgreements).unwrap_or(0),
+            total_active_agreements: instance.get(&DataKey::TotalActiveAgreements).unwrap_or(0),
+            total_rent_collected: instance.get(&DataKey::TotalRentCollected).unwrap_or(0),
+            total_deposits_held: instance.get(&DataKey::TotalDepositsHeld).unwrap_or(0),
+            last_synced_at: instance.get(&DataKey::LastSyncedAt).unwrap_or(0),
+        }
+    }
+
+    // Top 50 landlords by synced rent, highest first.
+    pub fn get_top_landlords_by_revenue(env: Env, limit: u32) -> Vec<(Address, i128)> {
+        let top = Self::top_landlords(&env);
+        top.slice(0..limit.min(top.len()))
+    }
+
+    pub fn get_landlord_revenue(env: Env, landlord: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LandlordRevenue(landlord))
+            .unwrap_or(0)
+    }
+
+    // -----------------------------
+    // Internal helpers
+    // -----------------------------
+
+    // Keeps the leaderboard sorted by re-inserting the landlord at their new
+    // position, dropping whoever falls off the end.
+    fn add_landlord_revenue(env: &Env, landlord: &Address, delta: i128) {
+        let revenue = Self::get_landlord_revenue(env.clone(), landlord.clone()) + delta;
+        env.storage()
+            .persistent()
+            .set(&DataKey::LandlordRevenue(landlord.clone()), &revenue);
+
+        let mut top = Self::top_landlords(env);
+        if let Some(i) = top.iter().position(|(a, _)| a == *landlord) {
+            top.remove(i as u32);
+        }
+        let pos = top
+            .iter()
+            .position(|(_, r)| revenue > r)
+            .map(|i| i as u32)
+            .unwrap_or(top.len());
+        if pos < MAX_TOP_LANDLORDS {
+            top.insert(pos, (landlord.clone(), revenue));
+            if top.len() > MAX_TOP_LANDLORDS {
+                top.pop_back();
+            }
+        }
+        env.storage().persistent().set(&DataKey::TopLandlords, &top);
+    }
+
+    fn top_landlords(env: &Env) -> Vec<(Address, i128)> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TopLandlords)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn add_u32(env: &Env, key: DataKey, delta: i32) {
+        let current: u32 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&key, &current.saturating_add_signed(delta));
+    }
+
+    fn add_i128(env: &Env, key: DataKey, delta: i128) {
+        let current: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(current + delta));
+    }
+
+    fn agreement_client(env: &Env) -> RentalAgreementClient<'_> {
+        let addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::AgreementContract)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        RentalAgreementClient::new(env, &addr)
+    }
+
+    fn escrow_client(env: &Env) -> EscrowManagerClient<'_> {
+        let addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::EscrowContract)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        EscrowManagerClient::new(env, &addr)
+    }
+
+    fn check_not_paused(env: &Env) {
+        let paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        if paused {
+            panic_with_error!(env, Error::ContractPaused);
+        }
+    }
+
+    fn require_admin(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[contract]
+    struct MockAgreement;
+
+    #[contractimpl]
+    impl MockAgreement {
+        pub fn set_agreement(env: Env, agreement: RentalAgreement) {
+            env.storage().instance().set(&agreement.id, &agreement);
+        }
+
+        pub fn get_agreement(env: Env, agreement_id: BytesN<32>) -> RentalAgreement {
+            env.storage().instance().get(&agreement_id).unwrap()
+        }
+    }
+
+    #[contract]
+    struct MockEscrow;
+
+    #[contractimpl]
+    impl MockEscrow {
+        pub fn set_escrow(env: Env, escrow: EscrowAccount) {
+            env.storage().instance().set(&escrow.agreement_id, &escrow);
+        }
+
+        pub fn get_escrow(env: Env, agreement_id: BytesN<32>) -> EscrowAccount {
+            env.storage().instance().get(&agreement_id).unwrap()
+        }
+    }
+
+    fn agreement(env: &Env, seed: u8, landlord: &Address, rent_paid: i128) -> RentalAgreement {
+        RentalAgreement {
+            id: BytesN::from_array(env, &[seed; 32]),
+            property_id: BytesN::from_array(env, &[seed; 32]),
+            landlord: landlord.clone(),
+            tenant: Address::generate(env),
+            monthly_rent: 1_000,
+            security_deposit: 500,
+            start_date: 0,
+            end_date: 365 * 24 * 60 * 60,
+            status: AgreementStatus::Active,
+            landlord_signed: true,
+            landlord_signed_at: 0,
+            tenant_signed: true,
+            tenant_signed_at: 0,
+            deposit_paid: true,
+            deposit_paid_at: 0,
+            total_rent_paid: rent_paid,
+            months_paid: 1,
+            created_at: 0,
+            completed_at: 0,
+            notice_period_days: 30,
+            notice_served_at: 0,
+            notice_served_by: None,
+            document_hash: BytesN::from_array(env, &[0; 32]),
+            co_signer: None,
+            subletting_allowed: false,
+            custom_clauses: Vec::new(env),
+        }
+    }
+
+    fn escrow(agreement: &RentalAgreement, held: i128) -> EscrowAccount {
+        EscrowAccount {
+            agreement_id: agreement.id.clone(),
+            landlord: agreement.landlord.clone(),
+            tenant: agreement.tenant.clone(),
+            security_deposit_amount: agreement.security_deposit,
+            security_deposit_held: held,
+            monthly_rent_amount: agreement.monthly_rent,
+            total_rent_received: agreement.total_rent_paid,
+            total_rent_released: agreement.total_rent_paid,
+            is_deposit_released: held == 0,
+            deposit_released_at: 0,
+            created_at: 0,
+        }
+    }
+
+    #[test]
+    fn initialize_smoke() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, Analytics);
+        let client = AnalyticsClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let agreement_contract = Address::generate(&env);
+        let escrow_contract = Address::generate(&env);
+        client.initialize(&admin, &agreement_contract, &escrow_contract);
+
+        assert_eq!(client.get_platform_summary().total_agreements, 0);
+        assert_eq!(client.get_top_landlords_by_revenue(&10).len(), 0);
+
+        client.pause();
+        client.unpause();
+    }
+
+    #[test]
+    fn resyncing_applies_only_the_difference() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let agreement_contract = env.register_contract(None, MockAgreement);
+        let agreements = MockAgreementClient::new(&env, &agreement_contract);
+        let escrow_contract = env.register_contract(None, MockEscrow);
+        let escrows = MockEscrowClient::new(&env, &escrow_contract);
+        let contract_id = env.register_contract(None, Analytics);
+        let client = AnalyticsClient::new(&env, &contract_id);
+        client.initialize(
+            &Address::generate(&env),
+            &agreement_contract,
+            &escrow_contract,
+        );
+
+        let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+        let mut first = agreement(&env, 1, &alice, 2_000);
+        let second = agreement(&env, 2, &bob, 3_000);
+        for a in [&first, &second] {
+            agreements.set_agreement(a);
+            escrows.set_escrow(&escrow(a, 500));
+        }
+
+        client.sync_agreement(&first.id);
+        client.sync_agreement(&first.id);
+        client.sync_agreement(&second.id);
+        let summary = client.get_platform_summary();
+        assert_eq!(summary.total_agreements, 2);
+        assert_eq!(summary.total_active_agreements, 2);
+        assert_eq!(summary.total_rent_collected, 5_000);
+        assert_eq!(summary.total_deposits_held, 1_000);
+        assert_eq!(
+            client.get_top_landlords_by_revenue(&10),
+            Vec::from_array(&env, [(bob.clone(), 3_000), (alice.clone(), 2_000)])
+        );
+
+        // The first agreement finishes with more rent and its deposit returned.
+        first.status = AgreementStatus::Completed;
+        first.total_rent_paid = 4_000;
+        agreements.set_agreement(&first);
+        escrows.set_escrow(&escrow(&first, 0));
+        client.sync_agreement(&first.id);
+
+        let summary = client.get_platform_summary();
+        assert_eq!(summary.total_agreements, 2);
+        assert_eq!(summary.total_active_agreements, 1);
+        assert_eq!(summary.total_rent_collected, 7_000);
+        assert_eq!(summary.total_deposits_held, 500);
+        assert_eq!(client.get_landlord_revenue(&alice), 4_000);
+        assert_eq!(
+            client.get_top_landlords_by_revenue(&10),
+            Vec::from_array(&env, [(alice.clone(), 4_000), (bob, 3_000)])
+        );
+        assert_eq!(
+            client.get_top_landlords_by_revenue(&1),
+            Vec::from_array(&env, [(alice, 4_000)])
+        );
+    }
+}
@@ -2,7 +2,7 @@
 
 use soroban_sdk::{
     contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error, Address,
-    BytesN, Env, String, Symbol,
+    BytesN, Env, String, Symbol, Vec,
 };
 
 // -----------------------------
@@ -12,9 +12,12 @@ use soroban_sdk::{
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AgreementStatus {
+    PendingLandlordApproval,
+    Rejected,
     Draft,
     PendingTenantSign,
     PendingLandlordSign,
+    PendingCoSignerSign,
     PendingPayment,
     Active,
     Completed,
@@ -43,6 +46,13 @@ pub struct RentalAgreement {
     pub months_paid: u32,
     pub created_at: u64,
     pub completed_at: u64,
+    pub notice_period_days: u32,
+    pub notice_served_at: u64,
+    pub notice_served_by: Option<Address>,
+    pub document_hash: BytesN<32>,
+    pub co_signer: Option<Address>,
+    pub subletting_allowed: bool,
+    pub custom_clauses: Vec<String>,
 }
 
 #[contractclient(name = "RentalAgreementClient")]
@@ -54,6 +64,25 @@ pub trait RentalAgreementContract {
 // BriqToken contract (simple reward token)
 // -----------------------------
 
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+const DEFAULT_VESTING_DURATION_DAYS: u32 = 30;
+const STAKE_LOCK_SECONDS: u64 = 7 * SECONDS_PER_DAY;
+const MAX_BATCH_RECIPIENTS: u32 = 20;
+const MAX_REWARD_HISTORY: u32 = 200;
+const ONE_BRIQ: i128 = 10_000_000;
+const MAX_BURN_REASON_LEN: u32 = 100;
+// 100 BRIQ per bps, i.e. 1000 BRIQ = 10 bps.
+const DEFAULT_REDEMPTION_RATE: i128 = 100 * ONE_BRIQ;
+const MAX_REDEMPTION_DISCOUNT_BPS: u32 = 200;
+const ADMIN_TRANSFER_WINDOW_SECONDS: u64 = 7 * SECONDS_PER_DAY;
+const SECONDS_PER_YEAR: u64 = 365 * SECONDS_PER_DAY;
+const BPS_DENOMINATOR: i128 = 10_000;
+const DEFAULT_LOCK_YIELD_RATE_BPS: u32 = 500;
+const DEFAULT_CASHBACK_RATE_BPS: u32 = 50;
+const MAX_LOCK_ENTRIES: u32 = 20;
+const MAX_HOLDERS: u32 = 10_000;
+const MAX_HOLDER_PAGE: u32 = 200;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
@@ -64,7 +93,28 @@ pub enum Error {
 
     InvalidAmount = 500,
     InsufficientBalance = 501,
+    SupplyCapExceeded = 504,
+    AddressFrozen = 505,
     AgreementContractNotSet = 503,
+    RewardExpired = 506,
+    InsufficientAllowance = 507,
+    StakeLocked = 508,
+    BatchTooLarge = 509,
+    InvalidReason = 510,
+    NoPendingAdminTransfer = 515,
+    AdminTransferExpired = 516,
+    TokensLocked = 517,
+    InvalidUnlockTime = 518,
+    TooManyLocks = 519,
+    InvalidRate = 520,
+    NoEmissionSchedule = 521,
+    InvalidEmissionSchedule = 522,
+    EpochNotElapsed = 523,
+    HolderSetFull = 524,
+    AlreadyMinter = 525,
+    NotMinter = 526,
+    BadgeAlreadyIssued = 527,
+    RewardNotExpired = 528,
 }
 
 #[contracttype]
@@ -73,6 +123,85 @@ pub struct RewardConfig {
     pub first_payment_reward: i128,
     pub review_reward: i128,
     pub mutual_review_bonus: i128,
+    pub rent_cashback_bps: u32,
+    pub referral_reward: i128,
+    pub referee_welcome_reward: i128,
+}
+
+/// Epoch-based emission for the first-payment and review rewards. Each
+/// `advance_epoch` scales both by `halving_numerator / halving_denominator`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmissionSchedule {
+    pub epoch_start: u64,
+    pub epoch_duration_seconds: u64,
+    pub epoch_first_payment_reward: i128,
+    pub epoch_review_reward: i128,
+    pub halving_numerator: u32,
+    pub halving_denominator: u32,
+}
+
+/// One time-locked tranche. Yield accrues from `yield_from` until `unlock_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockEntry {
+    pub amount: i128,
+    pub unlock_at: u64,
+    pub yield_from: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    pub total: i128,
+    pub released: i128,
+    pub start_timestamp: u64,
+    pub duration_seconds: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardEvent {
+    pub reward_type: Symbol,
+    pub agreement_id: BytesN<32>,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Soulbound record of a completed tenancy. `final_rating` is 0 when the
+/// issuer had no rating to attach.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProofOfRentalBadge {
+    pub agreement_id: BytesN<32>,
+    pub months_completed: u32,
+    pub final_rating: u32,
+    pub issued_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LoyaltyTier {
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+}
+
+/// Minimum balances (in base units) for each tier above Bronze.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierConfig {
+    pub silver: i128,
+    pub gold: i128,
+    pub platinum: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierBenefits {
+    pub fee_discount_bps: u32,
+    pub reward_multiplier: u32,
 }
 
 #[contracttype]
@@ -90,11 +219,54 @@ pub enum DataKey {
     Metadata,
     TotalSupply,
     Balance(Address),
+    Allowance(Address, Address),
     RewardConfig,
+    // Mirrors RewardConfig.rent_cashback_bps; kept in sync by both setters.
+    CashbackRateBps,
     AgreementContract,
     ClaimFirstPayment(BytesN<32>, Address),
     ClaimReview(BytesN<32>, Address),
     ClaimMutual(BytesN<32>),
+    Vesting(Address),
+    VestingDurationDays,
+    RewardExpiryDays,
+    // When the holder's unclaimed vesting rewards lapse. Absent means never.
+    RewardExpiry(Address),
+    StakedBalance(Address),
+    StakeLockUntil(Address),
+    EscrowContract,
+    PaymentStreak(Address),
+    MaxSupply,
+    Frozen(Address),
+    LastSnapshotSeq,
+    SnapshotBalance(Address, u64),
+    // Ascending snapshot seqs the owner has a SnapshotBalance recorded for.
+    SnapshotSeqs(Address),
+    // Allowed to take snapshots alongside the admin, one per proposal.
+    GovernanceContract,
+    RewardHistory(Address),
+    TierThresholds,
+    RedemptionRate,
+    PendingDiscount(Address),
+    ReferralRegistry,
+    Stakers,
+    // Sum of the owner's LockEntry amounts.
+    LockedBalance(Address),
+    Locks(Address),
+    LockYieldRateBps,
+    EmissionSchedule,
+    // Tracked automatically on mint/transfer until MAX_HOLDERS is reached.
+    // Holder maps an address to its slot in the dense HolderAt index.
+    Holder(Address),
+    HolderAt(u32),
+    HolderCount,
+    TotalLockedBalance,
+    TotalStakedBalance,
+    // Contracts allowed to issue rewards alongside the admin.
+    MinterRole,
+    RentalBadges(Address),
+    PendingAdmin,
+    AdminTransferExpiry,
 }
 
 #[contract]
@@ -102,6 +274,7 @@ pub struct BriqToken;
 
 #[contractimpl]
 impl BriqToken {
+    #[allow(clippy::inconsistent_digit_grouping)]
     pub fn initialize(env: Env, admin: Address) {
         if env.storage().instance().has(&DataKey::Admin) {
             panic_with_error!(&env, Error::AlreadyInitialized);
@@ -120,6 +293,9 @@ impl BriqToken {
             },
         );
         env.storage().instance().set(&DataKey::TotalSupply, &0i128);
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxSupply, &i128::MAX);
 
         // Defaults (using 7 decimals)
         env.storage().instance().set(
@@ -128,8 +304,14 @@ impl BriqToken {
                 first_payment_reward: 10_000_0000,
                 review_reward: 25_000_0000,
                 mutual_review_bonus: 15_000_0000,
+                rent_cashback_bps: DEFAULT_CASHBACK_RATE_BPS,
+                referral_reward: 50_000_0000,
+                referee_welcome_reward: 10_000_0000,
             },
         );
+        env.storage()
+            .instance()
+            .set(&DataKey::CashbackRateBps, &DEFAULT_CASHBACK_RATE_BPS);
 
         env.events().publish(
             (Symbol::new(&env, "Initialized"),),
@@ -155,18 +337,98 @@ impl BriqToken {
             .publish((Symbol::new(&env, "Unpaused"),), env.ledger().timestamp());
     }
 
+    /// Starts a two-step admin handover. The proposed admin has 7 days to
+    /// call `accept_admin_transfer`.
+    pub fn propose_admin_transfer(env: Env, current_admin: Address, proposed_admin: Address) {
+        if current_admin != Self::require_admin(&env) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        current_admin.require_auth();
+
+        let expiry = env.ledger().timestamp() + ADMIN_TRANSFER_WINDOW_SECONDS;
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &proposed_admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::AdminTransferExpiry, &expiry);
+
+        env.events().publish(
+            (Symbol::new(&env, "AdminTransferProposed"),),
+            (current_admin, proposed_admin, expiry),
+        );
+    }
+
+    pub fn accept_admin_transfer(env: Env, proposed_admin: Address) {
+        proposed_admin.require_auth();
+
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoPendingAdminTransfer));
+        if pending != proposed_admin {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        let expiry: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AdminTransferExpiry)
+            .unwrap_or(0);
+        if env.ledger().timestamp() > expiry {
+            panic_with_error!(&env, Error::AdminTransferExpired);
+        }
+
+        let previous_admin = Self::require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::Admin, &proposed_admin);
+        Self::clear_pending_admin(&env);
+
+        env.events().publish(
+            (Symbol::new(&env, "AdminTransferred"),),
+            (previous_admin, proposed_admin),
+        );
+    }
+
+    pub fn cancel_admin_transfer(env: Env, current_admin: Address) {
+        if current_admin != Self::require_admin(&env) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        current_admin.require_auth();
+
+        if !env.storage().instance().has(&DataKey::PendingAdmin) {
+            panic_with_error!(&env, Error::NoPendingAdminTransfer);
+        }
+        Self::clear_pending_admin(&env);
+
+        env.events().publish(
+            (Symbol::new(&env, "AdminTransferCancelled"),),
+            current_admin,
+        );
+    }
+
     pub fn set_reward_config(
         env: Env,
         first_payment_reward: i128,
         review_reward: i128,
         mutual_review_bonus: i128,
+        rent_cashback_bps: u32,
+        referral_reward: i128,
+        referee_welcome_reward: i128,
     ) {
         Self::check_not_paused(&env);
 
         let admin = Self::require_admin(&env);
         admin.require_auth();
 
-        if first_payment_reward < 0 || review_reward < 0 || mutual_review_bonus < 0 {
+        if first_payment_reward < 0
+            || review_reward < 0
+            || mutual_review_bonus < 0
+            || rent_cashback_bps > 10_000
+            || referral_reward < 0
+            || referee_welcome_reward < 0
+        {
             panic_with_error!(&env, Error::InvalidAmount);
         }
 
@@ -176,12 +438,25 @@ impl BriqToken {
                 first_payment_reward,
                 review_reward,
                 mutual_review_bonus,
+                rent_cashback_bps,
+                referral_reward,
+                referee_welcome_reward,
             },
         );
+        env.storage()
+            .instance()
+            .set(&DataKey::CashbackRateBps, &rent_cashback_bps);
 
         env.events().publish(
             (Symbol::new(&env, "RewardConfigSet"),),
-            (first_payment_reward, review_reward, mutual_review_bonus),
+            (
+                first_payment_reward,
+                review_reward,
+                mutual_review_bonus,
+                rent_cashback_bps,
+                referral_reward,
+                referee_welcome_reward,
+            ),
         );
     }
 
@@ -192,7 +467,9 @@ impl BriqToken {
             .unwrap()
     }
 
-    pub fn set_agreement_contract(env: Env, agreement_contract: Address) {
+    /// Vesting window applied to newly issued rewards. 0 disables vesting and
+    /// rewards are minted immediately.
+    pub fn set_vesting_duration(env: Env, days: u32) {
         Self::check_not_paused(&env);
 
         let admin = Self::require_admin(&env);
@@ -200,319 +477,2828 @@ impl BriqToken {
 
         env.storage()
             .instance()
-            .set(&DataKey::AgreementContract, &agreement_contract);
+            .set(&DataKey::VestingDurationDays, &days);
 
-        env.events().publish(
-            (Symbol::new(&env, "AgreementContractSet"),),
-            (agreement_contract, env.ledger().timestamp()),
-        );
+        env.events()
+            .publish((Symbol::new(&env, "VestingDurationSet"),), days);
     }
 
-    // --- Token metadata ---
-
-    pub fn name(env: Env) -> String {
-        Self::metadata(&env).name
+    pub fn get_vesting_duration(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::VestingDurationDays)
+            .unwrap_or(DEFAULT_VESTING_DURATION_DAYS)
     }
 
-    pub fn symbol(env: Env) -> String {
-        Self::metadata(&env).symbol
-    }
+    /// Starts a new emission schedule at the current time. While a schedule is
+    /// set it overrides the first-payment and review amounts in `RewardConfig`.
+    pub fn set_emission_schedule(
+        env: Env,
+        epoch_duration_seconds: u64,
+        first_payment_reward: i128,
+        review_reward: i128,
+        halving_numerator: u32,
+        halving_denominator: u32,
+    ) {
+        Self::check_not_paused(&env);
 
-    pub fn decimals(env: Env) -> u32 {
-        Self::metadata(&env).decimals
-    }
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
 
-    pub fn total_supply(env: Env) -> i128 {
+        if epoch_duration_seconds == 0
+            || first_payment_reward < 0
+            || review_reward < 0
+            || halving_denominator == 0
+            || halving_numerator > halving_denominator
+        {
+            panic_with_error!(&env, Error::InvalidEmissionSchedule);
+        }
+
+        let schedule = EmissionSchedule {
+            epoch_start: env.ledger().timestamp(),
+            epoch_duration_seconds,
+            epoch_first_payment_reward: first_payment_reward,
+            epoch_review_reward: review_reward,
+            halving_numerator,
+            halving_denominator,
+        };
         env.storage()
             .instance()
-            .get(&DataKey::TotalSupply)
-            .unwrap_or(0)
-    }
+            .set(&DataKey::EmissionSchedule, &schedule);
 
-    pub fn balance_of(env: Env, owner: Address) -> i128 {
-        Self::get_balance(&env, &owner)
+        env.events()
+            .publish((Symbol::new(&env, "EmissionScheduleSet"),), schedule);
     }
 
-    // --- Token actions ---
-
-    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
-        Self::check_not_paused(&env);
-        from.require_auth();
-
-        Self::do_transfer(&env, &from, &to, amount);
+    pub fn get_emission_schedule(env: Env) -> Option<EmissionSchedule> {
+        env.storage().instance().get(&DataKey::EmissionSchedule)
     }
 
-    pub fn mint(env: Env, to: Address, amount: i128) {
+    /// Permissionless. Moves the schedule forward by one epoch once the current
+    /// one has elapsed and scales the reward amounts down.
+    pub fn advance_epoch(env: Env) {
         Self::check_not_paused(&env);
 
-        let admin = Self::require_admin(&env);
-        admin.require_auth();
+        let mut schedule = Self::get_emission_schedule(env.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoEmissionSchedule));
+        let next_start = schedule.epoch_start + schedule.epoch_duration_seconds;
+        if env.ledger().timestamp() < next_start {
+            panic_with_error!(&env, Error::EpochNotElapsed);
+        }
 
-        Self::do_mint(&env, &to, amount);
-        env.events()
-            .publish((Symbol::new(&env, "Mint"),), (to, amount));
+        let num = schedule.halving_numerator as i128;
+        let den = schedule.halving_denominator as i128;
+        schedule.epoch_start = next_start;
+        schedule.epoch_first_payment_reward = schedule.epoch_first_payment_reward * num / den;
+        schedule.epoch_review_reward = schedule.epoch_review_reward * num / den;
+        env.storage()
+            .instance()
+            .set(&DataKey::EmissionSchedule, &schedule);
+
+        env.events().publish(
+            (Symbol::new(&env, "EpochAdvanced"),),
+            (
+                next_start,
+                schedule.epoch_first_payment_reward,
+                schedule.epoch_review_reward,
+            ),
+        );
     }
 
-    pub fn burn(env: Env, from: Address, amount: i128) {
+    /// Days after a vesting schedule ends before its unclaimed remainder
+    /// lapses. 0 disables expiry. Applies to schedules granted afterwards.
+    pub fn set_reward_expiry_days(env: Env, days: u32) {
         Self::check_not_paused(&env);
 
         let admin = Self::require_admin(&env);
         admin.require_auth();
 
-        Self::do_burn(&env, &from, amount);
+        env.storage()
+            .instance()
+            .set(&DataKey::RewardExpiryDays, &days);
+
         env.events()
-            .publish((Symbol::new(&env, "Burn"),), (from, amount));
+            .publish((Symbol::new(&env, "RewardExpiryDaysSet"),), days);
     }
 
-    // --- Rewards (called by other contracts) ---
+    pub fn get_reward_expiry_days(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RewardExpiryDays)
+            .unwrap_or(0)
+    }
+
+    /// 0 when the holder's rewards don't expire.
+    pub fn get_reward_expiry(env: Env, holder: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RewardExpiry(holder))
+            .unwrap_or(0)
+    }
 
-    pub fn reward_first_payment(env: Env, agreement_id: BytesN<32>, tenant: Address) {
+    pub fn set_agreement_contract(env: Env, agreement_contract: Address) {
         Self::check_not_paused(&env);
 
-        // One-claim-per-(agreement, tenant)
-        if env.storage().persistent().has(&DataKey::ClaimFirstPayment(
-            agreement_id.clone(),
-            tenant.clone(),
-        )) {
-            return;
-        }
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
 
-        let cfg = Self::reward_config(&env);
-        if cfg.first_payment_reward <= 0 {
-            return;
-        }
+        env.storage()
+            .instance()
+            .set(&DataKey::AgreementContract, &agreement_contract);
 
-        Self::do_mint(&env, &tenant, cfg.first_payment_reward);
-        env.storage().persistent().set(
-            &DataKey::ClaimFirstPayment(agreement_id.clone(), tenant.clone()),
-            &true,
-        );
         env.events().publish(
-            (Symbol::new(&env, "RewardIssued"),),
-            (
-                Symbol::new(&env, "FirstPayment"),
-                agreement_id,
-                tenant,
-                cfg.first_payment_reward,
-            ),
+            (Symbol::new(&env, "AgreementContractSet"),),
+            (agreement_contract, env.ledger().timestamp()),
         );
     }
 
-    pub fn reward_review(env: Env, agreement_id: BytesN<32>, reviewer: Address) {
+    pub fn set_referral_registry(env: Env, referral_registry: Address) {
         Self::check_not_paused(&env);
 
-        // One-claim-per-(agreement, reviewer)
-        if env.storage().persistent().has(&DataKey::ClaimReview(
-            agreement_id.clone(),
-            reviewer.clone(),
-        )) {
-            return;
-        }
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
 
-        let cfg = Self::reward_config(&env);
-        if cfg.review_reward <= 0 {
-            return;
-        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ReferralRegistry, &referral_registry);
 
-        Self::do_mint(&env, &reviewer, cfg.review_reward);
-        env.storage().persistent().set(
-            &DataKey::ClaimReview(agreement_id.clone(), reviewer.clone()),
-            &true,
-        );
         env.events().publish(
-            (Symbol::new(&env, "RewardIssued"),),
-            (
-                Symbol::new(&env, "Review"),
-                agreement_id,
-                reviewer,
-                cfg.review_reward,
-            ),
+            (Symbol::new(&env, "ReferralRegistrySet"),),
+            (referral_registry, env.ledger().timestamp()),
         );
     }
 
-    // Called once mutual reviews are complete.
-    // Token contract fetches the agreement and mints bonus to both tenant and landlord.
-    pub fn reward_mutual_review(env: Env, agreement_id: BytesN<32>) {
+    /// Caps total supply. `i128::MAX` means uncapped; once a real cap is set it
+    /// can only be raised so existing balances never end up above it.
+    pub fn set_max_supply(env: Env, cap: i128) {
         Self::check_not_paused(&env);
 
-        // One-claim-per-agreement
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::ClaimMutual(agreement_id.clone()))
-        {
-            return;
-        }
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
 
-        let cfg = Self::reward_config(&env);
-        if cfg.mutual_review_bonus <= 0 {
-            return;
+        let current = Self::get_max_supply(env.clone());
+        if cap < Self::total_supply(env.clone()) || (current != i128::MAX && cap < current) {
+            panic_with_error!(&env, Error::InvalidAmount);
         }
 
-        let agreement_contract: Address = env
-            .storage()
+        env.storage().instance().set(&DataKey::MaxSupply, &cap);
+
+        env.events()
+            .publish((Symbol::new(&env, "MaxSupplySet"),), (cap, admin));
+    }
+
+    pub fn get_max_supply(env: Env) -> i128 {
+        env.storage()
             .instance()
-            .get(&DataKey::AgreementContract)
-            .unwrap_or_else(|| panic_with_error!(&env, Error::AgreementContractNotSet));
-        let agreement_client = RentalAgreementClient::new(&env, &agreement_contract);
-        let a = agreement_client.get_agreement(&agreement_id);
+            .get(&DataKey::MaxSupply)
+            .unwrap_or(i128::MAX)
+    }
 
-        Self::do_mint(&env, &a.tenant, cfg.mutual_review_bonus);
-        Self::do_mint(&env, &a.landlord, cfg.mutual_review_bonus);
+    /// Frozen addresses can still receive minted rewards but cannot move,
+    /// stake or unstake tokens.
+    pub fn freeze_address(env: Env, target: Address) {
+        Self::set_frozen(&env, target, true);
+    }
+
+    pub fn unfreeze_address(env: Env, target: Address) {
+        Self::set_frozen(&env, target, false);
+    }
 
+    pub fn is_frozen(env: Env, address: Address) -> bool {
         env.storage()
             .persistent()
-            .set(&DataKey::ClaimMutual(agreement_id.clone()), &true);
+            .get(&DataKey::Frozen(address))
+            .unwrap_or(false)
+    }
+
+    pub fn set_tier_config(env: Env, config: TierConfig) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        if config.silver <= 0 || config.gold <= config.silver || config.platinum <= config.gold {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TierThresholds, &config);
 
         env.events().publish(
-            (Symbol::new(&env, "RewardIssued"),),
-            (
-                Symbol::new(&env, "MutualReview"),
-                agreement_id.clone(),
-                a.tenant,
-                cfg.mutual_review_bonus,
-            ),
+            (Symbol::new(&env, "TierConfigUpdated"),),
+            (config.silver, config.gold, config.platinum),
         );
-        env.events().publish(
-            (Symbol::new(&env, "RewardIssued"),),
-            (
-                Symbol::new(&env, "MutualReview"),
-                agreement_id,
-                a.landlord,
-                cfg.mutual_review_bonus,
-            ),
+    }
+
+    pub fn get_tier_config(env: Env) -> TierConfig {
+        Self::tier_config(&env)
+    }
+
+    /// Base units of BRIQ that must be burned per 1 bps of fee discount.
+    pub fn set_redemption_rate(env: Env, briq_per_bps: i128) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        if briq_per_bps <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RedemptionRate, &briq_per_bps);
+
+        env.events()
+            .publish((Symbol::new(&env, "RedemptionRateSet"),), briq_per_bps);
+    }
+
+    pub fn get_redemption_rate(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RedemptionRate)
+            .unwrap_or(DEFAULT_REDEMPTION_RATE)
+    }
+
+    /// EscrowManager is the only caller allowed to update payment streaks.
+    pub fn set_escrow_contract(env: Env, escrow_contract: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::EscrowContract, &escrow_contract);
+
+        env.events().publish(
+            (Symbol::new(&env, "EscrowContractSet"),),
+            (escrow_contract, env.ledger().timestamp()),
         );
     }
 
-    // -----------------------------
-    // Internals
-    // -----------------------------
+    /// Governance takes a snapshot for every proposal it creates.
+    pub fn set_governance_contract(env: Env, governance_contract: Address) {
+        Self::check_not_paused(&env);
 
-    fn check_not_paused(env: &Env) {
-        let paused: bool = env
-            .storage()
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        env.storage()
             .instance()
-            .get(&DataKey::Paused)
-            .unwrap_or(false);
-        if paused {
-            panic_with_error!(env, Error::ContractPaused);
+            .set(&DataKey::GovernanceContract, &governance_contract);
+
+        env.events().publish(
+            (Symbol::new(&env, "GovernanceContractSet"),),
+            (governance_contract, env.ledger().timestamp()),
+        );
+    }
+
+    // --- Minters ---
+
+    pub fn add_minter(env: Env, minter: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let mut minters = Self::minters(&env);
+        if minters.contains(&minter) {
+            panic_with_error!(&env, Error::AlreadyMinter);
         }
+        minters.push_back(minter.clone());
+        env.storage().instance().set(&DataKey::MinterRole, &minters);
+
+        env.events()
+            .publish((Symbol::new(&env, "MinterAdded"),), minter);
     }
 
-    fn require_admin(env: &Env) -> Address {
+    pub fn remove_minter(env: Env, minter: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let mut minters = Self::minters(&env);
+        let i = minters
+            .first_index_of(&minter)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotMinter));
+        minters.remove(i);
+        env.storage().instance().set(&DataKey::MinterRole, &minters);
+
+        env.events()
+            .publish((Symbol::new(&env, "MinterRemoved"),), minter);
+    }
+
+    pub fn is_minter(env: Env, address: Address) -> bool {
+        Self::minters(&env).contains(&address)
+    }
+
+    // --- Token metadata ---
+
+    pub fn name(env: Env) -> String {
+        Self::metadata(&env).name
+    }
+
+    pub fn symbol(env: Env) -> String {
+        Self::metadata(&env).symbol
+    }
+
+    pub fn decimals(env: Env) -> u32 {
+        Self::metadata(&env).decimals
+    }
+
+    pub fn total_supply(env: Env) -> i128 {
         env.storage()
             .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized))
+            .get(&DataKey::TotalSupply)
+            .unwrap_or(0)
     }
 
-    fn metadata(env: &Env) -> Metadata {
-        env.storage().instance().get(&DataKey::Metadata).unwrap()
+    /// Total supply minus staked and time-locked tokens.
+    pub fn get_circulating_supply(env: Env) -> i128 {
+        Self::total_supply(env.clone())
+            - Self::get_total_staked(env.clone())
+            - Self::get_total_locked(env)
     }
 
-    fn reward_config(env: &Env) -> RewardConfig {
+    pub fn get_total_staked(env: Env) -> i128 {
         env.storage()
             .instance()
-            .get(&DataKey::RewardConfig)
-            .unwrap()
+            .get(&DataKey::TotalStakedBalance)
+            .unwrap_or(0)
     }
 
-    fn do_transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
-        if amount <= 0 {
-            panic_with_error!(env, Error::InvalidAmount);
+    pub fn get_total_locked(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalLockedBalance)
+            .unwrap_or(0)
+    }
+
+    pub fn balance_of(env: Env, owner: Address) -> i128 {
+        Self::get_balance(&env, &owner)
+    }
+
+    // --- Token actions ---
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        Self::check_not_paused(&env);
+        from.require_auth();
+
+        Self::do_transfer(&env, &from, &to, amount);
+    }
+
+    /// Sends `amounts[i]` to `recipients[i]`. The total is checked against the
+    /// sender's liquid balance before anything moves, and a single
+    /// `BatchTransfer` event is emitted instead of one per recipient.
+    pub fn batch_transfer(env: Env, from: Address, recipients: Vec<Address>, amounts: Vec<i128>) {
+        Self::check_not_paused(&env);
+        from.require_auth();
+
+        if recipients.len() != amounts.len() {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if recipients.len() > MAX_BATCH_RECIPIENTS {
+            panic_with_error!(&env, Error::BatchTooLarge);
         }
 
-        let from_balance = Self::get_balance(env, from);
-        if from_balance < amount {
-            panic_with_error!(env, Error::InsufficientBalance);
+        let mut total: i128 = 0;
+        for amount in amounts.iter() {
+            if amount <= 0 {
+                panic_with_error!(&env, Error::InvalidAmount);
+            }
+            total = total
+                .checked_add(amount)
+                .unwrap_or_else(|| panic_with_error!(&env, Error::InvalidAmount));
+        }
+        if Self::liquid_balance(&env, &from) < total {
+            panic_with_error!(&env, Error::InsufficientBalance);
         }
 
-        Self::set_balance(env, from, from_balance - amount);
-        let to_balance = Self::get_balance(env, to);
-        Self::set_balance(env, to, to_balance + amount);
+        for (to, amount) in recipients.iter().zip(amounts.iter()) {
+            Self::move_balance(&env, &from, &to, amount);
+        }
 
         env.events().publish(
-            (Symbol::new(env, "Transfer"),),
-            (from.clone(), to.clone(), amount),
+            (Symbol::new(&env, "BatchTransfer"),),
+            (from, recipients.len(), total),
         );
     }
 
-    fn do_mint(env: &Env, to: &Address, amount: i128) {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        Self::do_mint(&env, &to, amount);
+        env.events()
+            .publish((Symbol::new(&env, "Mint"),), (to, amount));
+    }
+
+    pub fn burn(env: Env, from: Address, amount: i128) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        Self::do_burn(&env, &from, amount);
+        env.events()
+            .publish((Symbol::new(&env, "Burn"),), (from, amount));
+    }
+
+    /// Burns tokens the holder spends on a platform service (e.g. a property
+    /// listing fee). Called by the service contract under the holder's auth.
+    pub fn burn_for_service(env: Env, from: Address, amount: i128, service: Symbol) {
+        Self::check_not_paused(&env);
+        from.require_auth();
+        Self::check_not_frozen(&env, &from);
+
+        Self::do_burn(&env, &from, amount);
+        env.events()
+            .publish((Symbol::new(&env, "ServiceBurn"),), (from, amount, service));
+    }
+
+    /// Voluntary burn by the holder. Emits `SelfBurn` rather than `Burn` so
+    /// it can be told apart from admin burns.
+    pub fn self_burn(env: Env, from: Address, amount: i128, reason: Option<String>) {
+        Self::check_not_paused(&env);
+        from.require_auth();
+        Self::check_not_frozen(&env, &from);
+
         if amount <= 0 {
-            panic_with_error!(env, Error::InvalidAmount);
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if let Some(r) = &reason {
+            if r.len() > MAX_BURN_REASON_LEN {
+                panic_with_error!(&env, Error::InvalidReason);
+            }
         }
 
-        let to_balance = Self::get_balance(env, to);
-        Self::set_balance(env, to, to_balance + amount);
+        Self::do_burn(&env, &from, amount);
+        env.events()
+            .publish((Symbol::new(&env, "SelfBurn"),), (from, amount, reason));
+    }
 
-        let supply: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TotalSupply)
-            .unwrap_or(0);
-        env.storage()
-            .instance()
-            .set(&DataKey::TotalSupply, &(supply + amount));
+    // --- Allowances ---
+
+    pub fn approve(env: Env, owner: Address, spender: Address, amount: i128) {
+        Self::check_not_paused(&env);
+        owner.require_auth();
+
+        if amount < 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        Self::set_allowance(&env, &owner, &spender, amount);
     }
 
-    fn do_burn(env: &Env, from: &Address, amount: i128) {
+    pub fn increase_allowance(env: Env, owner: Address, spender: Address, amount: i128) {
+        Self::check_not_paused(&env);
+        owner.require_auth();
+
         if amount <= 0 {
-            panic_with_error!(env, Error::InvalidAmount);
+            panic_with_error!(&env, Error::InvalidAmount);
         }
 
-        let from_balance = Self::get_balance(env, from);
-        if from_balance < amount {
-            panic_with_error!(env, Error::InsufficientBalance);
+        let current = Self::allowance(&env, &owner, &spender);
+        let updated = current.saturating_add(amount);
+        Self::set_allowance(&env, &owner, &spender, updated);
+        env.events().publish(
+            (Symbol::new(&env, "AllowanceIncreased"),),
+            (owner, spender, amount, updated),
+        );
+    }
+
+    /// Clamps at zero, so a decrease racing a `transfer_from` that already
+    /// spent part of the allowance still succeeds.
+    pub fn decrease_allowance(env: Env, owner: Address, spender: Address, amount: i128) {
+        Self::check_not_paused(&env);
+        owner.require_auth();
+
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
         }
 
-        Self::set_balance(env, from, from_balance - amount);
-        let supply: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TotalSupply)
-            .unwrap_or(0);
-        env.storage()
-            .instance()
-            .set(&DataKey::TotalSupply, &(supply - amount));
+        let current = Self::allowance(&env, &owner, &spender);
+        let updated = (current - amount).max(0);
+        Self::set_allowance(&env, &owner, &spender, updated);
+        env.events().publish(
+            (Symbol::new(&env, "AllowanceDecreased"),),
+            (owner, spender, amount, updated),
+        );
     }
 
-    fn set_balance(env: &Env, owner: &Address, amount: i128) {
-        env.storage()
-            .persistent()
-            .set(&DataKey::Balance(owner.clone()), &amount);
+    pub fn get_allowance(env: Env, owner: Address, spender: Address) -> i128 {
+        Self::allowance(&env, &owner, &spender)
     }
 
-    fn get_balance(env: &Env, owner: &Address) -> i128 {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Balance(owner.clone()))
-            .unwrap_or(0)
+    // Lets other contracts (e.g. fee collection) move BRIQ a holder has approved.
+    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        Self::check_not_paused(&env);
+        spender.require_auth();
+
+        let current = Self::allowance(&env, &from, &spender);
+        if current < amount {
+            panic_with_error!(&env, Error::InsufficientAllowance);
+        }
+
+        Self::do_transfer(&env, &from, &to, amount);
+        Self::set_allowance(&env, &from, &spender, current - amount);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use soroban_sdk::testutils::Address as _;
+    // --- Staking ---
 
-    #[test]
-    fn mint_and_transfer() {
-        let env = Env::default();
-        env.mock_all_auths();
+    /// Locks `amount` of the holder's liquid balance. Each stake pushes the
+    /// unlock time to 7 days from now.
+    pub fn stake(env: Env, staker: Address, amount: i128) {
+        Self::check_not_paused(&env);
+        staker.require_auth();
+        Self::check_not_frozen(&env, &staker);
 
-        let contract_id = env.register_contract(None, BriqToken);
-        let client = BriqTokenClient::new(&env, &contract_id);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if Self::liquid_balance(&env, &staker) < amount {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
 
-        let admin = Address::generate(&env);
-        client.initialize(&admin);
+        let previous = Self::staked_balance(&env, &staker);
+        if previous == 0 {
+            let mut stakers = Self::get_stakers(env.clone());
+            stakers.push_back(staker.clone());
+            env.storage().persistent().set(&DataKey::Stakers, &stakers);
+        }
+        let staked = previous + amount;
+        env.storage()
+            .persistent()
+            .set(&DataKey::StakedBalance(staker.clone()), &staked);
+        Self::adjust_total(&env, &DataKey::TotalStakedBalance, amount);
+        let lock_until = env.ledger().timestamp() + STAKE_LOCK_SECONDS;
+        env.storage()
+            .persistent()
+            .set(&DataKey::StakeLockUntil(staker.clone()), &lock_until);
 
-        let alice = Address::generate(&env);
+        env.events()
+            .publish((Symbol::new(&env, "Staked"),), (staker, amount, lock_until));
+    }
+
+    pub fn unstake(env: Env, staker: Address, amount: i128) {
+        Self::check_not_paused(&env);
+        staker.require_auth();
+        Self::check_not_frozen(&env, &staker);
+
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        let lock_until: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::StakeLockUntil(staker.clone()))
+            .unwrap_or(0);
+        if env.ledger().timestamp() < lock_until {
+            panic_with_error!(&env, Error::StakeLocked);
+        }
+        let staked = Self::staked_balance(&env, &staker);
+        if staked < amount {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::StakedBalance(staker.clone()), &(staked - amount));
+        Self::adjust_total(&env, &DataKey::TotalStakedBalance, -amount);
+        if staked == amount {
+            let mut stakers = Self::get_stakers(env.clone());
+            if let Some(i) = stakers.first_index_of(&staker) {
+                stakers.remove(i);
+                env.storage().persistent().set(&DataKey::Stakers, &stakers);
+            }
+        }
+
+        env.events()
+            .publish((Symbol::new(&env, "Unstaked"),), (staker, amount));
+    }
+
+    pub fn get_staked_balance(env: Env, staker: Address) -> i128 {
+        Self::staked_balance(&env, &staker)
+    }
+
+    pub fn get_liquid_balance(env: Env, staker: Address) -> i128 {
+        Self::liquid_balance(&env, &staker)
+    }
+
+    /// Addresses with a non-zero staked balance.
+    pub fn get_stakers(env: Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Stakers)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // --- Time locks ---
+
+    /// Locks `amount` of the holder's liquid balance until `unlock_timestamp`.
+    /// Each call adds a separate lock, so schedules can be staggered.
+    pub fn lock_tokens(env: Env, owner: Address, amount: i128, unlock_timestamp: u64) {
+        Self::check_not_paused(&env);
+        owner.require_auth();
+        Self::check_not_frozen(&env, &owner);
+
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        let now = env.ledger().timestamp();
+        if unlock_timestamp <= now {
+            panic_with_error!(&env, Error::InvalidUnlockTime);
+        }
+        if Self::liquid_balance(&env, &owner) < amount {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+        let mut locks = Self::get_locks(env.clone(), owner.clone());
+        if locks.len() >= MAX_LOCK_ENTRIES {
+            panic_with_error!(&env, Error::TooManyLocks);
+        }
+
+        locks.push_back(LockEntry {
+            amount,
+            unlock_at: unlock_timestamp,
+            yield_from: now,
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::Locks(owner.clone()), &locks);
+        Self::set_locked_balance(&env, &owner, Self::locked_balance(&env, &owner) + amount);
+        Self::adjust_total(&env, &DataKey::TotalLockedBalance, amount);
+
+        env.events().publish(
+            (Symbol::new(&env, "TokensLocked"),),
+            (owner, amount, unlock_timestamp),
+        );
+    }
+
+    /// Releases every lock that has expired, then pays out the yield accrued
+    /// since the last claim. The principal is released even when the supply cap
+    /// leaves no room for the yield; whatever does not fit is forfeited.
+    pub fn unlock_tokens(env: Env, owner: Address) -> i128 {
+        Self::check_not_paused(&env);
+        owner.require_auth();
+
+        let mut locks = Self::get_locks(env.clone(), owner.clone());
+        let accrued = Self::accrue_lock_yield(&env, &mut locks);
+
+        let now = env.ledger().timestamp();
+        let mut remaining = Vec::<LockEntry>::new(&env);
+        let mut released: i128 = 0;
+        for entry in locks.iter() {
+            if entry.unlock_at <= now {
+                released += entry.amount;
+            } else {
+                remaining.push_back(entry);
+            }
+        }
+        if released == 0 {
+            panic_with_error!(&env, Error::TokensLocked);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Locks(owner.clone()), &remaining);
+        Self::set_locked_balance(&env, &owner, Self::locked_balance(&env, &owner) - released);
+        Self::adjust_total(&env, &DataKey::TotalLockedBalance, -released);
+
+        env.events().publish(
+            (Symbol::new(&env, "TokensUnlocked"),),
+            (owner.clone(), released),
+        );
+
+        Self::mint_lock_yield(&env, &owner, accrued);
+        released
+    }
+
+    /// Mints the yield accrued on all locks since the last claim.
+    pub fn claim_lock_yield(env: Env, owner: Address) -> i128 {
+        Self::check_not_paused(&env);
+        owner.require_auth();
+
+        Self::pay_lock_yield(&env, &owner)
+    }
+
+    /// Annualised yield on locked tokens, in basis points.
+    pub fn set_lock_yield_rate(env: Env, rate_bps: u32) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        if rate_bps as i128 > BPS_DENOMINATOR {
+            panic_with_error!(&env, Error::InvalidRate);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::LockYieldRateBps, &rate_bps);
+    }
+
+    pub fn get_lock_yield_rate(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::LockYieldRateBps)
+            .unwrap_or(DEFAULT_LOCK_YIELD_RATE_BPS)
+    }
+
+    pub fn get_locks(env: Env, owner: Address) -> Vec<LockEntry> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Locks(owner))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_locked_balance(env: Env, owner: Address) -> i128 {
+        Self::locked_balance(&env, &owner)
+    }
+
+    // --- Holders ---
+
+    /// Adds a holder the automatic tracking skipped because the set was full.
+    pub fn register_holder(env: Env, holder: Address) {
+        Self::check_not_paused(&env);
+        holder.require_auth();
+
+        if Self::get_balance(&env, &holder) <= 0 {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+        if !Self::track_holder(&env, &holder) {
+            panic_with_error!(&env, Error::HolderSetFull);
+        }
+    }
+
+    /// Drops zero-balance holders among the `limit` (at most 200) slots
+    /// starting at `offset`. Removed slots are refilled from the end of the
+    /// index, so callers should resume from `offset + limit - removed`.
+    /// Returns how many were removed.
+    pub fn remove_zero_balance_holders(env: Env, offset: u32, limit: u32) -> u32 {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let mut index = offset;
+        let mut scanned = 0;
+        let mut removed = 0;
+        while scanned < limit.min(MAX_HOLDER_PAGE) && index < Self::holder_count(&env) {
+            let holder = Self::holder_at(&env, index);
+            if Self::get_balance(&env, &holder) > 0 {
+                index += 1;
+            } else {
+                Self::untrack_holder(&env, &holder, index);
+                removed += 1;
+            }
+            scanned += 1;
+        }
+
+        env.events()
+            .publish((Symbol::new(&env, "HoldersPruned"),), removed);
+        removed
+    }
+
+    /// Returns up to `limit` (at most 200) holders starting at `offset`.
+    pub fn get_holders(env: Env, offset: u32, limit: u32) -> Vec<Address> {
+        let end = offset
+            .saturating_add(limit.min(MAX_HOLDER_PAGE))
+            .min(Self::holder_count(&env));
+        let mut holders = Vec::new(&env);
+        for index in offset..end {
+            holders.push_back(Self::holder_at(&env, index));
+        }
+        holders
+    }
+
+    pub fn get_holder_count(env: Env) -> u32 {
+        Self::holder_count(&env)
+    }
+
+    // --- Fee discount redemption ---
+
+    /// Burns BRIQ for a platform fee discount held until EscrowManager consumes
+    /// it. Only the amount that converts into whole bps (up to 200 bps) is
+    /// burned.
+    pub fn redeem_for_fee_discount(env: Env, user: Address, briq_amount: i128) -> u32 {
+        Self::check_not_paused(&env);
+        user.require_auth();
+        Self::check_not_frozen(&env, &user);
+
+        let rate = Self::get_redemption_rate(env.clone());
+        let pending = Self::pending_discount(&env, &user);
+        let headroom = MAX_REDEMPTION_DISCOUNT_BPS.saturating_sub(pending);
+        let bps = (briq_amount / rate).clamp(0, headroom as i128) as u32;
+        if bps == 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        Self::do_burn(&env, &user, (bps as i128) * rate);
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingDiscount(user.clone()), &(pending + bps));
+
+        env.events()
+            .publish((Symbol::new(&env, "FeeDiscountRedeemed"),), (user, bps));
+        bps
+    }
+
+    pub fn get_pending_discount(env: Env, user: Address) -> u32 {
+        Self::pending_discount(&env, &user)
+    }
+
+    /// Called by EscrowManager when charging a fee; returns and clears the
+    /// user's pending discount.
+    pub fn consume_pending_discount(env: Env, user: Address) -> u32 {
+        Self::check_not_paused(&env);
+        Self::require_escrow(&env).require_auth();
+
+        let bps = Self::pending_discount(&env, &user);
+        if bps > 0 {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::PendingDiscount(user.clone()));
+            env.events()
+                .publish((Symbol::new(&env, "FeeDiscountConsumed"),), (user, bps));
+        }
+        bps
+    }
+
+    // --- Loyalty tiers ---
+
+    pub fn get_loyalty_tier(env: Env, address: Address) -> LoyaltyTier {
+        Self::tier_for(&env, Self::get_balance(&env, &address))
+    }
+
+    /// `reward_multiplier` is in percent (100 = 1x).
+    pub fn get_tier_benefits(_env: Env, tier: LoyaltyTier) -> TierBenefits {
+        let (fee_discount_bps, reward_multiplier) = match tier {
+            LoyaltyTier::Bronze => (0, 100),
+            LoyaltyTier::Silver => (250, 110),
+            LoyaltyTier::Gold => (500, 125),
+            LoyaltyTier::Platinum => (1000, 150),
+        };
+        TierBenefits {
+            fee_discount_bps,
+            reward_multiplier,
+        }
+    }
+
+    // --- Governance snapshots ---
+
+    /// Starts a new snapshot at the current ledger sequence. Balances are
+    /// captured lazily: on `record_snapshot_balance`, or automatically just
+    /// before an address's balance first changes after the snapshot.
+    /// `caller` must be the admin or the configured governance contract.
+    pub fn take_snapshot(env: Env, caller: Address) -> u64 {
+        Self::check_not_paused(&env);
+
+        caller.require_auth();
+        let governance: Option<Address> =
+            env.storage().instance().get(&DataKey::GovernanceContract);
+        if caller != Self::require_admin(&env) && governance != Some(caller.clone()) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+
+        let seq = env.ledger().sequence() as u64;
+        env.storage()
+            .instance()
+            .set(&DataKey::LastSnapshotSeq, &seq);
+
+        env.events()
+            .publish((Symbol::new(&env, "SnapshotTaken"),), (seq, caller));
+        seq
+    }
+
+    pub fn get_last_snapshot_seq(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::LastSnapshotSeq)
+            .unwrap_or(0)
+    }
+
+    pub fn record_snapshot_balance(env: Env, owner: Address) {
+        Self::check_not_paused(&env);
+        Self::checkpoint_balance(&env, &owner);
+    }
+
+    pub fn get_snapshot_balance(env: Env, owner: Address, snapshot_seq: u64) -> i128 {
+        let last_seq = env
+            .storage()
+            .instance()
+            .get::<_, u64>(&DataKey::LastSnapshotSeq);
+        if last_seq.is_none_or(|last| snapshot_seq > last) {
+            return 0;
+        }
+        // The first checkpoint at or after `snapshot_seq` holds the balance as
+        // of that snapshot: any earlier change would have been checkpointed
+        // under a snapshot in between. No checkpoint means no change since.
+        let seqs = Self::snapshot_seqs(&env, &owner);
+        let i = match seqs.binary_search(snapshot_seq) {
+            Ok(i) | Err(i) => i,
+        };
+        match seqs.get(i) {
+            Some(seq) => env
+                .storage()
+                .persistent()
+                .get(&DataKey::SnapshotBalance(owner, seq))
+                .unwrap_or(0),
+            None => Self::get_balance(&env, &owner),
+        }
+    }
+
+    /// Voting power: liquid, staked and time-locked BRIQ combined.
+    pub fn get_governance_vote_weight(env: Env, address: Address) -> i128 {
+        Self::liquid_balance(&env, &address)
+            + Self::staked_balance(&env, &address)
+            + Self::locked_balance(&env, &address)
+    }
+
+    /// Voting power at `snapshot_seq`. Staked and locked tokens never leave
+    /// the balance, so the snapshotted balance already includes them.
+    pub fn get_snapshot_governance_weight(env: Env, address: Address, snapshot_seq: u64) -> i128 {
+        Self::get_snapshot_balance(env, address, snapshot_seq)
+    }
+
+    // --- Vesting ---
+
+    /// Mints whatever portion of `owner`'s reward schedule has vested so far.
+    /// Vesting is linear, so partial claims are possible mid-schedule. Every
+    /// new reward restarts the vesting clock: the vested part is released and
+    /// the unvested remainder vests again from scratch with the new reward.
+    pub fn claim_vested(env: Env, owner: Address) -> i128 {
+        Self::check_not_paused(&env);
+        owner.require_auth();
+
+        if Self::rewards_expired(&env, &owner) {
+            panic_with_error!(&env, Error::RewardExpired);
+        }
+        let claimed = Self::release_vested(&env, &owner);
+        if claimed > 0 {
+            env.events()
+                .publish((Symbol::new(&env, "VestedClaimed"),), (owner, claimed));
+        }
+        claimed
+    }
+
+    /// Permissionless. Drops the holder's unclaimed vesting rewards once they
+    /// have expired. Unclaimed rewards were never minted, so this only removes
+    /// them from the schedule; liquid balances are untouched.
+    pub fn expire_unclaimed_rewards(env: Env, holder: Address) -> i128 {
+        Self::check_not_paused(&env);
+
+        if !Self::rewards_expired(&env, &holder) {
+            panic_with_error!(&env, Error::RewardNotExpired);
+        }
+        Self::drop_expired_rewards(&env, &holder)
+    }
+
+    pub fn get_vesting_info(env: Env, owner: Address) -> VestingSchedule {
+        Self::vesting(&env, &owner)
+    }
+
+    // --- Payment streaks ---
+
+    /// Reward multiplier in percent for the tenant's current on-time streak.
+    pub fn get_streak_multiplier(env: Env, address: Address) -> u32 {
+        match Self::streak(&env, &address) {
+            0..=2 => 100,
+            3..=5 => 125,
+            6..=11 => 150,
+            _ => 200,
+        }
+    }
+
+    pub fn get_payment_streak(env: Env, address: Address) -> u32 {
+        Self::streak(&env, &address)
+    }
+
+    pub fn record_on_time_payment(env: Env, tenant: Address) {
+        Self::check_not_paused(&env);
+        Self::require_escrow(&env).require_auth();
+
+        let streak = Self::streak(&env, &tenant) + 1;
+        Self::set_streak(&env, &tenant, streak);
+    }
+
+    pub fn record_missed_payment(env: Env, tenant: Address) {
+        Self::check_not_paused(&env);
+        Self::require_escrow(&env).require_auth();
+
+        Self::set_streak(&env, &tenant, 0);
+    }
+
+    /// Oldest first, capped at the most recent 200 rewards.
+    pub fn get_reward_history(env: Env, address: Address) -> Vec<RewardEvent> {
+        Self::reward_history(&env, &address)
+    }
+
+    pub fn get_reward_history_count(env: Env, address: Address) -> u32 {
+        Self::reward_history(&env, &address).len()
+    }
+
+    // --- Rental badges ---
+
+    /// Called by RentalAgreement from `complete_agreement`. The agreement
+    /// details are passed in because this contract can't call back into it.
+    /// Badges have no transfer or burn path.
+    pub fn issue_rental_badge(
+        env: Env,
+        agreement_id: BytesN<32>,
+        tenant: Address,
+        months_completed: u32,
+        final_rating: u32,
+    ) {
+        Self::check_not_paused(&env);
+        let agreement_contract: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::AgreementContract)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::AgreementContractNotSet));
+        agreement_contract.require_auth();
+
+        let mut badges = Self::get_rental_badges(env.clone(), tenant.clone());
+        if badges.iter().any(|b| b.agreement_id == agreement_id) {
+            panic_with_error!(&env, Error::BadgeAlreadyIssued);
+        }
+        badges.push_back(ProofOfRentalBadge {
+            agreement_id: agreement_id.clone(),
+            months_completed,
+            final_rating,
+            issued_at: env.ledger().timestamp(),
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::RentalBadges(tenant.clone()), &badges);
+
+        env.events().publish(
+            (Symbol::new(&env, "RentalBadgeIssued"),),
+            (tenant, agreement_id, months_completed),
+        );
+    }
+
+    pub fn get_rental_badges(env: Env, holder: Address) -> Vec<ProofOfRentalBadge> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RentalBadges(holder))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_rental_badge_count(env: Env, holder: Address) -> u32 {
+        Self::get_rental_badges(env, holder).len()
+    }
+
+    // --- Rewards (called by other contracts) ---
+
+    pub fn reward_first_payment(
+        env: Env,
+        caller: Address,
+        agreement_id: BytesN<32>,
+        tenant: Address,
+    ) {
+        Self::check_not_paused(&env);
+        Self::require_minter(&env, &caller);
+
+        // One-claim-per-(agreement, tenant)
+        if env.storage().persistent().has(&DataKey::ClaimFirstPayment(
+            agreement_id.clone(),
+            tenant.clone(),
+        )) {
+            return;
+        }
+
+        let cfg = Self::emission_reward_config(&env);
+        if cfg.first_payment_reward <= 0 {
+            return;
+        }
+
+        let streak = Self::streak(&env, &tenant) + 1;
+        Self::set_streak(&env, &tenant, streak);
+        let amount = Self::apply_multiplier(&env, &tenant, cfg.first_payment_reward);
+        Self::grant_reward(&env, &tenant, amount);
+        Self::append_reward(&env, &tenant, "FirstPayment", &agreement_id, amount);
+        env.storage().persistent().set(
+            &DataKey::ClaimFirstPayment(agreement_id.clone(), tenant.clone()),
+            &true,
+        );
+        env.events().publish(
+            (Symbol::new(&env, "RewardIssued"),),
+            (
+                Symbol::new(&env, "FirstPayment"),
+                agreement_id,
+                tenant,
+                amount,
+            ),
+        );
+    }
+
+    pub fn reward_review(env: Env, caller: Address, agreement_id: BytesN<32>, reviewer: Address) {
+        Self::check_not_paused(&env);
+        Self::require_minter(&env, &caller);
+
+        // One-claim-per-(agreement, reviewer)
+        if env.storage().persistent().has(&DataKey::ClaimReview(
+            agreement_id.clone(),
+            reviewer.clone(),
+        )) {
+            return;
+        }
+
+        let cfg = Self::emission_reward_config(&env);
+        if cfg.review_reward <= 0 {
+            return;
+        }
+
+        let amount = Self::apply_multiplier(&env, &reviewer, cfg.review_reward);
+        Self::grant_reward(&env, &reviewer, amount);
+        Self::append_reward(&env, &reviewer, "Review", &agreement_id, amount);
+        env.storage().persistent().set(
+            &DataKey::ClaimReview(agreement_id.clone(), reviewer.clone()),
+            &true,
+        );
+        env.events().publish(
+            (Symbol::new(&env, "RewardIssued"),),
+            (Symbol::new(&env, "Review"), agreement_id, reviewer, amount),
+        );
+    }
+
+    /// Cashback on a monthly rent payment, called by EscrowManager from `pay_rent`.
+    /// Unlike the other rewards, only the configured escrow may issue it.
+    pub fn reward_rent_payment(
+        env: Env,
+        caller: Address,
+        agreement_id: BytesN<32>,
+        tenant: Address,
+        rent_amount: i128,
+    ) {
+        Self::check_not_paused(&env);
+        caller.require_auth();
+        if caller != Self::require_escrow(&env) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+
+        let amount = rent_amount * (Self::get_cashback_rate(env.clone()) as i128) / 10_000;
+        if amount <= 0 {
+            return;
+        }
+
+        Self::grant_reward(&env, &tenant, amount);
+        Self::append_reward(&env, &tenant, "RentCashback", &agreement_id, amount);
+        env.events().publish(
+            (Symbol::new(&env, "RewardIssued"),),
+            (
+                Symbol::new(&env, "RentCashback"),
+                agreement_id,
+                tenant,
+                amount,
+            ),
+        );
+    }
+
+    /// Rent cashback rate in basis points (default 50 = 0.5%).
+    pub fn set_cashback_rate(env: Env, rate_bps: u32) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        if rate_bps as i128 > BPS_DENOMINATOR {
+            panic_with_error!(&env, Error::InvalidRate);
+        }
+        let mut cfg = Self::reward_config(&env);
+        cfg.rent_cashback_bps = rate_bps;
+        env.storage().instance().set(&DataKey::RewardConfig, &cfg);
+        env.storage()
+            .instance()
+            .set(&DataKey::CashbackRateBps, &rate_bps);
+
+        env.events()
+            .publish((Symbol::new(&env, "CashbackRateSet"),), rate_bps);
+    }
+
+    pub fn get_cashback_rate(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CashbackRateBps)
+            .unwrap_or_else(|| Self::reward_config(&env).rent_cashback_bps)
+    }
+
+    // --- Referrals ---
+
+    /// Called by the referral registry once a referee completes their first
+    /// rental. Returns the amount paid to the referrer.
+    pub fn reward_referral(
+        env: Env,
+        agreement_id: BytesN<32>,
+        referrer: Address,
+        referee: Address,
+    ) -> i128 {
+        Self::check_not_paused(&env);
+        let registry: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::ReferralRegistry)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Unauthorized));
+        registry.require_auth();
+
+        let cfg = Self::reward_config(&env);
+        for (to, amount) in [
+            (&referrer, cfg.referral_reward),
+            (&referee, cfg.referee_welcome_reward),
+        ] {
+            if amount > 0 {
+                Self::grant_reward(&env, to, amount);
+                Self::append_reward(&env, to, "Referral", &agreement_id, amount);
+            }
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "ReferralRewardIssued"),),
+            (
+                referrer,
+                referee,
+                cfg.referral_reward,
+                cfg.referee_welcome_reward,
+            ),
+        );
+
+        cfg.referral_reward.max(0)
+    }
+
+    // Called once mutual reviews are complete.
+    // Token contract fetches the agreement and mints bonus to both tenant and landlord.
+    pub fn reward_mutual_review(env: Env, caller: Address, agreement_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+        Self::require_minter(&env, &caller);
+
+        // One-claim-per-agreement
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::ClaimMutual(agreement_id.clone()))
+        {
+            return;
+        }
+
+        let cfg = Self::reward_config(&env);
+        if cfg.mutual_review_bonus <= 0 {
+            return;
+        }
+
+        let agreement_contract: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::AgreementContract)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::AgreementContractNotSet));
+        let agreement_client = RentalAgreementClient::new(&env, &agreement_contract);
+        let a = agreement_client.get_agreement(&agreement_id);
+
+        Self::grant_reward(&env, &a.tenant, cfg.mutual_review_bonus);
+        Self::grant_reward(&env, &a.landlord, cfg.mutual_review_bonus);
+        for party in [&a.tenant, &a.landlord] {
+            Self::append_reward(
+                &env,
+                party,
+                "MutualReview",
+                &agreement_id,
+                cfg.mutual_review_bonus,
+            );
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::ClaimMutual(agreement_id.clone()), &true);
+
+        env.events().publish(
+            (Symbol::new(&env, "RewardIssued"),),
+            (
+                Symbol::new(&env, "MutualReview"),
+                agreement_id.clone(),
+                a.tenant,
+                cfg.mutual_review_bonus,
+            ),
+        );
+        env.events().publish(
+            (Symbol::new(&env, "RewardIssued"),),
+            (
+                Symbol::new(&env, "MutualReview"),
+                agreement_id,
+                a.landlord,
+                cfg.mutual_review_bonus,
+            ),
+        );
+    }
+
+    // -----------------------------
+    // Internals
+    // -----------------------------
+
+    fn check_not_paused(env: &Env) {
+        let paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        if paused {
+            panic_with_error!(env, Error::ContractPaused);
+        }
+    }
+
+    fn clear_pending_admin(env: &Env) {
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        env.storage()
+            .instance()
+            .remove(&DataKey::AdminTransferExpiry);
+    }
+
+    fn require_admin(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized))
+    }
+
+    fn metadata(env: &Env) -> Metadata {
+        env.storage().instance().get(&DataKey::Metadata).unwrap()
+    }
+
+    fn reward_config(env: &Env) -> RewardConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::RewardConfig)
+            .unwrap()
+    }
+
+    // RewardConfig with the emission schedule's current epoch amounts applied.
+    fn emission_reward_config(env: &Env) -> RewardConfig {
+        let mut cfg = Self::reward_config(env);
+        if let Some(schedule) = Self::get_emission_schedule(env.clone()) {
+            cfg.first_payment_reward = schedule.epoch_first_payment_reward;
+            cfg.review_reward = schedule.epoch_review_reward;
+        }
+        cfg
+    }
+
+    // Rewards vest linearly instead of being minted outright. Any amount already
+    // vested is released first, then the unvested remainder plus the new reward
+    // restart on a fresh schedule.
+    fn grant_reward(env: &Env, to: &Address, amount: i128) {
+        let duration_seconds = (Self::get_vesting_duration(env.clone()) as u64) * SECONDS_PER_DAY;
+        if duration_seconds == 0 {
+            Self::do_mint(env, to, amount);
+            return;
+        }
+
+        if Self::rewards_expired(env, to) {
+            Self::drop_expired_rewards(env, to);
+        } else {
+            Self::release_vested(env, to);
+        }
+        let current = Self::vesting(env, to);
+        let now = env.ledger().timestamp();
+        let schedule = VestingSchedule {
+            total: current.total - current.released + amount,
+            released: 0,
+            start_timestamp: now,
+            duration_seconds,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Vesting(to.clone()), &schedule);
+
+        let expiry_days = Self::get_reward_expiry_days(env.clone()) as u64;
+        if expiry_days > 0 {
+            let expires_at = now + duration_seconds + expiry_days * SECONDS_PER_DAY;
+            env.storage()
+                .persistent()
+                .set(&DataKey::RewardExpiry(to.clone()), &expires_at);
+        } else {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::RewardExpiry(to.clone()));
+        }
+    }
+
+    fn rewards_expired(env: &Env, owner: &Address) -> bool {
+        let expires_at = Self::get_reward_expiry(env.clone(), owner.clone());
+        expires_at > 0 && env.ledger().timestamp() >= expires_at
+    }
+
+    fn drop_expired_rewards(env: &Env, owner: &Address) -> i128 {
+        let schedule = Self::vesting(env, owner);
+        let unclaimed = schedule.total - schedule.released;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Vesting(owner.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RewardExpiry(owner.clone()));
+
+        env.events().publish(
+            (Symbol::new(env, "RewardsExpired"),),
+            (owner.clone(), unclaimed),
+        );
+        unclaimed
+    }
+
+    fn release_vested(env: &Env, owner: &Address) -> i128 {
+        let mut schedule = Self::vesting(env, owner);
+        let claimable = Self::vested_amount(env, &schedule) - schedule.released;
+        if claimable <= 0 {
+            return 0;
+        }
+
+        schedule.released += claimable;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Vesting(owner.clone()), &schedule);
+        Self::do_mint(env, owner, claimable);
+        claimable
+    }
+
+    fn vested_amount(env: &Env, schedule: &VestingSchedule) -> i128 {
+        let elapsed = env
+            .ledger()
+            .timestamp()
+            .saturating_sub(schedule.start_timestamp);
+        if elapsed >= schedule.duration_seconds {
+            return schedule.total;
+        }
+        schedule.total * (elapsed as i128) / (schedule.duration_seconds as i128)
+    }
+
+    fn vesting(env: &Env, owner: &Address) -> VestingSchedule {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Vesting(owner.clone()))
+            .unwrap_or(VestingSchedule {
+                total: 0,
+                released: 0,
+                start_timestamp: 0,
+                duration_seconds: 0,
+            })
+    }
+
+    fn do_transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
+        Self::move_balance(env, from, to, amount);
+
+        env.events().publish(
+            (Symbol::new(env, "Transfer"),),
+            (from.clone(), to.clone(), amount),
+        );
+    }
+
+    fn move_balance(env: &Env, from: &Address, to: &Address, amount: i128) {
+        if amount <= 0 {
+            panic_with_error!(env, Error::InvalidAmount);
+        }
+        Self::check_not_frozen(env, from);
+        Self::check_not_frozen(env, to);
+
+        let from_balance = Self::get_balance(env, from);
+        if Self::liquid_balance(env, from) < amount {
+            panic_with_error!(env, Error::InsufficientBalance);
+        }
+
+        Self::set_balance(env, from, from_balance - amount);
+        let to_balance = Self::get_balance(env, to);
+        Self::set_balance(env, to, to_balance + amount);
+        Self::track_holder(env, to);
+    }
+
+    fn do_mint(env: &Env, to: &Address, amount: i128) {
+        if amount <= 0 {
+            panic_with_error!(env, Error::InvalidAmount);
+        }
+
+        let supply: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalSupply)
+            .unwrap_or(0);
+        let max_supply = Self::get_max_supply(env.clone());
+        if amount > max_supply - supply {
+            panic_with_error!(env, Error::SupplyCapExceeded);
+        }
+
+        let to_balance = Self::get_balance(env, to);
+        Self::set_balance(env, to, to_balance + amount);
+        Self::track_holder(env, to);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalSupply, &(supply + amount));
+    }
+
+    fn holder_count(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::HolderCount)
+            .unwrap_or(0)
+    }
+
+    fn holder_at(env: &Env, index: u32) -> Address {
+        env.storage()
+            .persistent()
+            .get(&DataKey::HolderAt(index))
+            .unwrap()
+    }
+
+    // Returns false only when the holder is missing and the set is full.
+    fn track_holder(env: &Env, holder: &Address) -> bool {
+        let key = DataKey::Holder(holder.clone());
+        if env.storage().persistent().has(&key) {
+            return true;
+        }
+        let count = Self::holder_count(env);
+        if count >= MAX_HOLDERS {
+            return false;
+        }
+        env.storage().persistent().set(&key, &count);
+        env.storage()
+            .persistent()
+            .set(&DataKey::HolderAt(count), holder);
+        env.storage()
+            .instance()
+            .set(&DataKey::HolderCount, &(count + 1));
+        true
+    }
+
+    // Swap-removes the holder at `index`, moving the last holder into its slot.
+    fn untrack_holder(env: &Env, holder: &Address, index: u32) {
+        let last = Self::holder_count(env) - 1;
+        if index != last {
+            let moved = Self::holder_at(env, last);
+            env.storage()
+                .persistent()
+                .set(&DataKey::HolderAt(index), &moved);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Holder(moved), &index);
+        }
+        env.storage().persistent().remove(&DataKey::HolderAt(last));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Holder(holder.clone()));
+        env.storage().instance().set(&DataKey::HolderCount, &last);
+    }
+
+    fn do_burn(env: &Env, from: &Address, amount: i128) {
+        if amount <= 0 {
+            panic_with_error!(env, Error::InvalidAmount);
+        }
+
+        let from_balance = Self::get_balance(env, from);
+        if Self::liquid_balance(env, from) < amount {
+            panic_with_error!(env, Error::InsufficientBalance);
+        }
+
+        Self::set_balance(env, from, from_balance - amount);
+        let supply: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalSupply)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalSupply, &(supply - amount));
+    }
+
+    fn set_frozen(env: &Env, target: Address, frozen: bool) {
+        Self::check_not_paused(env);
+
+        let admin = Self::require_admin(env);
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Frozen(target.clone()), &frozen);
+
+        let name = if frozen {
+            "AddressFrozen"
+        } else {
+            "AddressUnfrozen"
+        };
+        env.events()
+            .publish((Symbol::new(env, name),), (target, admin));
+    }
+
+    fn check_not_frozen(env: &Env, address: &Address) {
+        if Self::is_frozen(env.clone(), address.clone()) {
+            panic_with_error!(env, Error::AddressFrozen);
+        }
+    }
+
+    fn reward_history(env: &Env, address: &Address) -> Vec<RewardEvent> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RewardHistory(address.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    // Appends to the address's history, dropping the oldest entry when full.
+    fn append_reward(
+        env: &Env,
+        address: &Address,
+        reward_type: &str,
+        agreement_id: &BytesN<32>,
+        amount: i128,
+    ) {
+        let mut history = Self::reward_history(env, address);
+        if history.len() >= MAX_REWARD_HISTORY {
+            history.pop_front();
+        }
+        history.push_back(RewardEvent {
+            reward_type: Symbol::new(env, reward_type),
+            agreement_id: agreement_id.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::RewardHistory(address.clone()), &history);
+    }
+
+    fn pending_discount(env: &Env, user: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingDiscount(user.clone()))
+            .unwrap_or(0)
+    }
+
+    fn minters(env: &Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinterRole)
+            .unwrap_or(Vec::new(env))
+    }
+
+    // Reward issuance is limited to the admin, registered minter contracts and
+    // the configured escrow, which was trusted before the minter role existed.
+    fn require_minter(env: &Env, caller: &Address) {
+        caller.require_auth();
+        let escrow: Option<Address> = env.storage().instance().get(&DataKey::EscrowContract);
+        if *caller != Self::require_admin(env)
+            && escrow.as_ref() != Some(caller)
+            && !Self::minters(env).contains(caller)
+        {
+            panic_with_error!(env, Error::Unauthorized);
+        }
+    }
+
+    fn require_escrow(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::EscrowContract)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized))
+    }
+
+    fn streak(env: &Env, address: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PaymentStreak(address.clone()))
+            .unwrap_or(0)
+    }
+
+    fn set_streak(env: &Env, address: &Address, streak: u32) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::PaymentStreak(address.clone()), &streak);
+        env.events().publish(
+            (Symbol::new(env, "PaymentStreakUpdated"),),
+            (address.clone(), streak),
+        );
+    }
+
+    fn apply_multiplier(env: &Env, address: &Address, base: i128) -> i128 {
+        let multiplier = Self::get_streak_multiplier(env.clone(), address.clone());
+        base * (multiplier as i128) / 100
+    }
+
+    fn staked_balance(env: &Env, staker: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::StakedBalance(staker.clone()))
+            .unwrap_or(0)
+    }
+
+    // Staked and time-locked tokens stay in the balance but cannot be moved or burned.
+    fn liquid_balance(env: &Env, owner: &Address) -> i128 {
+        Self::get_balance(env, owner)
+            - Self::staked_balance(env, owner)
+            - Self::locked_balance(env, owner)
+    }
+
+    fn locked_balance(env: &Env, owner: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LockedBalance(owner.clone()))
+            .unwrap_or(0)
+    }
+
+    fn set_locked_balance(env: &Env, owner: &Address, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::LockedBalance(owner.clone()), &amount);
+    }
+
+    // Global staked/locked accumulators behind get_circulating_supply.
+    fn adjust_total(env: &Env, key: &DataKey, delta: i128) {
+        let total: i128 = env.storage().instance().get(key).unwrap_or(0);
+        env.storage().instance().set(key, &(total + delta));
+    }
+
+    fn pay_lock_yield(env: &Env, owner: &Address) -> i128 {
+        let mut locks = Self::get_locks(env.clone(), owner.clone());
+        let accrued = Self::accrue_lock_yield(env, &mut locks);
+        if accrued <= 0 {
+            return 0;
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Locks(owner.clone()), &locks);
+        Self::mint_lock_yield(env, owner, accrued)
+    }
+
+    // locked * rate * elapsed / year / 10_000, with elapsed capped at unlock.
+    // Advances each entry's yield_from; the caller persists the locks.
+    fn accrue_lock_yield(env: &Env, locks: &mut Vec<LockEntry>) -> i128 {
+        let now = env.ledger().timestamp();
+        let rate = Self::get_lock_yield_rate(env.clone()) as i128;
+        let mut total: i128 = 0;
+        for i in 0..locks.len() {
+            let mut entry = locks.get_unchecked(i);
+            let until = now.min(entry.unlock_at);
+            if until <= entry.yield_from {
+                continue;
+            }
+            let elapsed = (until - entry.yield_from) as i128;
+            total += entry.amount * rate * elapsed / SECONDS_PER_YEAR as i128 / BPS_DENOMINATOR;
+            entry.yield_from = until;
+            locks.set(i, entry);
+        }
+        total
+    }
+
+    // Mints as much of the accrued yield as the supply cap still allows.
+    fn mint_lock_yield(env: &Env, owner: &Address, accrued: i128) -> i128 {
+        let headroom = Self::get_max_supply(env.clone()) - Self::total_supply(env.clone());
+        let paid = accrued.min(headroom);
+        if paid <= 0 {
+            return 0;
+        }
+
+        Self::do_mint(env, owner, paid);
+        env.events().publish(
+            (Symbol::new(env, "LockYieldClaimed"),),
+            (owner.clone(), paid),
+        );
+        paid
+    }
+
+    fn allowance(env: &Env, owner: &Address, spender: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Allowance(owner.clone(), spender.clone()))
+            .unwrap_or(0)
+    }
+
+    fn set_allowance(env: &Env, owner: &Address, spender: &Address, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Allowance(owner.clone(), spender.clone()), &amount);
+        env.events().publish(
+            (Symbol::new(env, "Approval"),),
+            (owner.clone(), spender.clone(), amount),
+        );
+    }
+
+    // Records the current balance for the latest snapshot, once per address.
+    fn checkpoint_balance(env: &Env, owner: &Address) {
+        let Some(seq) = env
+            .storage()
+            .instance()
+            .get::<_, u64>(&DataKey::LastSnapshotSeq)
+        else {
+            return;
+        };
+        let key = DataKey::SnapshotBalance(owner.clone(), seq);
+        if !env.storage().persistent().has(&key) {
+            env.storage()
+                .persistent()
+                .set(&key, &Self::get_balance(env, owner));
+            let mut seqs = Self::snapshot_seqs(env, owner);
+            seqs.push_back(seq);
+            env.storage()
+                .persistent()
+                .set(&DataKey::SnapshotSeqs(owner.clone()), &seqs);
+        }
+    }
+
+    fn snapshot_seqs(env: &Env, owner: &Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SnapshotSeqs(owner.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn set_balance(env: &Env, owner: &Address, amount: i128) {
+        Self::checkpoint_balance(env, owner);
+        let old_tier = Self::tier_for(env, Self::get_balance(env, owner));
+        env.storage()
+            .persistent()
+            .set(&DataKey::Balance(owner.clone()), &amount);
+
+        let new_tier = Self::tier_for(env, amount);
+        if (new_tier as u32) > (old_tier as u32) {
+            env.events().publish(
+                (Symbol::new(env, "TierUpgraded"),),
+                (owner.clone(), new_tier),
+            );
+        }
+    }
+
+    fn tier_config(env: &Env) -> TierConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::TierThresholds)
+            .unwrap_or(TierConfig {
+                silver: 1_000 * ONE_BRIQ,
+                gold: 5_000 * ONE_BRIQ,
+                platinum: 25_000 * ONE_BRIQ,
+            })
+    }
+
+    fn tier_for(env: &Env, balance: i128) -> LoyaltyTier {
+        let cfg = Self::tier_config(env);
+        if balance >= cfg.platinum {
+            LoyaltyTier::Platinum
+        } else if balance >= cfg.gold {
+            LoyaltyTier::Gold
+        } else if balance >= cfg.silver {
+            LoyaltyTier::Silver
+        } else {
+            LoyaltyTier::Bronze
+        }
+    }
+
+    fn get_balance(env: &Env, owner: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Balance(owner.clone()))
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke};
+    use soroban_sdk::{IntoVal, Val};
+
+    #[test]
+    fn mint_and_transfer() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        client.mint(&alice, &100);
+        assert_eq!(client.balance_of(&alice), 100);
+        // transfer requires "from" now
+        client.transfer(&alice, &bob, &25);
+        assert_eq!(client.balance_of(&alice), 75);
+        assert_eq!(client.balance_of(&bob), 25);
+    }
+
+    #[test]
+    fn allowances_and_transfer_from() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+        client.mint(&alice, &100);
+        client.approve(&alice, &bob, &30);
+        client.transfer_from(&bob, &alice, &carol, &20);
+        assert_eq!(client.balance_of(&alice), 80);
+        assert_eq!(client.balance_of(&carol), 20);
+        assert_eq!(client.get_allowance(&alice, &bob), 10);
+        assert_eq!(
+            client.try_transfer_from(&bob, &alice, &carol, &11),
+            Err(Ok(Error::InsufficientAllowance.into()))
+        );
+
+        client.increase_allowance(&alice, &bob, &5);
+        client.transfer_from(&bob, &alice, &carol, &15);
+        assert_eq!(client.get_allowance(&alice, &bob), 0);
+        assert_eq!(
+            client.try_approve(&alice, &bob, &-1),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+    }
+
+    #[test]
+    fn max_supply_caps_minting() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        client.mint(&alice, &100);
+        assert_eq!(client.get_max_supply(), i128::MAX);
+        client.set_max_supply(&150);
+        assert_eq!(
+            client.try_mint(&alice, &51),
+            Err(Ok(Error::SupplyCapExceeded.into()))
+        );
+        client.mint(&alice, &50);
+        assert_eq!(client.total_supply(), 150);
+
+        // A cap can only be raised once set.
+        assert_eq!(
+            client.try_set_max_supply(&149),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+        client.set_max_supply(&200);
+        assert_eq!(
+            client.try_set_max_supply(&180),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+    }
+
+    #[test]
+    fn frozen_addresses_cannot_move_tokens() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        client.mint(&alice, &100);
+        client.freeze_address(&alice);
+        assert!(client.is_frozen(&alice));
+        assert_eq!(
+            client.try_transfer(&alice, &bob, &10),
+            Err(Ok(Error::AddressFrozen.into()))
+        );
+        assert_eq!(
+            client.try_stake(&alice, &10),
+            Err(Ok(Error::AddressFrozen.into()))
+        );
+        // Minted rewards still arrive.
+        client.mint(&alice, &5);
+        assert_eq!(client.balance_of(&alice), 105);
+
+        client.unfreeze_address(&alice);
+        assert!(!client.is_frozen(&alice));
+        client.transfer(&alice, &bob, &10);
+        assert_eq!(client.balance_of(&bob), 10);
+    }
+
+    #[test]
+    fn snapshot_keeps_balances_from_before_changes() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        client.mint(&alice, &100);
+        env.ledger().with_mut(|l| l.sequence_number = 5);
+        let seq = client.take_snapshot(&admin);
+        assert_eq!(client.get_last_snapshot_seq(), seq);
+
+        client.transfer(&alice, &bob, &30);
+        client.mint(&bob, &10);
+        assert_eq!(client.get_snapshot_balance(&alice, &seq), 100);
+        assert_eq!(client.get_snapshot_balance(&bob, &seq), 0);
+        assert_eq!(client.balance_of(&bob), 40);
+        assert_eq!(
+            client.try_take_snapshot(&bob),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+    }
+
+    #[test]
+    fn batch_transfer_pays_every_recipient() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+        client.mint(&alice, &100);
+        client.batch_transfer(
+            &alice,
+            &Vec::from_array(&env, [bob.clone(), carol.clone()]),
+            &Vec::from_array(&env, [30, 20]),
+        );
+        assert_eq!(client.balance_of(&alice), 50);
+        assert_eq!(client.balance_of(&bob), 30);
+        assert_eq!(client.balance_of(&carol), 20);
+
+        assert_eq!(
+            client.try_batch_transfer(
+                &alice,
+                &Vec::from_array(&env, [bob.clone()]),
+                &Vec::from_array(&env, [10, 10]),
+            ),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+        // The whole batch is checked against the balance up front.
+        assert_eq!(
+            client.try_batch_transfer(
+                &alice,
+                &Vec::from_array(&env, [bob.clone(), carol.clone()]),
+                &Vec::from_array(&env, [40, 11]),
+            ),
+            Err(Ok(Error::InsufficientBalance.into()))
+        );
+
+        let mut recipients = Vec::new(&env);
+        let mut amounts = Vec::new(&env);
+        for _ in 0..=MAX_BATCH_RECIPIENTS {
+            recipients.push_back(bob.clone());
+            amounts.push_back(1);
+        }
+        assert_eq!(
+            client.try_batch_transfer(&alice, &recipients, &amounts),
+            Err(Ok(Error::BatchTooLarge.into()))
+        );
+        assert_eq!(client.balance_of(&alice), 50);
+    }
+
+    #[test]
+    fn reward_history_records_each_reward() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        let agreement_id = BytesN::from_array(&env, &[7; 32]);
+        client.reward_review(&admin, &agreement_id, &alice);
+        client.reward_review(&admin, &agreement_id, &alice);
+        assert_eq!(client.get_reward_history_count(&alice), 1);
+        assert_eq!(client.get_vesting_info(&alice).total, 25_000_0000);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.reward_first_payment(&admin, &agreement_id, &alice);
+        let history = client.get_reward_history(&alice);
+        assert_eq!(history.len(), 2);
+        let latest = history.get_unchecked(1);
+        assert_eq!(latest.reward_type, Symbol::new(&env, "FirstPayment"));
+        assert_eq!(latest.agreement_id, agreement_id);
+        assert_eq!(latest.timestamp, 1_000);
+    }
+
+    #[test]
+    fn loyalty_tier_follows_balance() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        assert_eq!(client.get_loyalty_tier(&alice), LoyaltyTier::Bronze);
+        client.mint(&alice, &(1_000 * ONE_BRIQ));
+        assert_eq!(client.get_loyalty_tier(&alice), LoyaltyTier::Silver);
+        assert_eq!(
+            client.get_tier_benefits(&LoyaltyTier::Silver),
+            TierBenefits {
+                fee_discount_bps: 250,
+                reward_multiplier: 110,
+            }
+        );
+
+        client.set_tier_config(&TierConfig {
+            silver: 100 * ONE_BRIQ,
+            gold: 500 * ONE_BRIQ,
+            platinum: 1_000 * ONE_BRIQ,
+        });
+        assert_eq!(client.get_loyalty_tier(&alice), LoyaltyTier::Platinum);
+        assert_eq!(
+            client.try_set_tier_config(&TierConfig {
+                silver: 100 * ONE_BRIQ,
+                gold: 100 * ONE_BRIQ,
+                platinum: 1_000 * ONE_BRIQ,
+            }),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+    }
+
+    #[test]
+    fn holders_can_burn_their_own_tokens() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        client.mint(&alice, &100);
+        client.self_burn(&alice, &30, &Some(String::from_str(&env, "retire")));
+        client.self_burn(&alice, &10, &None);
+        assert_eq!(client.balance_of(&alice), 60);
+        assert_eq!(client.total_supply(), 60);
+
+        // Staked tokens are not burnable.
+        client.stake(&alice, &50);
+        assert_eq!(
+            client.try_self_burn(&alice, &11, &None),
+            Err(Ok(Error::InsufficientBalance.into()))
+        );
+        let reason = String::from_bytes(&env, &[b'x'; MAX_BURN_REASON_LEN as usize + 1]);
+        assert_eq!(
+            client.try_self_burn(&alice, &1, &Some(reason)),
+            Err(Ok(Error::InvalidReason.into()))
+        );
+    }
+
+    #[test]
+    fn fee_discount_redemption_burns_whole_bps() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        let escrow = Address::generate(&env);
+        client.set_escrow_contract(&escrow);
+        client.mint(&alice, &(30_000 * ONE_BRIQ));
+
+        // 100 BRIQ per bps; the 50 BRIQ remainder stays with the holder.
+        assert_eq!(
+            client.redeem_for_fee_discount(&alice, &(1_050 * ONE_BRIQ)),
+            10
+        );
+        assert_eq!(client.balance_of(&alice), 29_000 * ONE_BRIQ);
+
+        // Capped at 200 bps pending.
+        assert_eq!(
+            client.redeem_for_fee_discount(&alice, &(25_000 * ONE_BRIQ)),
+            190
+        );
+        assert_eq!(client.balance_of(&alice), 10_000 * ONE_BRIQ);
+        assert_eq!(client.get_pending_discount(&alice), 200);
+        assert_eq!(
+            client.try_redeem_for_fee_discount(&alice, &(100 * ONE_BRIQ)),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+
+        assert_eq!(client.consume_pending_discount(&alice), 200);
+        assert_eq!(client.get_pending_discount(&alice), 0);
+        assert_eq!(client.consume_pending_discount(&alice), 0);
+    }
+
+    #[test]
+    fn referral_rewards_need_the_registry() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let registry = Address::generate(&env);
+        let referrer = Address::generate(&env);
+        let referee = Address::generate(&env);
+        let agreement_id = BytesN::from_array(&env, &[5; 32]);
+        assert_eq!(
+            client.try_reward_referral(&agreement_id, &referrer, &referee),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+
+        client.set_referral_registry(&registry);
+        assert_eq!(
+            client.reward_referral(&agreement_id, &referrer, &referee),
+            50_000_0000
+        );
+        assert_eq!(client.get_vesting_info(&referrer).total, 50_000_0000);
+        assert_eq!(client.get_vesting_info(&referee).total, 10_000_0000);
+        assert_eq!(
+            client
+                .get_reward_history(&referee)
+                .get_unchecked(0)
+                .reward_type,
+            Symbol::new(&env, "Referral")
+        );
+    }
+
+    #[test]
+    fn decrease_allowance_clamps_at_zero() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
         let bob = Address::generate(&env);
         client.mint(&alice, &100);
-        assert_eq!(client.balance_of(&alice), 100);
-        // transfer requires "from" now
-        client.transfer(&alice, &bob, &25);
-        assert_eq!(client.balance_of(&alice), 75);
-        assert_eq!(client.balance_of(&bob), 25);
+        client.approve(&alice, &bob, &30);
+        client.transfer_from(&bob, &alice, &bob, &20);
+
+        // The owner meant to revoke 30 but 20 was already spent.
+        client.decrease_allowance(&alice, &bob, &30);
+        assert_eq!(client.get_allowance(&alice, &bob), 0);
+        assert_eq!(
+            client.try_decrease_allowance(&alice, &bob, &0),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+        assert_eq!(
+            client.try_increase_allowance(&alice, &bob, &0),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+    }
+
+    #[test]
+    fn emission_schedule_halves_rewards() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        assert_eq!(
+            client.try_advance_epoch(),
+            Err(Ok(Error::NoEmissionSchedule.into()))
+        );
+        assert_eq!(
+            client.try_set_emission_schedule(&SECONDS_PER_DAY, &1, &1, &3, &2),
+            Err(Ok(Error::InvalidEmissionSchedule.into()))
+        );
+
+        client.set_emission_schedule(&SECONDS_PER_DAY, &10_000_0000, &20_000_0000, &1, &2);
+        client.reward_review(&admin, &BytesN::from_array(&env, &[1; 32]), &alice);
+        assert_eq!(client.get_vesting_info(&alice).total, 20_000_0000);
+        assert_eq!(
+            client.try_advance_epoch(),
+            Err(Ok(Error::EpochNotElapsed.into()))
+        );
+
+        env.ledger().with_mut(|l| l.timestamp += SECONDS_PER_DAY);
+        client.advance_epoch();
+        client.reward_review(&admin, &BytesN::from_array(&env, &[2; 32]), &bob);
+        assert_eq!(client.get_vesting_info(&bob).total, 10_000_0000);
+        assert_eq!(
+            client
+                .get_emission_schedule()
+                .unwrap()
+                .epoch_first_payment_reward,
+            5_000_0000
+        );
+    }
+
+    #[test]
+    fn circulating_supply_excludes_staked_and_locked() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        client.mint(&alice, &100);
+        client.mint(&bob, &50);
+        client.stake(&alice, &60);
+        client.lock_tokens(&bob, &20, &(env.ledger().timestamp() + SECONDS_PER_DAY));
+        assert_eq!(client.get_total_staked(), 60);
+        assert_eq!(client.get_total_locked(), 20);
+        assert_eq!(client.get_circulating_supply(), 70);
+
+        env.ledger().with_mut(|l| l.timestamp += STAKE_LOCK_SECONDS);
+        client.unstake(&alice, &10);
+        client.unlock_tokens(&bob);
+        assert_eq!(client.get_total_staked(), 50);
+        assert_eq!(client.get_total_locked(), 0);
+        assert_eq!(client.get_circulating_supply(), client.total_supply() - 50);
+    }
+
+    #[test]
+    fn rental_badges_are_issued_once_per_agreement() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        let agreement_id = BytesN::from_array(&env, &[7; 32]);
+        assert_eq!(
+            client.try_issue_rental_badge(&agreement_id, &alice, &12, &0),
+            Err(Ok(Error::AgreementContractNotSet.into()))
+        );
+
+        client.set_agreement_contract(&Address::generate(&env));
+        env.ledger().with_mut(|l| l.timestamp = 500);
+        client.issue_rental_badge(&agreement_id, &alice, &12, &4);
+        assert_eq!(
+            client.try_issue_rental_badge(&agreement_id, &alice, &12, &4),
+            Err(Ok(Error::BadgeAlreadyIssued.into()))
+        );
+        client.issue_rental_badge(&BytesN::from_array(&env, &[9; 32]), &alice, &6, &0);
+        assert_eq!(client.get_rental_badge_count(&alice), 2);
+        assert_eq!(
+            client.get_rental_badges(&alice).get_unchecked(0),
+            ProofOfRentalBadge {
+                agreement_id,
+                months_completed: 12,
+                final_rating: 4,
+                issued_at: 500,
+            }
+        );
+        // Badges never touch the token balance.
+        assert_eq!(client.balance_of(&alice), 0);
+    }
+
+    #[test]
+    fn streak_multiplier_tiers_and_reset() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let escrow = Address::generate(&env);
+        let tenant = Address::generate(&env);
+        client.set_escrow_contract(&escrow);
+
+        // (streak, multiplier) at the edges of each tier.
+        let tiers = [
+            (0, 100),
+            (2, 100),
+            (3, 125),
+            (5, 125),
+            (6, 150),
+            (11, 150),
+            (12, 200),
+            (13, 200),
+        ];
+        for (streak, multiplier) in tiers {
+            while client.get_payment_streak(&tenant) < streak {
+                client.record_on_time_payment(&tenant);
+            }
+            assert_eq!(client.get_payment_streak(&tenant), streak);
+            assert_eq!(client.get_streak_multiplier(&tenant), multiplier);
+        }
+
+        client.record_missed_payment(&tenant);
+        assert_eq!(client.get_payment_streak(&tenant), 0);
+        assert_eq!(client.get_streak_multiplier(&tenant), 100);
+        client.record_on_time_payment(&tenant);
+        assert_eq!(client.get_payment_streak(&tenant), 1);
+    }
+
+    #[test]
+    fn only_the_escrow_updates_streaks() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let tenant = Address::generate(&env);
+        assert_eq!(
+            client.try_record_on_time_payment(&tenant),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+        assert_eq!(
+            client.try_record_missed_payment(&tenant),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+
+        let escrow = Address::generate(&env);
+        client.set_escrow_contract(&escrow);
+        client.record_on_time_payment(&tenant);
+
+        let stranger = Address::generate(&env);
+        let args: Vec<Val> = (tenant.clone(),).into_val(&env);
+        env.mock_auths(&[MockAuth {
+            address: &stranger,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "record_on_time_payment",
+                args: args.clone(),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client.try_record_on_time_payment(&tenant).is_err());
+        env.mock_auths(&[MockAuth {
+            address: &stranger,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "record_missed_payment",
+                args,
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client.try_record_missed_payment(&tenant).is_err());
+        assert_eq!(client.get_payment_streak(&tenant), 1);
+    }
+
+    #[test]
+    fn minter_role_gates_rewards() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let minter = Address::generate(&env);
+        let escrow = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let agreement_id = BytesN::from_array(&env, &[8; 32]);
+        assert_eq!(
+            client.try_reward_review(&minter, &agreement_id, &alice),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+        client.add_minter(&minter);
+        assert!(client.is_minter(&minter));
+        assert_eq!(
+            client.try_add_minter(&minter),
+            Err(Ok(Error::AlreadyMinter.into()))
+        );
+        client.reward_review(&minter, &agreement_id, &alice);
+        assert_eq!(client.get_reward_history_count(&alice), 1);
+
+        // Rent cashback stays with the escrow, even for registered minters.
+        assert_eq!(
+            client.try_reward_rent_payment(&minter, &agreement_id, &alice, &10_000),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+        client.set_escrow_contract(&escrow);
+        client.reward_rent_payment(&escrow, &agreement_id, &alice, &10_000);
+        assert_eq!(client.get_reward_history_count(&alice), 2);
+
+        // The configured escrow predates the minter role and keeps its rights.
+        client.reward_first_payment(&escrow, &agreement_id, &alice);
+        assert_eq!(client.get_reward_history_count(&alice), 3);
+
+        client.remove_minter(&minter);
+        assert!(!client.is_minter(&minter));
+        assert_eq!(
+            client.try_remove_minter(&minter),
+            Err(Ok(Error::NotMinter.into()))
+        );
+    }
+
+    #[test]
+    fn snapshot_weights_survive_later_snapshots() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let governance = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        client.mint(&alice, &100);
+        client.stake(&alice, &40);
+        assert_eq!(client.get_governance_vote_weight(&alice), 100);
+
+        assert_eq!(
+            client.try_take_snapshot(&governance),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+        client.set_governance_contract(&governance);
+        env.ledger().with_mut(|l| l.sequence_number = 10);
+        let first = client.take_snapshot(&governance);
+
+        env.ledger().with_mut(|l| l.sequence_number = 20);
+        let second = client.take_snapshot(&admin);
+        client.transfer(&alice, &bob, &60);
+
+        env.ledger().with_mut(|l| l.sequence_number = 30);
+        let third = client.take_snapshot(&admin);
+        client.transfer(&bob, &alice, &10);
+
+        // Alice's only checkpoint is under the second snapshot, which also
+        // answers for the first one taken before it.
+        assert_eq!(client.get_snapshot_governance_weight(&alice, &first), 100);
+        assert_eq!(client.get_snapshot_governance_weight(&alice, &second), 100);
+        assert_eq!(client.get_snapshot_governance_weight(&alice, &third), 40);
+        assert_eq!(client.get_snapshot_governance_weight(&bob, &first), 0);
+        assert_eq!(client.get_snapshot_governance_weight(&bob, &third), 60);
+        assert_eq!(client.get_snapshot_governance_weight(&bob, &(third + 1)), 0);
+        assert_eq!(client.get_governance_vote_weight(&bob), 50);
+    }
+
+    #[test]
+    fn rent_cashback_follows_configured_rate() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+        client.set_vesting_duration(&0);
+
+        let escrow = Address::generate(&env);
+        let tenant = Address::generate(&env);
+        let agreement_id = BytesN::from_array(&env, &[4; 32]);
+        client.set_escrow_contract(&escrow);
+        assert_eq!(client.get_cashback_rate(), 50);
+        client.reward_rent_payment(&escrow, &agreement_id, &tenant, &10_000);
+        assert_eq!(client.balance_of(&tenant), 50);
+
+        client.set_cashback_rate(&200);
+        assert_eq!(client.get_reward_config().rent_cashback_bps, 200);
+        client.reward_rent_payment(&escrow, &agreement_id, &tenant, &10_000);
+        assert_eq!(client.balance_of(&tenant), 250);
+        assert_eq!(
+            client.try_set_cashback_rate(&10_001),
+            Err(Ok(Error::InvalidRate.into()))
+        );
+
+        // Below one unit of cashback nothing is issued.
+        client.reward_rent_payment(&escrow, &agreement_id, &tenant, &49);
+        assert_eq!(client.get_reward_history_count(&tenant), 2);
+    }
+
+    #[test]
+    fn staked_tokens_are_locked() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        client.mint(&alice, &100);
+        client.stake(&alice, &60);
+        assert_eq!(client.get_staked_balance(&alice), 60);
+        assert_eq!(client.get_liquid_balance(&alice), 40);
+        assert_eq!(
+            client.try_transfer(&alice, &bob, &41),
+            Err(Ok(Error::InsufficientBalance.into()))
+        );
+        assert_eq!(
+            client.try_unstake(&alice, &60),
+            Err(Ok(Error::StakeLocked.into()))
+        );
+
+        env.ledger().with_mut(|l| l.timestamp += STAKE_LOCK_SECONDS);
+        client.unstake(&alice, &60);
+        client.transfer(&alice, &bob, &100);
+        assert_eq!(client.balance_of(&bob), 100);
+    }
+
+    #[test]
+    fn holders_are_indexed_and_pruned() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+        for holder in [&alice, &bob, &carol] {
+            client.mint(holder, &10);
+        }
+        client.transfer(&alice, &bob, &5);
+        assert_eq!(client.get_holder_count(), 3);
+        assert_eq!(
+            client.get_holders(&1, &10),
+            Vec::from_array(&env, [bob.clone(), carol.clone()])
+        );
+
+        client.transfer(&bob, &alice, &15);
+        client.transfer(&carol, &alice, &10);
+        assert_eq!(client.remove_zero_balance_holders(&0, &1), 0);
+        // Bob's slot is refilled by Carol, who is checked next in the same page.
+        assert_eq!(client.remove_zero_balance_holders(&1, &10), 2);
+        assert_eq!(client.get_holders(&0, &10), Vec::from_array(&env, [alice]));
+
+        client.mint(&bob, &1);
+        assert_eq!(client.get_holder_count(), 2);
+        assert_eq!(client.get_holders(&1, &1), Vec::from_array(&env, [bob]));
+    }
+
+    #[test]
+    fn unclaimed_rewards_expire() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+        client.set_reward_expiry_days(&10);
+
+        let alice = Address::generate(&env);
+        let agreement_id = BytesN::from_array(&env, &[3; 32]);
+        client.reward_review(&admin, &agreement_id, &alice);
+        assert_eq!(
+            client.try_expire_unclaimed_rewards(&alice),
+            Err(Ok(Error::RewardNotExpired.into()))
+        );
+
+        env.ledger()
+            .with_mut(|l| l.timestamp += 40 * SECONDS_PER_DAY);
+        assert_eq!(
+            client.try_claim_vested(&alice),
+            Err(Ok(Error::RewardExpired.into()))
+        );
+        assert_eq!(client.expire_unclaimed_rewards(&alice), 25_000_0000);
+        assert_eq!(client.get_vesting_info(&alice).total, 0);
+        assert_eq!(client.total_supply(), 0);
+    }
+
+    #[test]
+    fn vesting_claims_linearly_and_restarts_on_new_rewards() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+        client.set_vesting_duration(&30);
+
+        let alice = Address::generate(&env);
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.reward_review(&admin, &BytesN::from_array(&env, &[1; 32]), &alice);
+
+        env.ledger()
+            .with_mut(|l| l.timestamp += 12 * SECONDS_PER_DAY);
+        assert_eq!(client.claim_vested(&alice), 10_000_0000);
+        assert_eq!(client.balance_of(&alice), 10_000_0000);
+        assert_eq!(client.get_vesting_info(&alice).released, 10_000_0000);
+        assert_eq!(client.claim_vested(&alice), 0);
+
+        // Halfway through, a second reward releases what has vested and puts
+        // the rest on a fresh 30 day schedule with the new reward.
+        env.ledger()
+            .with_mut(|l| l.timestamp += 3 * SECONDS_PER_DAY);
+        client.reward_review(&admin, &BytesN::from_array(&env, &[2; 32]), &alice);
+        let schedule = client.get_vesting_info(&alice);
+        assert_eq!(schedule.start_timestamp, env.ledger().timestamp());
+        assert_eq!(schedule.total, 37_500_0000);
+        assert_eq!(schedule.released, 0);
+        assert_eq!(client.balance_of(&alice), 12_500_0000);
+
+        // The original schedule would have finished by now.
+        env.ledger()
+            .with_mut(|l| l.timestamp += 15 * SECONDS_PER_DAY);
+        assert_eq!(client.claim_vested(&alice), 18_750_0000);
+        env.ledger()
+            .with_mut(|l| l.timestamp += 15 * SECONDS_PER_DAY);
+        assert_eq!(client.claim_vested(&alice), 18_750_0000);
+        assert_eq!(client.balance_of(&alice), 50_000_0000);
+    }
+
+    #[test]
+    fn time_locked_tokens_earn_yield() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        client.mint(&alice, &10_000);
+        let unlock_at = env.ledger().timestamp() + SECONDS_PER_YEAR;
+        client.lock_tokens(&alice, &10_000, &unlock_at);
+        assert_eq!(client.get_locked_balance(&alice), 10_000);
+        assert_eq!(client.get_total_locked(), 10_000);
+        assert_eq!(client.get_circulating_supply(), 0);
+        assert_eq!(
+            client.try_transfer(&alice, &bob, &1),
+            Err(Ok(Error::InsufficientBalance.into()))
+        );
+        assert_eq!(
+            client.try_unlock_tokens(&alice),
+            Err(Ok(Error::TokensLocked.into()))
+        );
+
+        env.ledger()
+            .with_mut(|l| l.timestamp += 2 * SECONDS_PER_YEAR);
+        assert_eq!(client.unlock_tokens(&alice), 10_000);
+        assert_eq!(client.get_locked_balance(&alice), 0);
+        assert_eq!(client.balance_of(&alice), 10_500);
+    }
+
+    #[test]
+    fn supply_cap_does_not_block_unlocking() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BriqToken);
+        let client = BriqTokenClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let alice = Address::generate(&env);
+        client.mint(&alice, &10_000);
+        let unlock_at = env.ledger().timestamp() + SECONDS_PER_YEAR;
+        client.lock_tokens(&alice, &10_000, &unlock_at);
+        client.set_max_supply(&10_200);
+
+        env.ledger().with_mut(|l| l.timestamp = unlock_at);
+        assert_eq!(client.unlock_tokens(&alice), 10_000);
+        assert_eq!(client.get_locked_balance(&alice), 0);
+        assert_eq!(client.balance_of(&alice), 10_200);
+        assert_eq!(client.total_supply(), 10_200);
     }
 }
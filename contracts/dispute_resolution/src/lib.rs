@@ -0,0 +1,869 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error, Address,
+    BytesN, Env, String, Symbol, Vec,
+};
+
+// -----------------------------
+// Cross-contract: RentalAgreement
+// -----------------------------
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AgreementStatus {
+    PendingLandlordApproval,
+    Rejected,
+    Draft,
+    PendingTenantSign,
+    PendingLandlordSign,
+    PendingCoSignerSign,
+    PendingPayment,
+    Active,
+    Completed,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentalAgreement {
+    pub id: BytesN<32>,
+    pub property_id: BytesN<32>,
+    pub landlord: Address,
+    pub tenant: Address,
+    pub monthly_rent: i128,
+    pub security_deposit: i128,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub status: AgreementStatus,
+    pub landlord_signed: bool,
+    pub landlord_signed_at: u64,
+    pub tenant_signed: bool,
+    pub tenant_signed_at: u64,
+    pub deposit_paid: bool,
+    pub deposit_paid_at: u64,
+    pub total_rent_paid: i128,
+    pub months_paid: u32,
+    pub created_at: u64,
+    pub completed_at: u64,
+    pub notice_period_days: u32,
+    pub notice_served_at: u64,
+    pub notice_served_by: Option<Address>,
+    pub document_hash: BytesN<32>,
+    pub co_signer: Option<Address>,
+    pub subletting_allowed: bool,
+    pub custom_clauses: Vec<String>,
+}
+
+#[contractclient(name = "RentalAgreementClient")]
+pub trait RentalAgreementContract {
+    fn get_agreement(agreement_id: BytesN<32>) -> RentalAgreement;
+}
+
+// -----------------------------
+// Cross-contract: EscrowManager
+// -----------------------------
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowAccount {
+    pub agreement_id: BytesN<32>,
+    pub landlord: Address,
+    pub tenant: Address,
+    pub security_deposit_amount: i128,
+    pub security_deposit_held: i128,
+    pub monthly_rent_amount: i128,
+    pub total_rent_received: i128,
+    pub total_rent_released: i128,
+    pub is_deposit_released: bool,
+    pub deposit_released_at: u64,
+    pub created_at: u64,
+}
+
+#[contractclient(name = "EscrowManagerClient")]
+pub trait EscrowManagerContract {
+    fn get_escrow(agreement_id: BytesN<32>) -> EscrowAccount;
+    fn release_deposit_to_landlord(agreement_id: BytesN<32>, amount: i128);
+}
+
+// -----------------------------
+// DisputeResolution contract
+// -----------------------------
+
+const APPEAL_WINDOW_SECONDS: u64 = 7 * 24 * 60 * 60;
+const MAX_REASON_LEN: u32 = 1000;
+const MAX_ACTIVE_DISPUTES_PER_ARBITRATOR: u32 = 10;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    ContractPaused = 1,
+    Unauthorized = 2,
+    AlreadyInitialized = 3,
+
+    DisputeNotFound = 600,
+    NotAgreementParty = 601,
+    DisputeAlreadyOpen = 602,
+    InvalidDisputeState = 603,
+    InvalidRuling = 604,
+    ReasonTooLong = 605,
+    AppealWindowClosed = 606,
+    AppealWindowOpen = 607,
+    NotRegisteredArbitrator = 608,
+    ArbitratorOverloaded = 609,
+    AgreementContractNotSet = 610,
+    EscrowContractNotSet = 611,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeStatus {
+    Filed,
+    UnderReview,
+    Resolved,
+    Appealed,
+}
+
+/// `SplitDeposit(to_tenant, to_landlord)` must add up to the held deposit.
+/// Stored under `DataKey::Ruling` rather than on `Dispute`, since Soroban
+/// can't encode an `Option` of a custom type as a struct field; read it with
+/// `get_ruling`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeRuling {
+    FullDepositToTenant,
+    FullDepositToLandlord,
+    SplitDeposit(i128, i128),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    pub id: BytesN<32>,
+    pub agreement_id: BytesN<32>,
+    pub filer: Address,
+    pub respondent: Address,
+    pub reason: String,
+    pub evidence_hash: BytesN<32>,
+    pub status: DisputeStatus,
+    pub filed_at: u64,
+    pub resolved_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Evidence {
+    pub submitter: Address,
+    pub evidence_hash: BytesN<32>,
+    pub submitted_at: u64,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Paused,
+    AgreementContract,
+    EscrowContract,
+    Dispute(BytesN<32>),
+    DisputesByAgreement(BytesN<32>),
+    OpenDispute(BytesN<32>),
+    Arbitrator(BytesN<32>),
+    Evidence(BytesN<32>),
+    WasAppealed(BytesN<32>),
+    Settled(BytesN<32>),
+    Ruling(BytesN<32>),
+    ArbitratorRegistry,
+    ActiveDisputeCount(Address),
+}
+
+#[contract]
+pub struct DisputeResolution;
+
+#[contractimpl]
+impl DisputeResolution {
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        agreement_contract: Address,
+        escrow_contract: Address,
+    ) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.storage()
+            .instance()
+            .set(&DataKey::AgreementContract, &agreement_contract);
+        env.storage()
+            .instance()
+            .set(&DataKey::EscrowContract, &escrow_contract);
+
+        env.events().publish(
+            (Symbol::new(&env, "Initialized"),),
+            (
+                admin,
+                agreement_contract,
+                escrow_contract,
+                env.ledger().timestamp(),
+            ),
+        );
+    }
+
+    pub fn pause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.events()
+            .publish((Symbol::new(&env, "Paused"),), env.ledger().timestamp());
+    }
+
+    pub fn unpause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.events()
+            .publish((Symbol::new(&env, "Unpaused"),), env.ledger().timestamp());
+    }
+
+    // Either party to the agreement can open a dispute over the deposit.
+    // Only one dispute per agreement may be open at a time.
+    pub fn file_dispute(
+        env: Env,
+        filer: Address,
+        agreement_id: BytesN<32>,
+        reason: String,
+        evidence_hash: BytesN<32>,
+    ) -> BytesN<32> {
+        Self::check_not_paused(&env);
+
+        filer.require_auth();
+
+        if reason.len() > MAX_REASON_LEN {
+            panic_with_error!(&env, Error::ReasonTooLong);
+        }
+
+        let agreement = Self::fetch_agreement(&env, agreement_id.clone());
+        let respondent = if filer == agreement.tenant {
+            agreement.landlord
+        } else if filer == agreement.landlord {
+            agreement.tenant
+        } else {
+            panic_with_error!(&env, Error::NotAgreementParty)
+        };
+
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::OpenDispute(agreement_id.clone()))
+        {
+            panic_with_error!(&env, Error::DisputeAlreadyOpen);
+        }
+
+        let now = env.ledger().timestamp();
+        let dispute_id = Self::new_id(&env);
+        let dispute = Dispute {
+            id: dispute_id.clone(),
+            agreement_id: agreement_id.clone(),
+            filer: filer.clone(),
+            respondent: respondent.clone(),
+            reason,
+            evidence_hash: evidence_hash.clone(),
+            status: DisputeStatus::Filed,
+            filed_at: now,
+            resolved_at: 0,
+        };
+        Self::save_dispute(&env, &dispute);
+        env.storage()
+            .persistent()
+            .set(&DataKey::OpenDispute(agreement_id.clone()), &dispute_id);
+
+        let mut by_agreement = Self::get_disputes_by_agreement(env.clone(), agreement_id.clone());
+        by_agreement.push_back(dispute_id.clone());
+        env.storage().persistent().set(
+            &DataKey::DisputesByAgreement(agreement_id.clone()),
+            &by_agreement,
+        );
+
+        Self::append_evidence(&env, &dispute_id, &filer, evidence_hash);
+
+        env.events().publish(
+            (Symbol::new(&env, "DisputeFiled"),),
+            (dispute_id.clone(), agreement_id, filer, respondent),
+        );
+
+        dispute_id
+    }
+
+    pub fn register_arbitrator(env: Env, arbitrator: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let mut arbitrators = Self::get_arbitrators(env.clone());
+        if !arbitrators.contains(&arbitrator) {
+            arbitrators.push_back(arbitrator.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::ArbitratorRegistry, &arbitrators);
+        }
+
+        env.events()
+            .publish((Symbol::new(&env, "ArbitratorRegistered"),), arbitrator);
+    }
+
+    // Removing an arbitrator doesn't unassign disputes they already hold.
+    pub fn remove_arbitrator(env: Env, arbitrator: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let mut arbitrators = Self::get_arbitrators(env.clone());
+        if let Some(i) = arbitrators.first_index_of(&arbitrator) {
+            arbitrators.remove(i);
+            env.storage()
+                .persistent()
+                .set(&DataKey::ArbitratorRegistry, &arbitrators);
+        }
+
+        env.events()
+            .publish((Symbol::new(&env, "ArbitratorRemoved"),), arbitrator);
+    }
+
+    pub fn get_arbitrators(env: Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ArbitratorRegistry)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_active_dispute_count(env: Env, arbitrator: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ActiveDisputeCount(arbitrator))
+            .unwrap_or(0)
+    }
+
+    // The admin assigns a registered arbitrator, or a registered arbitrator
+    // assigns themselves. Arbitrators hold at most 10 unresolved disputes.
+    pub fn assign_arbitrator(
+        env: Env,
+        caller: Address,
+        dispute_id: BytesN<32>,
+        arbitrator: Address,
+    ) {
+        Self::check_not_paused(&env);
+
+        caller.require_auth();
+        if caller != Self::require_admin(&env) && caller != arbitrator {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        if !Self::get_arbitrators(env.clone()).contains(&arbitrator) {
+            panic_with_error!(&env, Error::NotRegisteredArbitrator);
+        }
+
+        let mut dispute = Self::get_dispute(env.clone(), dispute_id.clone());
+        match dispute.status {
+            DisputeStatus::Filed | DisputeStatus::Appealed => {}
+            _ => panic_with_error!(&env, Error::InvalidDisputeState),
+        }
+        if arbitrator == dispute.filer || arbitrator == dispute.respondent {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+
+        let active = Self::get_active_dispute_count(env.clone(), arbitrator.clone());
+        if active >= MAX_ACTIVE_DISPUTES_PER_ARBITRATOR {
+            panic_with_error!(&env, Error::ArbitratorOverloaded);
+        }
+        Self::set_active_dispute_count(&env, &arbitrator, active + 1);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Arbitrator(dispute_id.clone()), &arbitrator);
+        dispute.status = DisputeStatus::UnderReview;
+        Self::save_dispute(&env, &dispute);
+
+        env.events().publish(
+            (Symbol::new(&env, "ArbitratorAssigned"),),
+            (dispute_id, arbitrator),
+        );
+    }
+
+    pub fn submit_evidence(
+        env: Env,
+        submitter: Address,
+        dispute_id: BytesN<32>,
+        evidence_hash: BytesN<32>,
+    ) {
+        Self::check_not_paused(&env);
+
+        submitter.require_auth();
+
+        let dispute = Self::get_dispute(env.clone(), dispute_id.clone());
+        if submitter != dispute.filer && submitter != dispute.respondent {
+            panic_with_error!(&env, Error::NotAgreementParty);
+        }
+        if dispute.status == DisputeStatus::Resolved {
+            panic_with_error!(&env, Error::InvalidDisputeState);
+        }
+
+        Self::append_evidence(&env, &dispute_id, &submitter, evidence_hash.clone());
+
+        env.events().publish(
+            (Symbol::new(&env, "EvidenceSubmitted"),),
+            (dispute_id, submitter, evidence_hash),
+        );
+    }
+
+    // The assigned arbitrator records a ruling. Funds move once the appeal
+    // window has passed (see `finalize_dispute`), or immediately when ruling
+    // on an appeal.
+    pub fn resolve_dispute(
+        env: Env,
+        arbitrator: Address,
+        dispute_id: BytesN<32>,
+        ruling: DisputeRuling,
+    ) {
+        Self::check_not_paused(&env);
+
+        arbitrator.require_auth();
+
+        let mut dispute = Self::get_dispute(env.clone(), dispute_id.clone());
+        if dispute.status != DisputeStatus::UnderReview {
+            panic_with_error!(&env, Error::InvalidDisputeState);
+        }
+        if Self::get_arbitrator(env.clone(), dispute_id.clone()) != Some(arbitrator.clone()) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        if let DisputeRuling::SplitDeposit(to_tenant, to_landlord) = ruling {
+            if to_tenant < 0 || to_landlord < 0 {
+                panic_with_error!(&env, Error::InvalidRuling);
+            }
+        }
+
+        let active = Self::get_active_dispute_count(env.clone(), arbitrator.clone());
+        Self::set_active_dispute_count(&env, &arbitrator, active.saturating_sub(1));
+
+        dispute.status = DisputeStatus::Resolved;
+        dispute.resolved_at = env.ledger().timestamp();
+        Self::save_dispute(&env, &dispute);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Ruling(dispute_id.clone()), &ruling);
+
+        env.events().publish(
+            (Symbol::new(&env, "DisputeResolved"),),
+            (dispute_id.clone(), dispute.agreement_id.clone(), arbitrator),
+        );
+
+        if Self::was_appealed(&env, &dispute_id) {
+            Self::settle(&env, &dispute);
+        }
+    }
+
+    // Either party may appeal a ruling once, within 7 days of resolution.
+    pub fn appeal_dispute(env: Env, caller: Address, dispute_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        caller.require_auth();
+
+        let mut dispute = Self::get_dispute(env.clone(), dispute_id.clone());
+        if caller != dispute.filer && caller != dispute.respondent {
+            panic_with_error!(&env, Error::NotAgreementParty);
+        }
+        if dispute.status != DisputeStatus::Resolved || Self::was_appealed(&env, &dispute_id) {
+            panic_with_error!(&env, Error::InvalidDisputeState);
+        }
+        if env.ledger().timestamp() > dispute.resolved_at.saturating_add(APPEAL_WINDOW_SECONDS) {
+            panic_with_error!(&env, Error::AppealWindowClosed);
+        }
+
+        dispute.status = DisputeStatus::Appealed;
+        Self::save_dispute(&env, &dispute);
+        env.storage()
+            .persistent()
+            .set(&DataKey::WasAppealed(dispute_id.clone()), &true);
+
+        env.events().publish(
+            (Symbol::new(&env, "DisputeAppealed"),),
+            (dispute_id, caller),
+        );
+    }
+
+    // Anyone can execute an unappealed ruling once the appeal window has passed.
+    pub fn finalize_dispute(env: Env, dispute_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        let dispute = Self::get_dispute(env.clone(), dispute_id.clone());
+        if dispute.status != DisputeStatus::Resolved {
+            panic_with_error!(&env, Error::InvalidDisputeState);
+        }
+        if env.ledger().timestamp() <= dispute.resolved_at.saturating_add(APPEAL_WINDOW_SECONDS) {
+            panic_with_error!(&env, Error::AppealWindowOpen);
+        }
+
+        Self::settle(&env, &dispute);
+    }
+
+    pub fn get_dispute(env: Env, dispute_id: BytesN<32>) -> Dispute {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Dispute(dispute_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::DisputeNotFound))
+    }
+
+    pub fn get_disputes_by_agreement(env: Env, agreement_id: BytesN<32>) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DisputesByAgreement(agreement_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // When the agreement's open dispute was filed, if it has one. Used by
+    // EscrowManager to decide whether a stale dispute still blocks release.
+    pub fn get_open_dispute_filed_at(env: Env, agreement_id: BytesN<32>) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get::<_, BytesN<32>>(&DataKey::OpenDispute(agreement_id))
+            .map(|dispute_id| Self::get_dispute(env.clone(), dispute_id).filed_at)
+    }
+
+    /// `None` until the assigned arbitrator rules. An appeal keeps the earlier
+    /// ruling until it is replaced.
+    pub fn get_ruling(env: Env, dispute_id: BytesN<32>) -> Option<DisputeRuling> {
+        env.storage().persistent().get(&DataKey::Ruling(dispute_id))
+    }
+
+    pub fn get_arbitrator(env: Env, dispute_id: BytesN<32>) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Arbitrator(dispute_id))
+    }
+
+    pub fn get_evidence(env: Env, dispute_id: BytesN<32>) -> Vec<Evidence> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Evidence(dispute_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn is_settled(env: Env, dispute_id: BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Settled(dispute_id))
+            .unwrap_or(false)
+    }
+
+    // Pays out the ruling through EscrowManager and closes the dispute.
+    fn settle(env: &Env, dispute: &Dispute) {
+        if Self::is_settled(env.clone(), dispute.id.clone()) {
+            panic_with_error!(env, Error::InvalidDisputeState);
+        }
+
+        let escrow_client = Self::escrow_client(env);
+        let held = escrow_client
+            .get_escrow(&dispute.agreement_id)
+            .security_deposit_held;
+        let Some(ruling) = Self::get_ruling(env.clone(), dispute.id.clone()) else {
+            panic_with_error!(env, Error::InvalidDisputeState)
+        };
+        let to_landlord = match ruling {
+            DisputeRuling::FullDepositToTenant => 0,
+            DisputeRuling::FullDepositToLandlord => held,
+            DisputeRuling::SplitDeposit(to_tenant, to_landlord) => {
+                if to_tenant.saturating_add(to_landlord) != held {
+                    panic_with_error!(env, Error::InvalidRuling);
+                }
+                to_landlord
+            }
+        };
+        escrow_client.release_deposit_to_landlord(&dispute.agreement_id, &to_landlord);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Settled(dispute.id.clone()), &true);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::OpenDispute(dispute.agreement_id.clone()));
+
+        env.events().publish(
+            (Symbol::new(env, "DisputeSettled"),),
+            (dispute.id.clone(), to_landlord, held - to_landlord),
+        );
+    }
+
+    fn append_evidence(
+        env: &Env,
+        dispute_id: &BytesN<32>,
+        submitter: &Address,
+        evidence_hash: BytesN<32>,
+    ) {
+        let mut evidence = Self::get_evidence(env.clone(), dispute_id.clone());
+        evidence.push_back(Evidence {
+            submitter: submitter.clone(),
+            evidence_hash,
+            submitted_at: env.ledger().timestamp(),
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::Evidence(dispute_id.clone()), &evidence);
+    }
+
+    fn set_active_dispute_count(env: &Env, arbitrator: &Address, count: u32) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::ActiveDisputeCount(arbitrator.clone()), &count);
+    }
+
+    fn was_appealed(env: &Env, dispute_id: &BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::WasAppealed(dispute_id.clone()))
+            .unwrap_or(false)
+    }
+
+    fn save_dispute(env: &Env, dispute: &Dispute) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Dispute(dispute.id.clone()), dispute);
+    }
+
+    fn fetch_agreement(env: &Env, agreement_id: BytesN<32>) -> RentalAgreement {
+        let addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::AgreementContract)
+            .unwrap_or_else(|| panic_with_error!(env, Error::AgreementContractNotSet));
+        RentalAgreementClient::new(env, &addr).get_agreement(&agreement_id)
+    }
+
+    fn escrow_client(env: &Env) -> EscrowManagerClient<'_> {
+        let addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::EscrowContract)
+            .unwrap_or_else(|| panic_with_error!(env, Error::EscrowContractNotSet));
+        EscrowManagerClient::new(env, &addr)
+    }
+
+    fn check_not_paused(env: &Env) {
+        let paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        if paused {
+            panic_with_error!(env, Error::ContractPaused);
+        }
+    }
+
+    fn require_admin(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized))
+    }
+
+    fn new_id(env: &Env) -> BytesN<32> {
+        env.prng().gen::<BytesN<32>>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    #[contract]
+    struct MockAgreement;
+
+    #[contractimpl]
+    impl MockAgreement {
+        pub fn set_agreement(env: Env, agreement: RentalAgreement) {
+            env.storage().instance().set(&agreement.id, &agreement);
+        }
+
+        pub fn get_agreement(env: Env, agreement_id: BytesN<32>) -> RentalAgreement {
+            env.storage().instance().get(&agreement_id).unwrap()
+        }
+    }
+
+    // Holds one escrow and records what was released to the landlord.
+    #[contract]
+    struct MockEscrow;
+
+    #[contractimpl]
+    impl MockEscrow {
+        pub fn set_escrow(env: Env, escrow: EscrowAccount) {
+            env.storage().instance().set(&escrow.agreement_id, &escrow);
+        }
+
+        pub fn get_escrow(env: Env, agreement_id: BytesN<32>) -> EscrowAccount {
+            env.storage().instance().get(&agreement_id).unwrap()
+        }
+
+        pub fn release_deposit_to_landlord(env: Env, _agreement_id: BytesN<32>, amount: i128) {
+            env.storage()
+                .instance()
+                .set(&Symbol::new(&env, "released"), &amount);
+        }
+
+        pub fn released(env: Env) -> Option<i128> {
+            env.storage().instance().get(&Symbol::new(&env, "released"))
+        }
+    }
+
+    #[test]
+    fn initialize_smoke() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, DisputeResolution);
+        let client = DisputeResolutionClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let agreement_contract = Address::generate(&env);
+        let escrow_contract = Address::generate(&env);
+        client.initialize(&admin, &agreement_contract, &escrow_contract);
+
+        client.pause();
+        client.unpause();
+    }
+
+    #[test]
+    fn appealed_ruling_settles_through_escrow() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let landlord = Address::generate(&env);
+        let tenant = Address::generate(&env);
+        let agreement_id = BytesN::from_array(&env, &[1; 32]);
+
+        let agreement_contract = env.register_contract(None, MockAgreement);
+        MockAgreementClient::new(&env, &agreement_contract).set_agreement(&RentalAgreement {
+            id: agreement_id.clone(),
+            property_id: BytesN::from_array(&env, &[2; 32]),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            monthly_rent: 1_000,
+            security_deposit: 1_000,
+            start_date: 0,
+            end_date: 365 * 24 * 60 * 60,
+            status: AgreementStatus::Completed,
+            landlord_signed: true,
+            landlord_signed_at: 0,
+            tenant_signed: true,
+            tenant_signed_at: 0,
+            deposit_paid: true,
+            deposit_paid_at: 0,
+            total_rent_paid: 12_000,
+            months_paid: 12,
+            created_at: 0,
+            completed_at: 0,
+            notice_period_days: 30,
+            notice_served_at: 0,
+            notice_served_by: None,
+            document_hash: BytesN::from_array(&env, &[0; 32]),
+            co_signer: None,
+            subletting_allowed: false,
+            custom_clauses: Vec::new(&env),
+        });
+        let escrow_contract = env.register_contract(None, MockEscrow);
+        let escrow = MockEscrowClient::new(&env, &escrow_contract);
+        escrow.set_escrow(&EscrowAccount {
+            agreement_id: agreement_id.clone(),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            security_deposit_amount: 1_000,
+            security_deposit_held: 1_000,
+            monthly_rent_amount: 1_000,
+            total_rent_received: 12_000,
+            total_rent_released: 12_000,
+            is_deposit_released: false,
+            deposit_released_at: 0,
+            created_at: 0,
+        });
+
+        let contract_id = env.register_contract(None, DisputeResolution);
+        let client = DisputeResolutionClient::new(&env, &contract_id);
+        client.initialize(
+            &Address::generate(&env),
+            &agreement_contract,
+            &escrow_contract,
+        );
+        let arbitrator = Address::generate(&env);
+        client.register_arbitrator(&arbitrator);
+
+        let dispute_id = client.file_dispute(
+            &tenant,
+            &agreement_id,
+            &String::from_str(&env, "Deposit withheld for normal wear"),
+            &BytesN::from_array(&env, &[3; 32]),
+        );
+        assert_eq!(client.get_dispute(&dispute_id).respondent, landlord);
+        assert_eq!(client.get_ruling(&dispute_id), None);
+
+        client.assign_arbitrator(&arbitrator, &dispute_id, &arbitrator);
+        assert_eq!(client.get_active_dispute_count(&arbitrator), 1);
+        client.resolve_dispute(
+            &arbitrator,
+            &dispute_id,
+            &DisputeRuling::SplitDeposit(300, 700),
+        );
+        assert_eq!(
+            client.get_ruling(&dispute_id),
+            Some(DisputeRuling::SplitDeposit(300, 700))
+        );
+        assert_eq!(client.get_active_dispute_count(&arbitrator), 0);
+        assert_eq!(
+            client.try_finalize_dispute(&dispute_id),
+            Err(Ok(Error::AppealWindowOpen.into()))
+        );
+
+        env.ledger().with_mut(|l| l.timestamp += 24 * 60 * 60);
+        client.appeal_dispute(&landlord, &dispute_id);
+        assert_eq!(
+            client.get_dispute(&dispute_id).status,
+            DisputeStatus::Appealed
+        );
+        assert_eq!(escrow.released(), None);
+
+        // Ruling on the appeal pays out straight away.
+        client.assign_arbitrator(&arbitrator, &dispute_id, &arbitrator);
+        client.resolve_dispute(
+            &arbitrator,
+            &dispute_id,
+            &DisputeRuling::FullDepositToLandlord,
+        );
+        assert!(client.is_settled(&dispute_id));
+        assert_eq!(escrow.released(), Some(1_000));
+        assert_eq!(client.get_open_dispute_filed_at(&agreement_id), None);
+        assert_eq!(
+            client.try_appeal_dispute(&tenant, &dispute_id),
+            Err(Ok(Error::InvalidDisputeState.into()))
+        );
+    }
+
+    #[test]
+    fn filing_needs_the_agreement_contract() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, DisputeResolution);
+        let client = DisputeResolutionClient::new(&env, &contract_id);
+        assert_eq!(
+            client.try_file_dispute(
+                &Address::generate(&env),
+                &BytesN::from_array(&env, &[1; 32]),
+                &String::from_str(&env, "No agreement contract"),
+                &BytesN::from_array(&env, &[3; 32]),
+            ),
+            Err(Ok(Error::AgreementContractNotSet.into()))
+        );
+    }
+}
@@ -2,7 +2,7 @@
 
 use soroban_sdk::{
     contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error, Address,
-    BytesN, Env, Symbol, Vec,
+    BytesN, Env, String, Symbol, Vec,
 };
 
 use soroban_sdk::token;
@@ -14,9 +14,12 @@ use soroban_sdk::token;
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AgreementStatus {
+    PendingLandlordApproval,
+    Rejected,
     Draft,
     PendingTenantSign,
     PendingLandlordSign,
+    PendingCoSignerSign,
     PendingPayment,
     Active,
     Completed,
@@ -45,6 +48,13 @@ pub struct RentalAgreement {
     pub months_paid: u32,
     pub created_at: u64,
     pub completed_at: u64,
+    pub notice_period_days: u32,
+    pub notice_served_at: u64,
+    pub notice_served_by: Option<Address>,
+    pub document_hash: BytesN<32>,
+    pub co_signer: Option<Address>,
+    pub subletting_allowed: bool,
+    pub custom_clauses: Vec<String>,
 }
 
 #[contractclient(name = "RentalAgreementClient")]
@@ -52,12 +62,73 @@ pub trait RentalAgreementContract {
     fn get_agreement(agreement_id: BytesN<32>) -> RentalAgreement;
     fn mark_deposit_paid(agreement_id: BytesN<32>);
     fn record_rent_payment(agreement_id: BytesN<32>, amount: i128);
+    fn record_prorated_payment(agreement_id: BytesN<32>, amount: i128);
+    fn get_agreements_by_landlord(landlord: Address) -> Vec<RentalAgreement>;
+    fn co_signer_guarantee_payment(co_signer: Address, agreement_id: BytesN<32>);
+}
+
+// -----------------------------
+// Cross-contract: RewardToken (optional)
+// -----------------------------
+
+#[contractclient(name = "RewardTokenClient")]
+pub trait RewardToken {
+    fn record_on_time_payment(tenant: Address);
+    fn record_missed_payment(tenant: Address);
+    fn reward_rent_payment(
+        caller: Address,
+        agreement_id: BytesN<32>,
+        tenant: Address,
+        rent_amount: i128,
+    );
+    fn consume_pending_discount(user: Address) -> u32;
+}
+
+// -----------------------------
+// Cross-contract: PlatformTreasury (optional)
+// -----------------------------
+
+#[contractclient(name = "PlatformTreasuryClient")]
+pub trait PlatformTreasury {
+    fn deposit(amount: i128, token: Address);
+    fn get_fee_rate() -> u32;
+    fn is_fee_exempt(address: Address) -> bool;
+}
+
+// -----------------------------
+// Cross-contract: DisputeResolution (optional)
+// -----------------------------
+
+#[contractclient(name = "DisputeResolutionClient")]
+pub trait DisputeResolution {
+    fn get_open_dispute_filed_at(agreement_id: BytesN<32>) -> Option<u64>;
+}
+
+// -----------------------------
+// Cross-contract: TenantCredit (optional)
+// -----------------------------
+
+#[contractclient(name = "TenantCreditClient")]
+pub trait TenantCredit {
+    fn update_on_time_payment(agreement_id: BytesN<32>, tenant: Address);
+    fn update_late_payment(agreement_id: BytesN<32>, tenant: Address);
 }
 
 // -----------------------------
 // EscrowManager contract
 // -----------------------------
 
+const DAYS_PER_MONTH: u64 = 30;
+const SECONDS_PER_MONTH: u64 = DAYS_PER_MONTH * 24 * 60 * 60;
+const BPS_DENOMINATOR: i128 = 10_000;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+const DEFAULT_MAX_DEPOSIT_HOLD_DAYS: u32 = 60;
+const ADMIN_TRANSFER_WINDOW_SECONDS: u64 = 7 * 24 * 60 * 60;
+const MIGRATION_DELAY_SECONDS: u64 = SECONDS_PER_DAY;
+const REMINDER_LEAD_SECONDS: u64 = 3 * SECONDS_PER_DAY;
+// Archived escrows stay readable for about 180 days (5-second ledgers).
+const ARCHIVE_TTL_LEDGERS: u32 = 180 * 24 * 60 * 60 / 5;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
@@ -73,6 +144,24 @@ pub enum Error {
     DepositNotPaid = 304,
     DepositAlreadyReleased = 305,
     AgreementNotCompleted = 306,
+    ReleaseAlreadyRequested = 307,
+    ReleaseNotRequested = 308,
+    NoPendingAdminTransfer = 309,
+    AdminTransferExpired = 310,
+    DepositHoldNotExpired = 311,
+    InvalidHoldPeriod = 312,
+    NoticeNotServed = 313,
+    ProratedRentAlreadyPaid = 314,
+    DepositNotReleased = 315,
+    InvalidRentSplit = 316,
+    SplitAlreadyPaid = 317,
+    DepositsStillHeld = 318,
+    NoPendingTokenMigration = 319,
+    TokenMigrationNotReady = 320,
+    NoPendingAgreementUpdate = 321,
+    AgreementUpdateNotReady = 322,
+    RentInArrears = 323,
+    DepositsAlreadyBackfilled = 324,
 }
 
 #[contracttype]
@@ -82,7 +171,10 @@ pub enum PaymentType {
     FirstMonthRent,
     MonthlyRent,
     DepositRelease,
+    DisputeSettlement,
     EmergencyWithdrawal,
+    PlatformFee,
+    ProratedRent,
 }
 
 #[contracttype]
@@ -113,6 +205,25 @@ pub struct EscrowAccount {
     pub created_at: u64,
 }
 
+/// Both parties must act before a deposit goes back to the tenant:
+/// one requests the release and the other confirms it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositReleaseRequest {
+    pub requested_by: Address,
+    pub confirmed_by: Option<Address>,
+    pub requested_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HealthReport {
+    pub total_deposits_recorded: i128,
+    pub total_split_shares_held: i128,
+    pub contract_token_balance: i128,
+    pub discrepancy: i128,
+}
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -121,6 +232,31 @@ pub enum DataKey {
     XlmToken,
     Escrow(BytesN<32>),
     Payments(BytesN<32>),
+    RewardToken,
+    DisputeContract,
+    Treasury,
+    CreditContract,
+    DepositReleaseRequest(BytesN<32>),
+    // Running sum of `security_deposit_held` across all escrow accounts.
+    TotalDepositsHeld,
+    MaxDepositHoldDays,
+    ProratedRentPaid(BytesN<32>),
+    ArchivedEscrow(BytesN<32>),
+    ArchivedPayments(BytesN<32>),
+    LastReminderAt(BytesN<32>),
+    // Co-tenant shares of the monthly rent: Vec<(Address, i128)>.
+    RentSplit(BytesN<32>),
+    // Set once a co-tenant has paid their share of the current month.
+    SplitPaid(BytesN<32>, Address),
+    // Running sum of co-tenant shares held until their month is complete.
+    TotalSplitSharesHeld,
+    DepositsBackfilled,
+    PendingXlmToken,
+    TokenMigrationReadyAt,
+    PendingAgreementContract,
+    AgreementContractUpdateAt,
+    PendingAdmin,
+    AdminTransferExpiry,
 }
 
 #[contract]
@@ -169,6 +305,282 @@ impl EscrowManager {
             .publish((Symbol::new(&env, "Unpaused"),), env.ledger().timestamp());
     }
 
+    /// Starts a two-step admin handover. The proposed admin has 7 days to
+    /// call `accept_admin_transfer`.
+    pub fn propose_admin_transfer(env: Env, current_admin: Address, proposed_admin: Address) {
+        if current_admin != Self::require_admin(&env) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        current_admin.require_auth();
+
+        let expiry = env.ledger().timestamp() + ADMIN_TRANSFER_WINDOW_SECONDS;
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &proposed_admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::AdminTransferExpiry, &expiry);
+
+        env.events().publish(
+            (Symbol::new(&env, "AdminTransferProposed"),),
+            (current_admin, proposed_admin, expiry),
+        );
+    }
+
+    pub fn accept_admin_transfer(env: Env, proposed_admin: Address) {
+        proposed_admin.require_auth();
+
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoPendingAdminTransfer));
+        if pending != proposed_admin {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        let expiry: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AdminTransferExpiry)
+            .unwrap_or(0);
+        if env.ledger().timestamp() > expiry {
+            panic_with_error!(&env, Error::AdminTransferExpired);
+        }
+
+        let previous_admin = Self::require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::Admin, &proposed_admin);
+        Self::clear_pending_admin(&env);
+
+        env.events().publish(
+            (Symbol::new(&env, "AdminTransferred"),),
+            (previous_admin, proposed_admin),
+        );
+    }
+
+    pub fn cancel_admin_transfer(env: Env, current_admin: Address) {
+        if current_admin != Self::require_admin(&env) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        current_admin.require_auth();
+
+        if !env.storage().instance().has(&DataKey::PendingAdmin) {
+            panic_with_error!(&env, Error::NoPendingAdminTransfer);
+        }
+        Self::clear_pending_admin(&env);
+
+        env.events().publish(
+            (Symbol::new(&env, "AdminTransferCancelled"),),
+            current_admin,
+        );
+    }
+
+    // Moving to a new XLM token contract is only allowed while no deposits
+    // or rent shares are held, and takes effect after a 24-hour delay.
+    pub fn propose_token_migration(env: Env, new_token: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        if Self::holds_funds(&env) {
+            panic_with_error!(&env, Error::DepositsStillHeld);
+        }
+
+        let ready_at = env
+            .ledger()
+            .timestamp()
+            .saturating_add(MIGRATION_DELAY_SECONDS);
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingXlmToken, &new_token);
+        env.storage()
+            .instance()
+            .set(&DataKey::TokenMigrationReadyAt, &ready_at);
+
+        env.events().publish(
+            (Symbol::new(&env, "TokenMigrationProposed"),),
+            (new_token, ready_at),
+        );
+    }
+
+    pub fn accept_token_migration(env: Env) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let new_token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingXlmToken)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoPendingTokenMigration));
+        let ready_at: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TokenMigrationReadyAt)
+            .unwrap_or(0);
+        if env.ledger().timestamp() < ready_at {
+            panic_with_error!(&env, Error::TokenMigrationNotReady);
+        }
+        // Deposits may have come in since the proposal.
+        if Self::holds_funds(&env) {
+            panic_with_error!(&env, Error::DepositsStillHeld);
+        }
+
+        let old_token = Self::xlm_client(&env).address;
+        env.storage().instance().set(&DataKey::XlmToken, &new_token);
+        env.storage().instance().remove(&DataKey::PendingXlmToken);
+        env.storage()
+            .instance()
+            .remove(&DataKey::TokenMigrationReadyAt);
+
+        env.events().publish(
+            (Symbol::new(&env, "TokenMigrationCompleted"),),
+            (old_token, new_token),
+        );
+    }
+
+    // Repoints the escrow at an upgraded RentalAgreement contract after a
+    // 24-hour delay, mirroring the token migration flow. Existing escrows are
+    // looked up through the agreement contract, so this is only allowed while
+    // no deposits or rent shares are held.
+    pub fn propose_agreement_update(env: Env, new_contract: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        if Self::holds_funds(&env) {
+            panic_with_error!(&env, Error::DepositsStillHeld);
+        }
+
+        let ready_at = env
+            .ledger()
+            .timestamp()
+            .saturating_add(MIGRATION_DELAY_SECONDS);
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAgreementContract, &new_contract);
+        env.storage()
+            .instance()
+            .set(&DataKey::AgreementContractUpdateAt, &ready_at);
+
+        env.events().publish(
+            (Symbol::new(&env, "AgreementContractUpdateProposed"),),
+            (new_contract, ready_at),
+        );
+    }
+
+    pub fn accept_agreement_update(env: Env) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let new_contract: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAgreementContract)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoPendingAgreementUpdate));
+        let ready_at: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AgreementContractUpdateAt)
+            .unwrap_or(0);
+        if env.ledger().timestamp() < ready_at {
+            panic_with_error!(&env, Error::AgreementUpdateNotReady);
+        }
+        if Self::holds_funds(&env) {
+            panic_with_error!(&env, Error::DepositsStillHeld);
+        }
+
+        let old_contract = Self::agreement_client(&env).address;
+        env.storage()
+            .instance()
+            .set(&DataKey::AgreementContract, &new_contract);
+        env.storage()
+            .instance()
+            .remove(&DataKey::PendingAgreementContract);
+        env.storage()
+            .instance()
+            .remove(&DataKey::AgreementContractUpdateAt);
+
+        env.events().publish(
+            (Symbol::new(&env, "AgreementContractUpdated"),),
+            (old_contract, new_contract),
+        );
+    }
+
+    // Optional: configure reward token contract address.
+    // If not set, rent payments do not update BRIQ streaks or earn cashback.
+    pub fn set_reward_token(env: Env, reward_token: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RewardToken, &reward_token);
+
+        env.events().publish(
+            (Symbol::new(&env, "RewardTokenSet"),),
+            (reward_token, env.ledger().timestamp()),
+        );
+    }
+
+    // Dispute resolution contract allowed to settle held deposits.
+    pub fn set_dispute_contract(env: Env, dispute_contract: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::DisputeContract, &dispute_contract);
+
+        env.events().publish(
+            (Symbol::new(&env, "DisputeContractSet"),),
+            (dispute_contract, env.ledger().timestamp()),
+        );
+    }
+
+    // Optional: platform treasury that receives a fee on each rent release.
+    // If not set, landlords receive rent in full.
+    pub fn set_treasury(env: Env, treasury: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+
+        env.events().publish(
+            (Symbol::new(&env, "TreasurySet"),),
+            (treasury, env.ledger().timestamp()),
+        );
+    }
+
+    // Optional: tenant credit contract told whether each rent payment was late.
+    pub fn set_credit_contract(env: Env, credit_contract: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::CreditContract, &credit_contract);
+
+        env.events().publish(
+            (Symbol::new(&env, "CreditContractSet"),),
+            (credit_contract, env.ledger().timestamp()),
+        );
+    }
+
     // Initial payment: security deposit + first month rent.
     // - Transfers total from tenant to escrow contract
     // - Immediately releases first month rent to landlord
@@ -204,8 +616,13 @@ impl EscrowManager {
         // Tenant -> Escrow (deposit + rent)
         token_client.transfer(&agreement.tenant, &contract_addr, &total);
 
-        // Escrow -> Landlord (first month rent)
-        token_client.transfer(&contract_addr, &agreement.landlord, &agreement.monthly_rent);
+        // Escrow -> Treasury (platform fee), Escrow -> Landlord (first month rent)
+        let fee = Self::maybe_collect_fee(&env, &agreement, agreement.monthly_rent);
+        token_client.transfer(
+            &contract_addr,
+            &agreement.landlord,
+            &(agreement.monthly_rent - fee),
+        );
 
         // Store/update escrow state
         let now = env.ledger().timestamp();
@@ -213,18 +630,15 @@ impl EscrowManager {
             .storage()
             .persistent()
             .get::<_, EscrowAccount>(&DataKey::Escrow(agreement_id.clone()))
-            .unwrap_or(EscrowAccount {
-                agreement_id: agreement_id.clone(),
-                landlord: agreement.landlord.clone(),
-                tenant: agreement.tenant.clone(),
-                security_deposit_amount: agreement.security_deposit,
-                security_deposit_held: 0,
-                monthly_rent_amount: agreement.monthly_rent,
-                total_rent_received: 0,
-                total_rent_released: 0,
-                is_deposit_released: false,
-                deposit_released_at: 0,
-                created_at: now,
+            .unwrap_or_else(|| {
+                Self::empty_escrow(
+                    &env,
+                    agreement_id.clone(),
+                    agreement.landlord.clone(),
+                    agreement.tenant.clone(),
+                    agreement.security_deposit,
+                    agreement.monthly_rent,
+                )
             });
 
         escrow.security_deposit_amount = agreement.security_deposit;
@@ -232,6 +646,7 @@ impl EscrowManager {
         escrow.security_deposit_held = escrow
             .security_deposit_held
             .saturating_add(agreement.security_deposit);
+        Self::adjust_total_deposits_held(&env, agreement.security_deposit);
         escrow.total_rent_received = escrow
             .total_rent_received
             .saturating_add(agreement.monthly_rent);
@@ -292,137 +707,530 @@ impl EscrowManager {
         agreement_client.record_rent_payment(&agreement_id, &agreement.monthly_rent);
     }
 
-    // Monthly rent payments (manual, no recurring). Tenant pays contract, contract releases to landlord.
-    pub fn pay_rent(env: Env, tenant: Address, agreement_id: BytesN<32>) {
+    // Splits the monthly rent between co-tenants. Must be set before the
+    // agreement becomes active and the shares must add up to the rent.
+    pub fn set_rent_split(
+        env: Env,
+        landlord: Address,
+        agreement_id: BytesN<32>,
+        splits: Vec<(Address, i128)>,
+    ) {
         Self::check_not_paused(&env);
 
-        tenant.require_auth();
+        landlord.require_auth();
 
         let agreement = Self::fetch_agreement(&env, agreement_id.clone());
-        if tenant != agreement.tenant {
+        if landlord != agreement.landlord {
             panic_with_error!(&env, Error::Unauthorized);
         }
-        if agreement.status != AgreementStatus::Active {
-            panic_with_error!(&env, Error::InvalidAgreementState);
+        match agreement.status {
+            AgreementStatus::Active | AgreementStatus::Completed | AgreementStatus::Cancelled => {
+                panic_with_error!(&env, Error::InvalidAgreementState)
+            }
+            _ => {}
         }
 
-        let amount = agreement.monthly_rent;
-        if amount <= 0 {
-            panic_with_error!(&env, Error::InvalidPaymentAmount);
+        if splits.is_empty() {
+            panic_with_error!(&env, Error::InvalidRentSplit);
+        }
+        let mut total: i128 = 0;
+        for (i, (payer, share)) in splits.iter().enumerate() {
+            if share <= 0 {
+                panic_with_error!(&env, Error::InvalidRentSplit);
+            }
+            // Each co-tenant may appear only once.
+            for (other, _) in splits.iter().skip(i + 1) {
+                if other == payer {
+                    panic_with_error!(&env, Error::InvalidRentSplit);
+                }
+            }
+            total = total.saturating_add(share);
+        }
+        if total != agreement.monthly_rent {
+            panic_with_error!(&env, Error::InvalidRentSplit);
         }
 
-        let token_client = Self::xlm_client(&env);
-        let contract_addr = env.current_contract_address();
-
-        // Tenant -> Escrow (rent)
-        token_client.transfer(&agreement.tenant, &contract_addr, &amount);
-        // Escrow -> Landlord (rent)
-        token_client.transfer(&contract_addr, &agreement.landlord, &amount);
-
-        // Update escrow
-        let now = env.ledger().timestamp();
-        let mut escrow = Self::get_escrow(env.clone(), agreement_id.clone());
-        escrow.total_rent_received = escrow.total_rent_received.saturating_add(amount);
-        escrow.total_rent_released = escrow.total_rent_released.saturating_add(amount);
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(agreement_id.clone()), &escrow);
+            .set(&DataKey::RentSplit(agreement_id.clone()), &splits);
 
-        // Record
-        Self::append_payment(
-            &env,
-            PaymentRecord {
-                id: Self::new_id(&env),
-                agreement_id: agreement_id.clone(),
-                payer: agreement.tenant.clone(),
-                payee: agreement.landlord.clone(),
-                amount,
-                payment_type: PaymentType::MonthlyRent,
-                timestamp: now,
-            },
-        );
+        env.events()
+            .publish((Symbol::new(&env, "RentSplitSet"),), (agreement_id, splits));
+    }
 
-        env.events().publish(
-            (Symbol::new(&env, "RentPaymentReceived"),),
-            (agreement_id.clone(), agreement.tenant.clone(), amount),
-        );
-        env.events().publish(
-            (Symbol::new(&env, "RentReleasedToLandlord"),),
-            (agreement_id.clone(), agreement.landlord.clone(), amount),
-        );
+    pub fn get_rent_split(env: Env, agreement_id: BytesN<32>) -> Vec<(Address, i128)> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RentSplit(agreement_id))
+            .unwrap_or(Vec::new(&env))
+    }
 
-        // Notify agreement contract
-        let agreement_client = Self::agreement_client(&env);
-        agreement_client.record_rent_payment(&agreement_id, &amount);
+    pub fn is_split_paid(env: Env, agreement_id: BytesN<32>, payer: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::SplitPaid(agreement_id, payer))
     }
 
-    // Release security deposit back to tenant.
-    // MVP: only allowed after agreement is completed.
-    pub fn release_deposit_to_tenant(env: Env, caller: Address, agreement_id: BytesN<32>) {
+    // Monthly rent payments (manual, no recurring). Tenant pays contract, contract releases to landlord.
+    // With a rent split, each co-tenant pays their share and the month is
+    // forwarded to the landlord once every share is in.
+    pub fn pay_rent(env: Env, payer: Address, agreement_id: BytesN<32>) {
         Self::check_not_paused(&env);
 
-        caller.require_auth();
+        payer.require_auth();
 
         let agreement = Self::fetch_agreement(&env, agreement_id.clone());
-        if caller != agreement.tenant && caller != agreement.landlord {
-            panic_with_error!(&env, Error::Unauthorized);
+        if agreement.status != AgreementStatus::Active {
+            panic_with_error!(&env, Error::InvalidAgreementState);
         }
-        if agreement.status != AgreementStatus::Completed {
-            panic_with_error!(&env, Error::AgreementNotCompleted);
+
+        let splits = Self::get_rent_split(env.clone(), agreement_id.clone());
+        if !splits.is_empty() {
+            Self::pay_rent_share(&env, &agreement, &splits, payer);
+            return;
         }
 
-        let mut escrow = Self::get_escrow(env.clone(), agreement_id.clone());
-        if escrow.security_deposit_held <= 0 {
-            panic_with_error!(&env, Error::DepositNotPaid);
+        // A co-signer may step in once the tenant has fallen behind.
+        if payer != agreement.tenant {
+            if agreement.co_signer != Some(payer.clone())
+                || !Self::is_late_payment(&env, &agreement)
+            {
+                panic_with_error!(&env, Error::Unauthorized);
+            }
+            Self::agreement_client(&env).co_signer_guarantee_payment(&payer, &agreement_id);
         }
-        if escrow.is_deposit_released {
-            panic_with_error!(&env, Error::DepositAlreadyReleased);
+
+        let amount = agreement.monthly_rent;
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidPaymentAmount);
         }
 
-        let amount = escrow.security_deposit_held;
-        let token_client = Self::xlm_client(&env);
-        let contract_addr = env.current_contract_address();
-        token_client.transfer(&contract_addr, &escrow.tenant, &amount);
+        Self::collect_rent(&env, &agreement, &payer, amount, PaymentType::MonthlyRent);
+    }
 
-        escrow.security_deposit_held = 0;
+    // Emits `RentReminderDue` when the next month's rent is due within three
+    // days (or already overdue). Meant to be polled by off-chain bots, so it
+    // never fails: unknown or inactive agreements are ignored, and at most one
+    // reminder goes out per agreement per day.
+    pub fn emit_payment_reminder(env: Env, agreement_id: BytesN<32>) {
+        let Ok(Ok(agreement)) = Self::agreement_client(&env).try_get_agreement(&agreement_id)
+        else {
+            return;
+        };
+        if agreement.status != AgreementStatus::Active {
+            return;
+        }
+
+        let now = env.ledger().timestamp();
+        let expected_date = agreement
+            .start_date
+            .saturating_add(agreement.months_paid as u64 * SECONDS_PER_MONTH);
+        if expected_date >= agreement.end_date
+            || expected_date.saturating_sub(now) > REMINDER_LEAD_SECONDS
+        {
+            return;
+        }
+
+        let key = DataKey::LastReminderAt(agreement_id.clone());
+        if let Some(last) = env.storage().persistent().get::<_, u64>(&key) {
+            if now.saturating_sub(last) < SECONDS_PER_DAY {
+                return;
+            }
+        }
+        env.storage().persistent().set(&key, &now);
+
+        env.events().publish(
+            (Symbol::new(&env, "RentReminderDue"),),
+            (
+                agreement_id,
+                agreement.tenant,
+                agreement.landlord,
+                agreement.monthly_rent,
+                expected_date,
+            ),
+        );
+    }
+
+    // Whole months of rent the tenant is behind on. Only active agreements
+    // accrue arrears, and never for months past the end date.
+    pub fn get_outstanding_rent(env: Env, agreement_id: BytesN<32>) -> i128 {
+        let agreement = Self::fetch_agreement(&env, agreement_id);
+        Self::outstanding_rent(&env, &agreement)
+    }
+
+    // Sums arrears across a landlord's agreements. Loads every agreement the
+    // landlord has, so this is meant for off-chain dashboards. Named
+    // `get_landlord_outstanding_total` because `get_total_outstanding_for_landlord`
+    // is over the 32-character limit for contract function names.
+    pub fn get_landlord_outstanding_total(env: Env, landlord: Address) -> i128 {
+        let agreements = Self::agreement_client(&env).get_agreements_by_landlord(&landlord);
+        let mut total: i128 = 0;
+        for agreement in agreements.iter() {
+            total = total.saturating_add(Self::outstanding_rent(&env, &agreement));
+        }
+        total
+    }
+
+    // Rent owed for the time elapsed since the end of the last paid month, up
+    // to the end date. Assumes 30-day months throughout, matching how overdue
+    // payments are measured.
+    pub fn calculate_prorated_rent(env: Env, agreement_id: BytesN<32>) -> i128 {
+        let agreement = Self::fetch_agreement(&env, agreement_id);
+        Self::prorated_rent(&env, &agreement)
+    }
+
+    // Final rent payment after notice has been served: the tenant pays only
+    // the prorated share of the month they terminate in. Can be paid once,
+    // and only when no whole months are outstanding (those go through
+    // `pay_rent`).
+    pub fn pay_prorated_rent(env: Env, tenant: Address, agreement_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        tenant.require_auth();
+
+        let agreement = Self::fetch_agreement(&env, agreement_id.clone());
+        if tenant != agreement.tenant {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        if agreement.status != AgreementStatus::Active {
+            panic_with_error!(&env, Error::InvalidAgreementState);
+        }
+        if agreement.notice_served_by.is_none() {
+            panic_with_error!(&env, Error::NoticeNotServed);
+        }
+        let paid_key = DataKey::ProratedRentPaid(agreement_id.clone());
+        if env.storage().persistent().has(&paid_key) {
+            panic_with_error!(&env, Error::ProratedRentAlreadyPaid);
+        }
+        if Self::outstanding_rent(&env, &agreement) > 0 {
+            panic_with_error!(&env, Error::RentInArrears);
+        }
+
+        let amount = Self::prorated_rent(&env, &agreement);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidPaymentAmount);
+        }
+
+        env.storage().persistent().set(&paid_key, &true);
+        Self::collect_rent(
+            &env,
+            &agreement,
+            &agreement.tenant,
+            amount,
+            PaymentType::ProratedRent,
+        );
+    }
+
+    // Either party asks for the deposit to go back to the tenant once the
+    // agreement is completed. Nothing moves until the other party confirms.
+    pub fn request_deposit_release(env: Env, requester: Address, agreement_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        requester.require_auth();
+
+        let agreement = Self::fetch_agreement(&env, agreement_id.clone());
+        if requester != agreement.tenant && requester != agreement.landlord {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        Self::releasable_escrow(&env, &agreement);
+
+        let key = DataKey::DepositReleaseRequest(agreement_id.clone());
+        if env.storage().persistent().has(&key) {
+            panic_with_error!(&env, Error::ReleaseAlreadyRequested);
+        }
+
+        let now = env.ledger().timestamp();
+        env.storage().persistent().set(
+            &key,
+            &DepositReleaseRequest {
+                requested_by: requester.clone(),
+                confirmed_by: None,
+                requested_at: now,
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "DepositReleaseRequested"),),
+            (agreement_id, requester, now),
+        );
+    }
+
+    // The party that didn't request the release confirms it, which releases
+    // the deposit to the tenant.
+    pub fn confirm_deposit_release(env: Env, confirmer: Address, agreement_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        confirmer.require_auth();
+
+        let agreement = Self::fetch_agreement(&env, agreement_id.clone());
+        if confirmer != agreement.tenant && confirmer != agreement.landlord {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+
+        let key = DataKey::DepositReleaseRequest(agreement_id.clone());
+        let mut request: DepositReleaseRequest = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ReleaseNotRequested));
+        if request.requested_by == confirmer || request.confirmed_by.is_some() {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+
+        let escrow = Self::releasable_escrow(&env, &agreement);
+
+        request.confirmed_by = Some(confirmer.clone());
+        env.storage().persistent().set(&key, &request);
+
+        env.events().publish(
+            (Symbol::new(&env, "DepositReleaseConfirmed"),),
+            (agreement_id.clone(), confirmer),
+        );
+
+        Self::release_to_tenant(&env, agreement_id, escrow);
+    }
+
+    pub fn get_deposit_release_request(
+        env: Env,
+        agreement_id: BytesN<32>,
+    ) -> Option<DepositReleaseRequest> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DepositReleaseRequest(agreement_id))
+    }
+
+    // Admin override for the dispute-resolution path: releases the deposit
+    // to the tenant without both parties' sign-off.
+    pub fn release_deposit_to_tenant(env: Env, agreement_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let agreement = Self::fetch_agreement(&env, agreement_id.clone());
+        let escrow = Self::releasable_escrow(&env, &agreement);
+        Self::release_to_tenant(&env, agreement_id, escrow);
+    }
+
+    pub fn set_max_deposit_hold_days(env: Env, days: u32) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        if days == 0 {
+            panic_with_error!(&env, Error::InvalidHoldPeriod);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxDepositHoldDays, &days);
+
+        env.events()
+            .publish((Symbol::new(&env, "MaxDepositHoldDaysSet"),), days);
+    }
+
+    pub fn get_max_deposit_hold_days(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxDepositHoldDays)
+            .unwrap_or(DEFAULT_MAX_DEPOSIT_HOLD_DAYS)
+    }
+
+    // Anyone can return a deposit to the tenant once it has been held longer
+    // than the max hold period after completion. An open dispute blocks this
+    // until the dispute itself is older than the same period.
+    pub fn force_release_expired_deposit(env: Env, agreement_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        let agreement = Self::fetch_agreement(&env, agreement_id.clone());
+        let escrow = Self::releasable_escrow(&env, &agreement);
+
+        let now = env.ledger().timestamp();
+        let max_hold = Self::get_max_deposit_hold_days(env.clone()) as u64 * SECONDS_PER_DAY;
+        if now <= agreement.completed_at.saturating_add(max_hold) {
+            panic_with_error!(&env, Error::DepositHoldNotExpired);
+        }
+        if let Some(dispute_addr) = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::DisputeContract)
+        {
+            let filed_at = DisputeResolutionClient::new(&env, &dispute_addr)
+                .get_open_dispute_filed_at(&agreement_id);
+            if let Some(filed_at) = filed_at {
+                if now <= filed_at.saturating_add(max_hold) {
+                    panic_with_error!(&env, Error::DepositHoldNotExpired);
+                }
+            }
+        }
+
+        let amount = escrow.security_deposit_held;
+        Self::release_to_tenant(&env, agreement_id.clone(), escrow);
+
+        env.events().publish(
+            (Symbol::new(&env, "DepositForceReleased"),),
+            (agreement_id, agreement.tenant, amount),
+        );
+    }
+
+    // Dispute settlement: `amount` of the held deposit goes to the landlord and
+    // the remainder back to the tenant. Only callable by the dispute contract.
+    pub fn release_deposit_to_landlord(env: Env, agreement_id: BytesN<32>, amount: i128) {
+        Self::check_not_paused(&env);
+
+        let dispute_contract: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::DisputeContract)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Unauthorized));
+        dispute_contract.require_auth();
+
+        let mut escrow = Self::get_escrow(env.clone(), agreement_id.clone());
+        if escrow.is_deposit_released {
+            panic_with_error!(&env, Error::DepositAlreadyReleased);
+        }
+        let held = escrow.security_deposit_held;
+        if held <= 0 {
+            panic_with_error!(&env, Error::DepositNotPaid);
+        }
+        if amount < 0 || amount > held {
+            panic_with_error!(&env, Error::InvalidPaymentAmount);
+        }
+
+        let token_client = Self::xlm_client(&env);
+        let contract_addr = env.current_contract_address();
+        let now = env.ledger().timestamp();
+        let to_tenant = held - amount;
+        for (payee, share, payment_type) in [
+            (&escrow.landlord, amount, PaymentType::DisputeSettlement),
+            (&escrow.tenant, to_tenant, PaymentType::DepositRelease),
+        ] {
+            if share <= 0 {
+                continue;
+            }
+            token_client.transfer(&contract_addr, payee, &share);
+            Self::append_payment(
+                &env,
+                PaymentRecord {
+                    id: Self::new_id(&env),
+                    agreement_id: agreement_id.clone(),
+                    payer: contract_addr.clone(),
+                    payee: payee.clone(),
+                    amount: share,
+                    payment_type,
+                    timestamp: now,
+                },
+            );
+        }
+
+        escrow.security_deposit_held = 0;
         escrow.is_deposit_released = true;
-        escrow.deposit_released_at = env.ledger().timestamp();
+        escrow.deposit_released_at = now;
+        Self::adjust_total_deposits_held(&env, -held);
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(agreement_id.clone()), &escrow);
 
-        let now = env.ledger().timestamp();
-        Self::append_payment(
+        env.events().publish(
+            (Symbol::new(&env, "DepositSettledByDispute"),),
+            (agreement_id, amount, to_tenant),
+        );
+    }
+
+    // Called by the agreement contract once an agreement is awaiting payment,
+    // so the escrow account exists before it is funded. The agreement data is
+    // passed in because calling back into the agreement contract here would
+    // be re-entrant.
+    pub fn initialize_escrow_account(
+        env: Env,
+        agreement_id: BytesN<32>,
+        landlord: Address,
+        tenant: Address,
+        security_deposit: i128,
+        monthly_rent: i128,
+    ) {
+        Self::check_not_paused(&env);
+
+        let agreement_contract: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::AgreementContract)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Unauthorized));
+        agreement_contract.require_auth();
+
+        let key = DataKey::Escrow(agreement_id.clone());
+        if env.storage().persistent().has(&key) {
+            return;
+        }
+
+        let escrow = Self::empty_escrow(
             &env,
-            PaymentRecord {
-                id: Self::new_id(&env),
-                agreement_id: agreement_id.clone(),
-                payer: contract_addr,
-                payee: escrow.tenant.clone(),
-                amount,
-                payment_type: PaymentType::DepositRelease,
-                timestamp: now,
-            },
+            agreement_id.clone(),
+            landlord,
+            tenant,
+            security_deposit,
+            monthly_rent,
         );
+        env.storage().persistent().set(&key, &escrow);
 
         env.events().publish(
-            (Symbol::new(&env, "DepositReleasedToTenant"),),
-            (agreement_id, escrow.tenant, amount),
+            (Symbol::new(&env, "EscrowAccountInitialized"),),
+            (agreement_id, escrow.created_at),
         );
     }
 
-    // Placeholder for future disputes: release part/all deposit to landlord.
-    // MVP: not implemented.
-    pub fn release_deposit_to_landlord(_env: Env, _agreement_id: BytesN<32>, _amount: i128) {
-        // Intentionally left blank for MVP.
-        // Future: require both party signatures or dispute resolution.
+    // Called by the agreement contract when a lease is transferred to a new
+    // tenant. Accounts that don't exist yet pick up the new tenant on funding.
+    pub fn update_escrow_tenant(env: Env, agreement_id: BytesN<32>, new_tenant: Address) {
+        Self::check_not_paused(&env);
+
+        let agreement_contract: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::AgreementContract)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Unauthorized));
+        agreement_contract.require_auth();
+
+        // The split was agreed with the old co-tenants; refund whatever they
+        // have paid towards the current month and drop it.
+        Self::clear_rent_split(&env, &agreement_id);
+
+        let key = DataKey::Escrow(agreement_id.clone());
+        if let Some(mut escrow) = env.storage().persistent().get::<_, EscrowAccount>(&key) {
+            let previous_tenant = escrow.tenant.clone();
+            escrow.tenant = new_tenant.clone();
+            env.storage().persistent().set(&key, &escrow);
+
+            env.events().publish(
+                (Symbol::new(&env, "EscrowTenantUpdated"),),
+                (agreement_id, previous_tenant, new_tenant),
+            );
+        }
     }
 
+    // Accounts that were never initialized or funded read as empty, as long
+    // as the agreement itself exists.
     pub fn get_escrow(env: Env, agreement_id: BytesN<32>) -> EscrowAccount {
-        env.storage()
+        if let Some(escrow) = env
+            .storage()
             .persistent()
-            .get(&DataKey::Escrow(agreement_id))
-            .unwrap_or_else(|| panic_with_error!(&env, Error::AgreementNotFound))
+            .get(&DataKey::Escrow(agreement_id.clone()))
+        {
+            return escrow;
+        }
+
+        let Ok(Ok(agreement)) = Self::agreement_client(&env).try_get_agreement(&agreement_id)
+        else {
+            panic_with_error!(&env, Error::AgreementNotFound)
+        };
+        Self::empty_escrow(
+            &env,
+            agreement_id,
+            agreement.landlord,
+            agreement.tenant,
+            agreement.security_deposit,
+            agreement.monthly_rent,
+        )
     }
 
     pub fn get_payment_history(env: Env, agreement_id: BytesN<32>) -> Vec<PaymentRecord> {
@@ -432,6 +1240,125 @@ impl EscrowManager {
             .unwrap_or(Vec::<PaymentRecord>::new(&env))
     }
 
+    // Moves a settled escrow account and its payment history out of
+    // persistent storage once the deposit has been released, and drops
+    // per-agreement flags that are no longer needed. Any rent shares still
+    // held for a split month are refunded. The archived copy lives in
+    // temporary storage for ARCHIVE_TTL_LEDGERS (about 180 days); the
+    // `EscrowArchived` event is the lasting record.
+    pub fn archive_escrow(env: Env, agreement_id: BytesN<32>) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let escrow = Self::get_escrow(env.clone(), agreement_id.clone());
+        if !escrow.is_deposit_released {
+            panic_with_error!(&env, Error::DepositNotReleased);
+        }
+        let payments = Self::get_payment_history(env.clone(), agreement_id.clone());
+
+        let archive = env.storage().temporary();
+        let escrow_key = DataKey::ArchivedEscrow(agreement_id.clone());
+        let payments_key = DataKey::ArchivedPayments(agreement_id.clone());
+        archive.set(&escrow_key, &escrow);
+        archive.set(&payments_key, &payments);
+        archive.extend_ttl(&escrow_key, ARCHIVE_TTL_LEDGERS, ARCHIVE_TTL_LEDGERS);
+        archive.extend_ttl(&payments_key, ARCHIVE_TTL_LEDGERS, ARCHIVE_TTL_LEDGERS);
+        Self::clear_rent_split(&env, &agreement_id);
+
+        let storage = env.storage().persistent();
+        storage.remove(&DataKey::Escrow(agreement_id.clone()));
+        storage.remove(&DataKey::Payments(agreement_id.clone()));
+        storage.remove(&DataKey::DepositReleaseRequest(agreement_id.clone()));
+        storage.remove(&DataKey::ProratedRentPaid(agreement_id.clone()));
+        storage.remove(&DataKey::LastReminderAt(agreement_id.clone()));
+
+        env.events().publish(
+            (Symbol::new(&env, "EscrowArchived"),),
+            (
+                agreement_id,
+                escrow.total_rent_received,
+                escrow.deposit_released_at,
+            ),
+        );
+    }
+
+    pub fn get_archived_escrow(env: Env, agreement_id: BytesN<32>) -> EscrowAccount {
+        env.storage()
+            .temporary()
+            .get(&DataKey::ArchivedEscrow(agreement_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::AgreementNotFound))
+    }
+
+    pub fn get_archived_payments(env: Env, agreement_id: BytesN<32>) -> Vec<PaymentRecord> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::ArchivedPayments(agreement_id))
+            .unwrap_or(Vec::<PaymentRecord>::new(&env))
+    }
+
+    // One-off migration for contracts upgraded from before deposits were
+    // totalled: adds the deposits still held by the given escrows (those
+    // funded before the upgrade) to the running total. Must run before any
+    // of them is released, or the total goes negative.
+    pub fn backfill_total_deposits_held(env: Env, agreement_ids: Vec<BytesN<32>>) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        if env.storage().instance().has(&DataKey::DepositsBackfilled) {
+            panic_with_error!(&env, Error::DepositsAlreadyBackfilled);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::DepositsBackfilled, &true);
+
+        let mut added: i128 = 0;
+        for agreement_id in agreement_ids.iter() {
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<_, EscrowAccount>(&DataKey::Escrow(agreement_id))
+            {
+                if !escrow.is_deposit_released {
+                    added = added.saturating_add(escrow.security_deposit_held);
+                }
+            }
+        }
+        Self::adjust_total_deposits_held(&env, added);
+
+        env.events()
+            .publish((Symbol::new(&env, "DepositsBackfilled"),), added);
+    }
+
+    // Compares the deposits and co-tenant rent shares this contract thinks it
+    // holds with its actual token balance. A positive discrepancy means the
+    // contract holds more than recorded (e.g. a direct transfer in, or
+    // deposits from before the upgrade that haven't been backfilled).
+    pub fn escrow_health_check(env: Env) -> HealthReport {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let total_deposits_recorded = Self::total_deposits_held(&env);
+        let total_split_shares_held = Self::split_shares_held(&env);
+        let contract_token_balance =
+            Self::xlm_client(&env).balance(&env.current_contract_address());
+        let discrepancy =
+            contract_token_balance - total_deposits_recorded - total_split_shares_held;
+
+        if discrepancy != 0 {
+            env.events().publish(
+                (Symbol::new(&env, "EscrowDiscrepancyDetected"),),
+                (total_deposits_recorded, contract_token_balance, discrepancy),
+            );
+        }
+
+        HealthReport {
+            total_deposits_recorded,
+            total_split_shares_held,
+            contract_token_balance,
+            discrepancy,
+        }
+    }
+
     // Emergency: admin can withdraw any held balance tracked for agreement.
     // This is meant for testnet MVP and should be removed or heavily restricted post-MVP.
     pub fn emergency_withdraw(env: Env, agreement_id: BytesN<32>, to: Address) {
@@ -453,6 +1380,7 @@ impl EscrowManager {
         escrow.security_deposit_held = 0;
         escrow.is_deposit_released = true;
         escrow.deposit_released_at = env.ledger().timestamp();
+        Self::adjust_total_deposits_held(&env, -amount);
 
         env.storage()
             .persistent()
@@ -489,6 +1417,13 @@ impl EscrowManager {
         }
     }
 
+    fn clear_pending_admin(env: &Env) {
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        env.storage()
+            .instance()
+            .remove(&DataKey::AdminTransferExpiry);
+    }
+
     fn require_admin(env: &Env) -> Address {
         env.storage()
             .instance()
@@ -505,6 +1440,29 @@ impl EscrowManager {
         RentalAgreementClient::new(env, &addr)
     }
 
+    fn empty_escrow(
+        env: &Env,
+        agreement_id: BytesN<32>,
+        landlord: Address,
+        tenant: Address,
+        security_deposit: i128,
+        monthly_rent: i128,
+    ) -> EscrowAccount {
+        EscrowAccount {
+            agreement_id,
+            landlord,
+            tenant,
+            security_deposit_amount: security_deposit,
+            security_deposit_held: 0,
+            monthly_rent_amount: monthly_rent,
+            total_rent_received: 0,
+            total_rent_released: 0,
+            is_deposit_released: false,
+            deposit_released_at: 0,
+            created_at: env.ledger().timestamp(),
+        }
+    }
+
     fn fetch_agreement(env: &Env, agreement_id: BytesN<32>) -> RentalAgreement {
         Self::agreement_client(env).get_agreement(&agreement_id)
     }
@@ -518,6 +1476,372 @@ impl EscrowManager {
         token::Client::new(env, &token_addr)
     }
 
+    // A payment is late once more whole months have elapsed since start than
+    // months already paid (same rule RentalAgreement uses for overdue checks).
+    fn is_late_payment(env: &Env, agreement: &RentalAgreement) -> bool {
+        let elapsed = env
+            .ledger()
+            .timestamp()
+            .saturating_sub(agreement.start_date);
+        agreement.months_paid < (elapsed / SECONDS_PER_MONTH) as u32
+    }
+
+    // Updates the tenant's payment streak and pays rent cashback. Reward
+    // failures (e.g. BRIQ paused or at its supply cap) must not block rent.
+    fn maybe_reward_payment(env: &Env, agreement: &RentalAgreement, amount: i128) {
+        if let Some(token_addr) = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::RewardToken)
+        {
+            let client = RewardTokenClient::new(env, &token_addr);
+            if Self::is_late_payment(env, agreement) {
+                let _ = client.try_record_missed_payment(&agreement.tenant);
+            } else {
+                let _ = client.try_record_on_time_payment(&agreement.tenant);
+            }
+            let _ = client.try_reward_rent_payment(
+                &env.current_contract_address(),
+                &agreement.id,
+                &agreement.tenant,
+                &amount,
+            );
+        }
+    }
+
+    fn maybe_update_credit(env: &Env, agreement: &RentalAgreement) {
+        if let Some(credit_addr) = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::CreditContract)
+        {
+            let client = TenantCreditClient::new(env, &credit_addr);
+            if Self::is_late_payment(env, agreement) {
+                client.update_late_payment(&agreement.id, &agreement.tenant);
+            } else {
+                client.update_on_time_payment(&agreement.id, &agreement.tenant);
+            }
+        }
+    }
+
+    // Sends the platform fee on a rent release to the treasury and returns it.
+    // The landlord's pending BRIQ fee discount, if any, is applied and used up.
+    fn maybe_collect_fee(env: &Env, agreement: &RentalAgreement, rent: i128) -> i128 {
+        let Some(treasury_addr) = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::Treasury)
+        else {
+            return 0;
+        };
+        let treasury = PlatformTreasuryClient::new(env, &treasury_addr);
+        if treasury.is_fee_exempt(&agreement.landlord) {
+            return 0;
+        }
+
+        let mut fee_bps = treasury.get_fee_rate();
+        if fee_bps > 0 {
+            if let Some(token_addr) = env
+                .storage()
+                .instance()
+                .get::<_, Address>(&DataKey::RewardToken)
+            {
+                let discount = RewardTokenClient::new(env, &token_addr)
+                    .consume_pending_discount(&agreement.landlord);
+                fee_bps = fee_bps.saturating_sub(discount);
+            }
+        }
+
+        let fee = rent * fee_bps as i128 / BPS_DENOMINATOR;
+        if fee <= 0 {
+            return 0;
+        }
+
+        let token_client = Self::xlm_client(env);
+        token_client.transfer(&env.current_contract_address(), &treasury_addr, &fee);
+        treasury.deposit(&fee, &token_client.address);
+
+        Self::append_payment(
+            env,
+            PaymentRecord {
+                id: Self::new_id(env),
+                agreement_id: agreement.id.clone(),
+                payer: agreement.landlord.clone(),
+                payee: treasury_addr,
+                amount: fee,
+                payment_type: PaymentType::PlatformFee,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        env.events().publish(
+            (Symbol::new(env, "PlatformFeeCollected"),),
+            (agreement.id.clone(), agreement.landlord.clone(), fee),
+        );
+
+        fee
+    }
+
+    fn releasable_escrow(env: &Env, agreement: &RentalAgreement) -> EscrowAccount {
+        if agreement.status != AgreementStatus::Completed {
+            panic_with_error!(env, Error::AgreementNotCompleted);
+        }
+
+        let escrow = Self::get_escrow(env.clone(), agreement.id.clone());
+        if escrow.security_deposit_held <= 0 {
+            panic_with_error!(env, Error::DepositNotPaid);
+        }
+        if escrow.is_deposit_released {
+            panic_with_error!(env, Error::DepositAlreadyReleased);
+        }
+        escrow
+    }
+
+    fn release_to_tenant(env: &Env, agreement_id: BytesN<32>, mut escrow: EscrowAccount) {
+        let amount = escrow.security_deposit_held;
+        let token_client = Self::xlm_client(env);
+        let contract_addr = env.current_contract_address();
+        token_client.transfer(&contract_addr, &escrow.tenant, &amount);
+
+        escrow.security_deposit_held = 0;
+        escrow.is_deposit_released = true;
+        escrow.deposit_released_at = env.ledger().timestamp();
+        Self::adjust_total_deposits_held(env, -amount);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(agreement_id.clone()), &escrow);
+
+        let now = env.ledger().timestamp();
+        Self::append_payment(
+            env,
+            PaymentRecord {
+                id: Self::new_id(env),
+                agreement_id: agreement_id.clone(),
+                payer: contract_addr,
+                payee: escrow.tenant.clone(),
+                amount,
+                payment_type: PaymentType::DepositRelease,
+                timestamp: now,
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(env, "DepositReleasedToTenant"),),
+            (agreement_id, escrow.tenant, amount),
+        );
+    }
+
+    // Moves a rent payment from the payer (tenant or co-signer) to the landlord.
+    fn collect_rent(
+        env: &Env,
+        agreement: &RentalAgreement,
+        payer: &Address,
+        amount: i128,
+        payment_type: PaymentType,
+    ) {
+        // Payer -> Escrow (rent)
+        Self::xlm_client(env).transfer(payer, &env.current_contract_address(), &amount);
+        Self::forward_rent(env, agreement, amount, payment_type);
+    }
+
+    // Holds one co-tenant's share; forwards the month once all are paid.
+    fn pay_rent_share(
+        env: &Env,
+        agreement: &RentalAgreement,
+        splits: &Vec<(Address, i128)>,
+        payer: Address,
+    ) {
+        let share = splits
+            .iter()
+            .find(|(addr, _)| *addr == payer)
+            .map(|(_, share)| share)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+
+        let paid_key = DataKey::SplitPaid(agreement.id.clone(), payer.clone());
+        if env.storage().persistent().has(&paid_key) {
+            panic_with_error!(env, Error::SplitAlreadyPaid);
+        }
+
+        // Co-tenant -> Escrow (share)
+        Self::xlm_client(env).transfer(&payer, &env.current_contract_address(), &share);
+        env.storage().persistent().set(&paid_key, &true);
+        Self::adjust_split_shares_held(env, share);
+
+        env.events().publish(
+            (Symbol::new(env, "RentSplitPaid"),),
+            (agreement.id.clone(), payer, share),
+        );
+
+        let all_paid = splits.iter().all(|(addr, _)| {
+            env.storage()
+                .persistent()
+                .has(&DataKey::SplitPaid(agreement.id.clone(), addr))
+        });
+        if !all_paid {
+            return;
+        }
+
+        let mut total: i128 = 0;
+        for (addr, share) in splits.iter() {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::SplitPaid(agreement.id.clone(), addr));
+            total = total.saturating_add(share);
+        }
+        Self::adjust_split_shares_held(env, -total);
+        Self::forward_rent(env, agreement, total, PaymentType::MonthlyRent);
+    }
+
+    // Refunds any shares held for the current month and removes the split.
+    fn clear_rent_split(env: &Env, agreement_id: &BytesN<32>) {
+        let key = DataKey::RentSplit(agreement_id.clone());
+        let Some(splits) = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<(Address, i128)>>(&key)
+        else {
+            return;
+        };
+
+        let contract_addr = env.current_contract_address();
+        for (payer, share) in splits.iter() {
+            let paid_key = DataKey::SplitPaid(agreement_id.clone(), payer.clone());
+            if !env.storage().persistent().has(&paid_key) {
+                continue;
+            }
+            env.storage().persistent().remove(&paid_key);
+            Self::adjust_split_shares_held(env, -share);
+            // Escrow -> Co-tenant (refund)
+            Self::xlm_client(env).transfer(&contract_addr, &payer, &share);
+        }
+        env.storage().persistent().remove(&key);
+
+        env.events().publish(
+            (Symbol::new(env, "RentSplitCleared"),),
+            agreement_id.clone(),
+        );
+    }
+
+    // Releases rent already held by the escrow to the landlord (less any
+    // platform fee), records it, and notifies the agreement, reward and
+    // credit contracts.
+    fn forward_rent(
+        env: &Env,
+        agreement: &RentalAgreement,
+        amount: i128,
+        payment_type: PaymentType,
+    ) {
+        let token_client = Self::xlm_client(env);
+        let contract_addr = env.current_contract_address();
+
+        // Escrow -> Treasury (platform fee), Escrow -> Landlord (rent)
+        let fee = Self::maybe_collect_fee(env, agreement, amount);
+        token_client.transfer(&contract_addr, &agreement.landlord, &(amount - fee));
+
+        // Update escrow
+        let now = env.ledger().timestamp();
+        let mut escrow = Self::get_escrow(env.clone(), agreement.id.clone());
+        escrow.total_rent_received = escrow.total_rent_received.saturating_add(amount);
+        escrow.total_rent_released = escrow.total_rent_released.saturating_add(amount);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(agreement.id.clone()), &escrow);
+
+        // Record
+        let prorated = payment_type == PaymentType::ProratedRent;
+        Self::append_payment(
+            env,
+            PaymentRecord {
+                id: Self::new_id(env),
+                agreement_id: agreement.id.clone(),
+                payer: agreement.tenant.clone(),
+                payee: agreement.landlord.clone(),
+                amount,
+                payment_type,
+                timestamp: now,
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(env, "RentPaymentReceived"),),
+            (agreement.id.clone(), agreement.tenant.clone(), amount),
+        );
+        env.events().publish(
+            (Symbol::new(env, "RentReleasedToLandlord"),),
+            (agreement.id.clone(), agreement.landlord.clone(), amount),
+        );
+
+        // Notify agreement contract; a prorated payment doesn't complete a month.
+        let agreement_client = Self::agreement_client(env);
+        if prorated {
+            agreement_client.record_prorated_payment(&agreement.id, &amount);
+        } else {
+            agreement_client.record_rent_payment(&agreement.id, &amount);
+        }
+
+        Self::maybe_reward_payment(env, agreement, amount);
+        Self::maybe_update_credit(env, agreement);
+    }
+
+    fn outstanding_rent(env: &Env, agreement: &RentalAgreement) -> i128 {
+        if agreement.status != AgreementStatus::Active {
+            return 0;
+        }
+        let elapsed = env
+            .ledger()
+            .timestamp()
+            .saturating_sub(agreement.start_date);
+        // Same cap RentalAgreement applies for overdue checks.
+        let duration = agreement.end_date.saturating_sub(agreement.start_date);
+        let expected_months =
+            (elapsed / SECONDS_PER_MONTH).min(duration.div_ceil(SECONDS_PER_MONTH)) as u32;
+        if agreement.months_paid >= expected_months {
+            return 0;
+        }
+        (expected_months - agreement.months_paid) as i128 * agreement.monthly_rent
+    }
+
+    fn prorated_rent(env: &Env, agreement: &RentalAgreement) -> i128 {
+        let paid_until = agreement
+            .start_date
+            .saturating_add(agreement.months_paid as u64 * SECONDS_PER_MONTH);
+        let until = env.ledger().timestamp().min(agreement.end_date);
+        let days_elapsed = until.saturating_sub(paid_until) / SECONDS_PER_DAY;
+        agreement.monthly_rent * days_elapsed as i128 / DAYS_PER_MONTH as i128
+    }
+
+    fn total_deposits_held(env: &Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalDepositsHeld)
+            .unwrap_or(0)
+    }
+
+    fn adjust_total_deposits_held(env: &Env, delta: i128) {
+        let total = Self::total_deposits_held(env);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalDepositsHeld, &(total + delta));
+    }
+
+    fn split_shares_held(env: &Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalSplitSharesHeld)
+            .unwrap_or(0)
+    }
+
+    fn adjust_split_shares_held(env: &Env, delta: i128) {
+        let total = Self::split_shares_held(env);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalSplitSharesHeld, &(total + delta));
+    }
+
+    // True while the contract holds deposits or co-tenant rent shares.
+    fn holds_funds(env: &Env) -> bool {
+        Self::total_deposits_held(env) != 0 || Self::split_shares_held(env) != 0
+    }
+
     fn append_payment(env: &Env, rec: PaymentRecord) {
         let key = rec.agreement_id.clone();
         let mut v: Vec<PaymentRecord> = env
@@ -537,7 +1861,143 @@ impl EscrowManager {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    // Stands in for RentalAgreement: stores agreements as given and applies
+    // the escrow's callbacks to them.
+    #[contract]
+    struct MockAgreement;
+
+    #[contractimpl]
+    impl MockAgreement {
+        pub fn set_agreement(env: Env, agreement: RentalAgreement) {
+            let ids_key = Symbol::new(&env, "ids");
+            let mut ids: Vec<BytesN<32>> = env
+                .storage()
+                .instance()
+                .get(&ids_key)
+                .unwrap_or(Vec::new(&env));
+            if !ids.contains(&agreement.id) {
+                ids.push_back(agreement.id.clone());
+                env.storage().instance().set(&ids_key, &ids);
+            }
+            env.storage().instance().set(&agreement.id, &agreement);
+        }
+
+        pub fn get_agreement(env: Env, agreement_id: BytesN<32>) -> RentalAgreement {
+            env.storage().instance().get(&agreement_id).unwrap()
+        }
+
+        pub fn mark_deposit_paid(env: Env, agreement_id: BytesN<32>) {
+            let mut a = Self::get_agreement(env.clone(), agreement_id);
+            a.deposit_paid = true;
+            a.status = AgreementStatus::Active;
+            Self::set_agreement(env, a);
+        }
+
+        pub fn record_rent_payment(env: Env, agreement_id: BytesN<32>, amount: i128) {
+            let mut a = Self::get_agreement(env.clone(), agreement_id);
+            a.total_rent_paid += amount;
+            a.months_paid += 1;
+            Self::set_agreement(env, a);
+        }
+
+        pub fn record_prorated_payment(env: Env, agreement_id: BytesN<32>, amount: i128) {
+            let mut a = Self::get_agreement(env.clone(), agreement_id);
+            a.total_rent_paid += amount;
+            Self::set_agreement(env, a);
+        }
+
+        pub fn get_agreements_by_landlord(env: Env, landlord: Address) -> Vec<RentalAgreement> {
+            let ids: Vec<BytesN<32>> = env
+                .storage()
+                .instance()
+                .get(&Symbol::new(&env, "ids"))
+                .unwrap_or(Vec::new(&env));
+            let mut out = Vec::new(&env);
+            for id in ids.iter() {
+                let a = Self::get_agreement(env.clone(), id);
+                if a.landlord == landlord {
+                    out.push_back(a);
+                }
+            }
+            out
+        }
+
+        pub fn co_signer_guarantee_payment(
+            _env: Env,
+            _co_signer: Address,
+            _agreement_id: BytesN<32>,
+        ) {
+        }
+    }
+
+    struct Setup<'a> {
+        escrow: EscrowManagerClient<'a>,
+        agreements: MockAgreementClient<'a>,
+        xlm: token::Client<'a>,
+        xlm_admin: token::StellarAssetClient<'a>,
+    }
+
+    fn setup(env: &Env) -> Setup<'_> {
+        env.mock_all_auths();
+
+        let agreement_id = env.register_contract(None, MockAgreement);
+        let contract_id = env.register_contract(None, EscrowManager);
+        let xlm = env.register_stellar_asset_contract_v2(Address::generate(env));
+
+        let escrow = EscrowManagerClient::new(env, &contract_id);
+        escrow.initialize(&Address::generate(env), &agreement_id, &xlm.address());
+
+        Setup {
+            escrow,
+            agreements: MockAgreementClient::new(env, &agreement_id),
+            xlm: token::Client::new(env, &xlm.address()),
+            xlm_admin: token::StellarAssetClient::new(env, &xlm.address()),
+        }
+    }
+
+    // Awaiting payment: rent 1_000, deposit 500, running for a year from 0.
+    fn agreement(env: &Env, seed: u8, tenant: &Address) -> RentalAgreement {
+        RentalAgreement {
+            id: BytesN::from_array(env, &[seed; 32]),
+            property_id: BytesN::from_array(env, &[seed; 32]),
+            landlord: Address::generate(env),
+            tenant: tenant.clone(),
+            monthly_rent: 1_000,
+            security_deposit: 500,
+            start_date: 0,
+            end_date: 12 * SECONDS_PER_MONTH,
+            status: AgreementStatus::PendingPayment,
+            landlord_signed: true,
+            landlord_signed_at: 0,
+            tenant_signed: true,
+            tenant_signed_at: 0,
+            deposit_paid: false,
+            deposit_paid_at: 0,
+            total_rent_paid: 0,
+            months_paid: 0,
+            created_at: 0,
+            completed_at: 0,
+            notice_period_days: 30,
+            notice_served_at: 0,
+            notice_served_by: None,
+            document_hash: BytesN::from_array(env, &[0; 32]),
+            co_signer: None,
+            subletting_allowed: false,
+            custom_clauses: Vec::new(env),
+        }
+    }
+
+    // Funded through the escrow with deposit and first month paid.
+    fn funded_agreement(env: &Env, t: &Setup<'_>, seed: u8) -> RentalAgreement {
+        let tenant = Address::generate(env);
+        t.xlm_admin.mint(&tenant, &10_000);
+        let a = agreement(env, seed, &tenant);
+        t.agreements.set_agreement(&a);
+        t.escrow.deposit_security_and_rent(&tenant, &a.id);
+        t.agreements.get_agreement(&a.id)
+    }
 
     #[test]
     fn initialize_smoke() {
@@ -555,4 +2015,331 @@ mod tests {
         client.pause();
         client.unpause();
     }
+
+    #[test]
+    fn prorated_final_payment_does_not_count_as_a_month() {
+        let env = Env::default();
+        let t = setup(&env);
+        let mut a = funded_agreement(&env, &t, 1);
+        assert_eq!(a.months_paid, 1);
+
+        a.notice_served_by = Some(a.tenant.clone());
+        t.agreements.set_agreement(&a);
+
+        // Two months elapsed, one paid: the full month has to be paid first.
+        env.ledger()
+            .set_timestamp(2 * SECONDS_PER_MONTH + 15 * SECONDS_PER_DAY);
+        assert_eq!(
+            t.escrow.try_pay_prorated_rent(&a.tenant, &a.id),
+            Err(Ok(Error::RentInArrears.into()))
+        );
+        t.escrow.pay_rent(&a.tenant, &a.id);
+
+        assert_eq!(t.escrow.calculate_prorated_rent(&a.id), 500);
+        t.escrow.pay_prorated_rent(&a.tenant, &a.id);
+        let a = t.agreements.get_agreement(&a.id);
+        assert_eq!(a.months_paid, 2);
+        assert_eq!(a.total_rent_paid, 2_500);
+        assert_eq!(t.xlm.balance(&a.landlord), 2_500);
+        assert_eq!(
+            t.escrow.try_pay_prorated_rent(&a.tenant, &a.id),
+            Err(Ok(Error::ProratedRentAlreadyPaid.into()))
+        );
+    }
+
+    #[test]
+    fn token_migration_waits_for_held_rent_shares() {
+        let env = Env::default();
+        let t = setup(&env);
+        let tenant = Address::generate(&env);
+        let co_tenant = Address::generate(&env);
+        t.xlm_admin.mint(&tenant, &10_000);
+        t.xlm_admin.mint(&co_tenant, &10_000);
+
+        let mut a = agreement(&env, 1, &tenant);
+        a.security_deposit = 0;
+        t.agreements.set_agreement(&a);
+        t.escrow.set_rent_split(
+            &a.landlord,
+            &a.id,
+            &Vec::from_array(&env, [(tenant.clone(), 600), (co_tenant.clone(), 400)]),
+        );
+        t.escrow.deposit_security_and_rent(&tenant, &a.id);
+
+        t.escrow.pay_rent(&co_tenant, &a.id);
+        let new_token = Address::generate(&env);
+        assert_eq!(
+            t.escrow.try_propose_token_migration(&new_token),
+            Err(Ok(Error::DepositsStillHeld.into()))
+        );
+
+        t.escrow.pay_rent(&tenant, &a.id);
+        t.escrow.propose_token_migration(&new_token);
+        assert_eq!(
+            t.escrow.try_accept_token_migration(),
+            Err(Ok(Error::TokenMigrationNotReady.into()))
+        );
+        env.ledger().set_timestamp(SECONDS_PER_DAY);
+        t.escrow.accept_token_migration();
+    }
+
+    #[test]
+    fn health_check_counts_deposits_and_rent_shares() {
+        let env = Env::default();
+        let t = setup(&env);
+        let a = funded_agreement(&env, &t, 1);
+
+        let tenant = Address::generate(&env);
+        let co_tenant = Address::generate(&env);
+        t.xlm_admin.mint(&tenant, &10_000);
+        t.xlm_admin.mint(&co_tenant, &10_000);
+        let split = agreement(&env, 2, &tenant);
+        t.agreements.set_agreement(&split);
+        t.escrow.set_rent_split(
+            &split.landlord,
+            &split.id,
+            &Vec::from_array(&env, [(tenant.clone(), 600), (co_tenant.clone(), 400)]),
+        );
+        t.escrow.deposit_security_and_rent(&tenant, &split.id);
+        t.escrow.pay_rent(&co_tenant, &split.id);
+
+        let report = t.escrow.escrow_health_check();
+        assert_eq!(report.total_deposits_recorded, 1_000);
+        assert_eq!(report.total_split_shares_held, 400);
+        assert_eq!(report.contract_token_balance, 1_400);
+        assert_eq!(report.discrepancy, 0);
+
+        // An escrow funded before deposits were totalled.
+        env.as_contract(&t.escrow.address, || {
+            env.storage()
+                .instance()
+                .set(&DataKey::TotalDepositsHeld, &500i128);
+        });
+        assert_eq!(t.escrow.escrow_health_check().discrepancy, 500);
+        t.escrow
+            .backfill_total_deposits_held(&Vec::from_array(&env, [a.id.clone()]));
+        assert_eq!(t.escrow.escrow_health_check().discrepancy, 0);
+        assert_eq!(
+            t.escrow.try_backfill_total_deposits_held(&Vec::new(&env)),
+            Err(Ok(Error::DepositsAlreadyBackfilled.into()))
+        );
+    }
+
+    #[test]
+    fn agreement_update_waits_for_delay_and_released_deposits() {
+        let env = Env::default();
+        let t = setup(&env);
+        let new_contract = Address::generate(&env);
+
+        assert_eq!(
+            t.escrow.try_accept_agreement_update(),
+            Err(Ok(Error::NoPendingAgreementUpdate.into()))
+        );
+        t.escrow.propose_agreement_update(&new_contract);
+        assert_eq!(
+            t.escrow.try_accept_agreement_update(),
+            Err(Ok(Error::AgreementUpdateNotReady.into()))
+        );
+
+        // A deposit arriving during the delay blocks the switch.
+        let a = funded_agreement(&env, &t, 1);
+        env.ledger().set_timestamp(SECONDS_PER_DAY);
+        assert_eq!(
+            t.escrow.try_accept_agreement_update(),
+            Err(Ok(Error::DepositsStillHeld.into()))
+        );
+
+        let mut completed = a.clone();
+        completed.status = AgreementStatus::Completed;
+        t.agreements.set_agreement(&completed);
+        t.escrow.release_deposit_to_tenant(&a.id);
+        t.escrow.accept_agreement_update();
+        assert_eq!(
+            t.escrow.try_accept_agreement_update(),
+            Err(Ok(Error::NoPendingAgreementUpdate.into()))
+        );
+    }
+
+    #[test]
+    fn archive_moves_released_escrow_out_of_persistent_storage() {
+        let env = Env::default();
+        let t = setup(&env);
+        let a = funded_agreement(&env, &t, 1);
+        assert_eq!(
+            t.escrow.try_archive_escrow(&a.id),
+            Err(Ok(Error::DepositNotReleased.into()))
+        );
+
+        let mut completed = a.clone();
+        completed.status = AgreementStatus::Completed;
+        t.agreements.set_agreement(&completed);
+        t.escrow.release_deposit_to_tenant(&a.id);
+        let escrow = t.escrow.get_escrow(&a.id);
+        t.escrow.archive_escrow(&a.id);
+
+        assert_eq!(t.escrow.get_archived_escrow(&a.id), escrow);
+        assert_eq!(t.escrow.get_archived_payments(&a.id).len(), 3);
+        assert!(t.escrow.get_payment_history(&a.id).is_empty());
+        env.as_contract(&t.escrow.address, || {
+            let storage = env.storage().persistent();
+            assert!(!storage.has(&DataKey::Escrow(a.id.clone())));
+            assert!(!storage.has(&DataKey::ArchivedEscrow(a.id.clone())));
+        });
+    }
+
+    #[test]
+    fn archive_outlives_default_ttl_and_clears_split_flags() {
+        let env = Env::default();
+        let t = setup(&env);
+        let tenant = Address::generate(&env);
+        let co_tenant = Address::generate(&env);
+        t.xlm_admin.mint(&tenant, &10_000);
+        t.xlm_admin.mint(&co_tenant, &10_000);
+
+        let a = agreement(&env, 1, &tenant);
+        t.agreements.set_agreement(&a);
+        t.escrow.set_rent_split(
+            &a.landlord,
+            &a.id,
+            &Vec::from_array(&env, [(tenant.clone(), 600), (co_tenant.clone(), 400)]),
+        );
+        t.escrow.deposit_security_and_rent(&tenant, &a.id);
+        // The co-tenant's share for the next month is still held at archive time.
+        t.escrow.pay_rent(&co_tenant, &a.id);
+
+        let mut completed = t.agreements.get_agreement(&a.id);
+        completed.status = AgreementStatus::Completed;
+        t.agreements.set_agreement(&completed);
+        t.escrow.release_deposit_to_tenant(&a.id);
+        let escrow = t.escrow.get_escrow(&a.id);
+        t.escrow.archive_escrow(&a.id);
+
+        assert_eq!(t.xlm.balance(&co_tenant), 10_000);
+        assert_eq!(t.escrow.escrow_health_check().total_split_shares_held, 0);
+        env.as_contract(&t.escrow.address, || {
+            let storage = env.storage().persistent();
+            assert!(!storage.has(&DataKey::RentSplit(a.id.clone())));
+            assert!(!storage.has(&DataKey::SplitPaid(a.id.clone(), co_tenant.clone())));
+        });
+
+        env.ledger().with_mut(|l| l.sequence_number += 1_000);
+        assert_eq!(t.escrow.get_archived_escrow(&a.id), escrow);
+        assert!(!t.escrow.get_archived_payments(&a.id).is_empty());
+    }
+
+    #[test]
+    fn outstanding_rent_stops_at_end_date() {
+        let env = Env::default();
+        let t = setup(&env);
+        let a = funded_agreement(&env, &t, 1);
+
+        env.ledger().set_timestamp(3 * SECONDS_PER_MONTH);
+        assert_eq!(t.escrow.get_outstanding_rent(&a.id), 2_000);
+
+        env.ledger().set_timestamp(20 * SECONDS_PER_MONTH);
+        assert_eq!(t.escrow.get_outstanding_rent(&a.id), 11_000);
+    }
+
+    #[test]
+    fn landlord_outstanding_total_decodes_every_status() {
+        let env = Env::default();
+        let t = setup(&env);
+        let a = funded_agreement(&env, &t, 1);
+
+        let mut requested = agreement(&env, 2, &Address::generate(&env));
+        requested.landlord = a.landlord.clone();
+        requested.status = AgreementStatus::PendingLandlordApproval;
+        t.agreements.set_agreement(&requested);
+        let mut rejected = agreement(&env, 3, &Address::generate(&env));
+        rejected.landlord = a.landlord.clone();
+        rejected.status = AgreementStatus::Rejected;
+        t.agreements.set_agreement(&rejected);
+
+        env.ledger().set_timestamp(3 * SECONDS_PER_MONTH);
+        assert_eq!(t.escrow.get_landlord_outstanding_total(&a.landlord), 2_000);
+    }
+
+    #[test]
+    fn deposit_release_needs_both_parties() {
+        let env = Env::default();
+        let t = setup(&env);
+        let mut a = funded_agreement(&env, &t, 1);
+        assert_eq!(
+            t.escrow.try_request_deposit_release(&a.tenant, &a.id),
+            Err(Ok(Error::AgreementNotCompleted.into()))
+        );
+
+        a.status = AgreementStatus::Completed;
+        t.agreements.set_agreement(&a);
+        assert_eq!(
+            t.escrow.try_confirm_deposit_release(&a.landlord, &a.id),
+            Err(Ok(Error::ReleaseNotRequested.into()))
+        );
+        t.escrow.request_deposit_release(&a.tenant, &a.id);
+        assert_eq!(
+            t.escrow.try_request_deposit_release(&a.landlord, &a.id),
+            Err(Ok(Error::ReleaseAlreadyRequested.into()))
+        );
+        assert_eq!(
+            t.escrow.try_confirm_deposit_release(&a.tenant, &a.id),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+
+        let before = t.xlm.balance(&a.tenant);
+        t.escrow.confirm_deposit_release(&a.landlord, &a.id);
+        assert_eq!(t.xlm.balance(&a.tenant), before + 500);
+        assert!(t.escrow.get_escrow(&a.id).is_deposit_released);
+        assert_eq!(
+            t.escrow
+                .get_deposit_release_request(&a.id)
+                .unwrap()
+                .confirmed_by,
+            Some(a.landlord.clone())
+        );
+    }
+
+    #[test]
+    fn rent_payment_earns_briq_cashback() {
+        let env = Env::default();
+        let t = setup(&env);
+
+        let briq_id = env.register_contract(None, briq_token::BriqToken);
+        let briq = briq_token::BriqTokenClient::new(&env, &briq_id);
+        briq.initialize(&Address::generate(&env));
+        briq.set_escrow_contract(&t.escrow.address);
+
+        let a = funded_agreement(&env, &t, 1);
+        t.escrow.set_reward_token(&briq_id);
+        t.escrow.pay_rent(&a.tenant, &a.id);
+        // 50 bps default cashback on 1_000, vesting rather than minted.
+        assert_eq!(briq.get_payment_streak(&a.tenant), 1);
+        assert_eq!(briq.get_vesting_info(&a.tenant).total, 5);
+    }
+
+    #[test]
+    fn rent_pays_when_briq_rewards_fail() {
+        let env = Env::default();
+        let t = setup(&env);
+
+        let briq_id = env.register_contract(None, briq_token::BriqToken);
+        let briq = briq_token::BriqTokenClient::new(&env, &briq_id);
+        briq.initialize(&Address::generate(&env));
+        briq.set_escrow_contract(&t.escrow.address);
+        briq.set_max_supply(&briq.total_supply());
+
+        let a = funded_agreement(&env, &t, 1);
+        t.escrow.set_reward_token(&briq_id);
+        t.escrow.pay_rent(&a.tenant, &a.id);
+
+        // The first cashback has vested, but the cap leaves no room to mint it.
+        env.ledger().set_timestamp(SECONDS_PER_MONTH);
+        t.escrow.pay_rent(&a.tenant, &a.id);
+        assert_eq!(t.agreements.get_agreement(&a.id).months_paid, 3);
+        assert_eq!(briq.balance_of(&a.tenant), 0);
+
+        briq.pause();
+        env.ledger().set_timestamp(2 * SECONDS_PER_MONTH);
+        t.escrow.pay_rent(&a.tenant, &a.id);
+        assert_eq!(t.agreements.get_agreement(&a.id).months_paid, 4);
+    }
 }
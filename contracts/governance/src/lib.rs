@@ -0,0 +1,513 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error,
+    xdr::FromXdr, Address, Bytes, BytesN, Env, String, Symbol, Val, Vec,
+};
+
+// -----------------------------
+// Cross-contract: BriqToken
+// -----------------------------
+
+#[contractclient(name = "BriqTokenClient")]
+pub trait BriqTokenContract {
+    fn total_supply() -> i128;
+    fn balance_of(owner: Address) -> i128;
+    fn take_snapshot(caller: Address) -> u64;
+    fn get_snapshot_governance_weight(address: Address, snapshot_seq: u64) -> i128;
+}
+
+// -----------------------------
+// Governance contract
+// -----------------------------
+
+const VOTING_PERIOD_SECONDS: u64 = 7 * 24 * 60 * 60;
+const MAX_DESCRIPTION_LEN: u32 = 1000;
+const QUORUM_BPS: i128 = 500;
+const BPS_DENOMINATOR: i128 = 10_000;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    ContractPaused = 1,
+    Unauthorized = 2,
+    AlreadyInitialized = 3,
+
+    ProposalNotFound = 700,
+    InvalidProposalState = 701,
+    VotingClosed = 702,
+    VotingStillOpen = 703,
+    AlreadyVoted = 704,
+    NoVotingPower = 705,
+    DescriptionTooLong = 706,
+    InvalidArguments = 707,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    Pending,
+    Passed,
+    Failed,
+    Executed,
+    Vetoed,
+}
+
+/// `encoded_args` is the XDR encoding of the `Vec<Val>` passed to
+/// `function_name` on `target_contract` when the proposal is executed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceProposal {
+    pub id: BytesN<32>,
+    pub proposer: Address,
+    pub target_contract: Address,
+    pub function_name: Symbol,
+    pub encoded_args: Bytes,
+    pub description: String,
+    pub votes_for: i128,
+    pub votes_against: i128,
+    pub status: ProposalStatus,
+    pub created_at: u64,
+    pub voting_ends_at: u64,
+    // BriqToken snapshot that vote weights are read from.
+    pub snapshot_seq: u64,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Paused,
+    TokenContract,
+    Proposal(BytesN<32>),
+    AllProposals,
+    Voted(BytesN<32>, Address),
+}
+
+#[contract]
+pub struct Governance;
+
+#[contractimpl]
+impl Governance {
+    pub fn initialize(env: Env, admin: Address, token_contract: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.storage()
+            .instance()
+            .set(&DataKey::TokenContract, &token_contract);
+
+        env.events().publish(
+            (Symbol::new(&env, "Initialized"),),
+            (admin, token_contract, env.ledger().timestamp()),
+        );
+    }
+
+    pub fn pause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.events()
+            .publish((Symbol::new(&env, "Paused"),), env.ledger().timestamp());
+    }
+
+    pub fn unpause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.events()
+            .publish((Symbol::new(&env, "Unpaused"),), env.ledger().timestamp());
+    }
+
+    // Any BRIQ holder can propose a call. The target contract must have this
+    // contract set as its admin for privileged setters to succeed, and
+    // BriqToken must have it set as its governance contract.
+    pub fn create_proposal(
+        env: Env,
+        proposer: Address,
+        target_contract: Address,
+        function_name: Symbol,
+        encoded_args: Bytes,
+        description: String,
+    ) -> BytesN<32> {
+        Self::check_not_paused(&env);
+
+        proposer.require_auth();
+
+        if description.len() > MAX_DESCRIPTION_LEN {
+            panic_with_error!(&env, Error::DescriptionTooLong);
+        }
+        if Self::token(&env).balance_of(&proposer) <= 0 {
+            panic_with_error!(&env, Error::NoVotingPower);
+        }
+        if Vec::<Val>::from_xdr(&env, &encoded_args).is_err() {
+            panic_with_error!(&env, Error::InvalidArguments);
+        }
+
+        let snapshot_seq = Self::token(&env).take_snapshot(&env.current_contract_address());
+        let now = env.ledger().timestamp();
+        let proposal_id = env.prng().gen::<BytesN<32>>();
+        let proposal = GovernanceProposal {
+            id: proposal_id.clone(),
+            proposer: proposer.clone(),
+            target_contract: target_contract.clone(),
+            function_name: function_name.clone(),
+            encoded_args,
+            description,
+            votes_for: 0,
+            votes_against: 0,
+            status: ProposalStatus::Pending,
+            created_at: now,
+            voting_ends_at: now + VOTING_PERIOD_SECONDS,
+            snapshot_seq,
+        };
+        Self::save_proposal(&env, &proposal);
+
+        let mut all = Self::get_all_proposals(env.clone());
+        all.push_back(proposal_id.clone());
+        env.storage().persistent().set(&DataKey::AllProposals, &all);
+
+        env.events().publish(
+            (Symbol::new(&env, "ProposalCreated"),),
+            (
+                proposal_id.clone(),
+                proposer,
+                target_contract,
+                function_name,
+            ),
+        );
+
+        proposal_id
+    }
+
+    // Votes are weighted by the voter's BRIQ governance weight at the
+    // proposal's snapshot, so tokens moved after creation can't vote twice.
+    pub fn cast_vote(env: Env, voter: Address, proposal_id: BytesN<32>, support: bool) {
+        Self::check_not_paused(&env);
+
+        voter.require_auth();
+
+        let mut proposal = Self::get_proposal(env.clone(), proposal_id.clone());
+        if proposal.status != ProposalStatus::Pending {
+            panic_with_error!(&env, Error::InvalidProposalState);
+        }
+        if env.ledger().timestamp() >= proposal.voting_ends_at {
+            panic_with_error!(&env, Error::VotingClosed);
+        }
+
+        let voted_key = DataKey::Voted(proposal_id.clone(), voter.clone());
+        if env.storage().persistent().has(&voted_key) {
+            panic_with_error!(&env, Error::AlreadyVoted);
+        }
+
+        let weight =
+            Self::token(&env).get_snapshot_governance_weight(&voter, &proposal.snapshot_seq);
+        if weight <= 0 {
+            panic_with_error!(&env, Error::NoVotingPower);
+        }
+
+        if support {
+            proposal.votes_for += weight;
+        } else {
+            proposal.votes_against += weight;
+        }
+        Self::save_proposal(&env, &proposal);
+        env.storage().persistent().set(&voted_key, &support);
+
+        env.events().publish(
+            (Symbol::new(&env, "VoteCast"),),
+            (proposal_id, voter, support, weight),
+        );
+    }
+
+    // Once voting ends, tallies the proposal as Passed or Failed.
+    // Passing needs 5% of total supply to have voted and >50% in favour.
+    pub fn finalize_proposal(env: Env, proposal_id: BytesN<32>) -> ProposalStatus {
+        Self::check_not_paused(&env);
+
+        let mut proposal = Self::get_proposal(env.clone(), proposal_id.clone());
+        if proposal.status != ProposalStatus::Pending {
+            panic_with_error!(&env, Error::InvalidProposalState);
+        }
+        if env.ledger().timestamp() < proposal.voting_ends_at {
+            panic_with_error!(&env, Error::VotingStillOpen);
+        }
+
+        let total_votes = proposal.votes_for + proposal.votes_against;
+        let quorum = Self::token(&env).total_supply() * QUORUM_BPS / BPS_DENOMINATOR;
+        proposal.status = if total_votes > 0
+            && total_votes >= quorum
+            && proposal.votes_for > proposal.votes_against
+        {
+            ProposalStatus::Passed
+        } else {
+            ProposalStatus::Failed
+        };
+        Self::save_proposal(&env, &proposal);
+
+        env.events().publish(
+            (Symbol::new(&env, "ProposalFinalized"),),
+            (proposal_id, proposal.status.clone()),
+        );
+
+        proposal.status
+    }
+
+    // Anyone can execute a passed proposal.
+    pub fn execute_proposal(env: Env, proposal_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        let mut proposal = Self::get_proposal(env.clone(), proposal_id.clone());
+        if proposal.status != ProposalStatus::Passed {
+            panic_with_error!(&env, Error::InvalidProposalState);
+        }
+
+        proposal.status = ProposalStatus::Executed;
+        Self::save_proposal(&env, &proposal);
+
+        let args = Vec::<Val>::from_xdr(&env, &proposal.encoded_args)
+            .unwrap_or_else(|_| panic_with_error!(&env, Error::InvalidArguments));
+        env.invoke_contract::<Val>(&proposal.target_contract, &proposal.function_name, args);
+
+        env.events().publish(
+            (Symbol::new(&env, "ProposalExecuted"),),
+            (
+                proposal_id,
+                proposal.target_contract,
+                proposal.function_name,
+            ),
+        );
+    }
+
+    pub fn veto_proposal(env: Env, proposal_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let mut proposal = Self::get_proposal(env.clone(), proposal_id.clone());
+        match proposal.status {
+            ProposalStatus::Pending | ProposalStatus::Passed => {}
+            _ => panic_with_error!(&env, Error::InvalidProposalState),
+        }
+
+        proposal.status = ProposalStatus::Vetoed;
+        Self::save_proposal(&env, &proposal);
+
+        env.events()
+            .publish((Symbol::new(&env, "ProposalVetoed"),), proposal_id);
+    }
+
+    pub fn get_proposal(env: Env, proposal_id: BytesN<32>) -> GovernanceProposal {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Proposal(proposal_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ProposalNotFound))
+    }
+
+    pub fn get_all_proposals(env: Env) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AllProposals)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn has_voted(env: Env, proposal_id: BytesN<32>, voter: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Voted(proposal_id, voter))
+    }
+
+    // -----------------------------
+    // Internal helpers
+    // -----------------------------
+
+    fn token(env: &Env) -> BriqTokenClient<'_> {
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::TokenContract)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        BriqTokenClient::new(env, &token)
+    }
+
+    fn save_proposal(env: &Env, proposal: &GovernanceProposal) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(proposal.id.clone()), proposal);
+    }
+
+    fn check_not_paused(env: &Env) {
+        let paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        if paused {
+            panic_with_error!(env, Error::ContractPaused);
+        }
+    }
+
+    fn require_admin(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::xdr::ToXdr;
+
+    #[contract]
+    struct MockToken;
+
+    #[contractimpl]
+    impl MockToken {
+        pub fn total_supply(_env: Env) -> i128 {
+            1_000
+        }
+
+        pub fn balance_of(env: Env, owner: Address) -> i128 {
+            env.storage().persistent().get(&owner).unwrap_or(0)
+        }
+
+        pub fn set_balance(env: Env, owner: Address, amount: i128) {
+            env.storage().persistent().set(&owner, &amount);
+        }
+
+        pub fn take_snapshot(env: Env, _caller: Address) -> u64 {
+            env.ledger().sequence() as u64
+        }
+
+        pub fn get_snapshot_governance_weight(env: Env, address: Address, _seq: u64) -> i128 {
+            Self::balance_of(env, address)
+        }
+    }
+
+    #[test]
+    fn initialize_smoke() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, Governance);
+        let client = GovernanceClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let token = Address::generate(&env);
+        client.initialize(&admin, &token);
+
+        client.pause();
+        client.unpause();
+    }
+
+    #[test]
+    fn proposal_passes_with_quorum() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let token_id = env.register_contract(None, MockToken);
+        let token = MockTokenClient::new(&env, &token_id);
+        let contract_id = env.register_contract(None, Governance);
+        let client = GovernanceClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &token_id);
+
+        let proposer = Address::generate(&env);
+        let voter = Address::generate(&env);
+        token.set_balance(&proposer, &30);
+        token.set_balance(&voter, &40);
+
+        let args: Vec<Val> = Vec::new(&env);
+        let proposal_id = client.create_proposal(
+            &proposer,
+            &token_id,
+            &Symbol::new(&env, "total_supply"),
+            &args.to_xdr(&env),
+            &String::from_str(&env, "No-op"),
+        );
+
+        client.cast_vote(&proposer, &proposal_id, &true);
+        client.cast_vote(&voter, &proposal_id, &true);
+        assert_eq!(
+            client.try_cast_vote(&voter, &proposal_id, &false),
+            Err(Ok(Error::AlreadyVoted.into()))
+        );
+
+        assert_eq!(
+            client.try_finalize_proposal(&proposal_id),
+            Err(Ok(Error::VotingStillOpen.into()))
+        );
+        env.ledger()
+            .with_mut(|li| li.timestamp += VOTING_PERIOD_SECONDS);
+        assert_eq!(
+            client.finalize_proposal(&proposal_id),
+            ProposalStatus::Passed
+        );
+
+        client.execute_proposal(&proposal_id);
+        assert_eq!(
+            client.get_proposal(&proposal_id).status,
+            ProposalStatus::Executed
+        );
+    }
+
+    #[test]
+    fn tokens_moved_after_voting_cannot_vote_again() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let token_id = env.register_contract(None, briq_token::BriqToken);
+        let token = briq_token::BriqTokenClient::new(&env, &token_id);
+        let contract_id = env.register_contract(None, Governance);
+        let client = GovernanceClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        token.initialize(&admin);
+        token.set_governance_contract(&contract_id);
+        client.initialize(&admin, &token_id);
+
+        let proposer = Address::generate(&env);
+        let voter = Address::generate(&env);
+        let friend = Address::generate(&env);
+        token.mint(&proposer, &10);
+        token.mint(&voter, &40);
+
+        let args = Vec::<Val>::new(&env).to_xdr(&env);
+        let proposal_id = client.create_proposal(
+            &proposer,
+            &token_id,
+            &Symbol::new(&env, "total_supply"),
+            &args,
+            &String::from_str(&env, "No-op"),
+        );
+
+        client.cast_vote(&voter, &proposal_id, &true);
+        token.transfer(&voter, &friend, &40);
+        assert_eq!(
+            client.try_cast_vote(&friend, &proposal_id, &false),
+            Err(Ok(Error::NoVotingPower.into()))
+        );
+        assert_eq!(client.get_proposal(&proposal_id).votes_for, 40);
+
+        // A later proposal snapshots the new holder.
+        env.ledger().with_mut(|li| li.sequence_number += 1);
+        let next_id = client.create_proposal(
+            &proposer,
+            &token_id,
+            &Symbol::new(&env, "total_supply"),
+            &args,
+            &String::from_str(&env, "No-op"),
+        );
+        client.cast_vote(&friend, &next_id, &false);
+        assert_eq!(client.get_proposal(&next_id).votes_against, 40);
+    }
+}
@@ -0,0 +1,669 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error, Address,
+    BytesN, Env, String, Symbol, Vec,
+};
+
+use soroban_sdk::token;
+
+// -----------------------------
+// Cross-contract: RentalAgreement
+// -----------------------------
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AgreementStatus {
+    PendingLandlordApproval,
+    Rejected,
+    Draft,
+    PendingTenantSign,
+    PendingLandlordSign,
+    PendingCoSignerSign,
+    PendingPayment,
+    Active,
+    Completed,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentalAgreement {
+    pub id: BytesN<32>,
+    pub property_id: BytesN<32>,
+    pub landlord: Address,
+    pub tenant: Address,
+    pub monthly_rent: i128,
+    pub security_deposit: i128,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub status: AgreementStatus,
+    pub landlord_signed: bool,
+    pub landlord_signed_at: u64,
+    pub tenant_signed: bool,
+    pub tenant_signed_at: u64,
+    pub deposit_paid: bool,
+    pub deposit_paid_at: u64,
+    pub total_rent_paid: i128,
+    pub months_paid: u32,
+    pub created_at: u64,
+    pub completed_at: u64,
+    pub notice_period_days: u32,
+    pub notice_served_at: u64,
+    pub notice_served_by: Option<Address>,
+    pub document_hash: BytesN<32>,
+    pub co_signer: Option<Address>,
+    pub subletting_allowed: bool,
+    pub custom_clauses: Vec<String>,
+}
+
+#[contractclient(name = "RentalAgreementClient")]
+pub trait RentalAgreementContract {
+    fn get_agreement(agreement_id: BytesN<32>) -> RentalAgreement;
+}
+
+// -----------------------------
+// Insurance contract
+// -----------------------------
+
+// Coverage may be at most this multiple of the premium, so one policy can't
+// promise far more than it brings into the pool.
+const MAX_COVERAGE_TO_PREMIUM: i128 = 20;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    ContractPaused = 1,
+    Unauthorized = 2,
+    AlreadyInitialized = 3,
+
+    PolicyNotFound = 1400,
+    ClaimNotFound = 1401,
+    InvalidAmount = 1402,
+    PolicyNotActive = 1403,
+    PremiumAlreadyPaid = 1404,
+    CoverageExceeded = 1405,
+    InvalidClaimState = 1406,
+    InsufficientPool = 1407,
+    InvalidAgreementState = 1408,
+    CoverageTooHigh = 1409,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClaimStatus {
+    Submitted,
+    Approved,
+    Rejected,
+    Paid,
+}
+
+/// Cover runs over the agreement's dates and starts once the premium is paid.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsurancePolicy {
+    pub id: BytesN<32>,
+    pub agreement_id: BytesN<32>,
+    pub landlord: Address,
+    pub premium: i128,
+    pub coverage_amount: i128,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub is_active: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceClaim {
+    pub id: BytesN<32>,
+    pub policy_id: BytesN<32>,
+    pub claimant: Address,
+    pub amount: i128,
+    pub evidence_hash: BytesN<32>,
+    pub status: ClaimStatus,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Paused,
+    AgreementContract,
+    XlmToken,
+    Policy(BytesN<32>),
+    PoliciesByAgreement(BytesN<32>),
+    Claim(BytesN<32>),
+    ClaimsByPolicy(BytesN<32>),
+    // Submitted + approved + paid claim amounts against a policy.
+    ClaimedAmount(BytesN<32>),
+    PoolBalance,
+}
+
+#[contract]
+pub struct Insurance;
+
+#[contractimpl]
+impl Insurance {
+    pub fn initialize(env: Env, admin: Address, agreement_contract: Address, xlm_token: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.storage()
+            .instance()
+            .set(&DataKey::AgreementContract, &agreement_contract);
+        env.storage().instance().set(&DataKey::XlmToken, &xlm_token);
+
+        env.events().publish(
+            (Symbol::new(&env, "Initialized"),),
+            (
+                admin,
+                agreement_contract,
+                xlm_token,
+                env.ledger().timestamp(),
+            ),
+        );
+    }
+
+    pub fn pause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.events()
+            .publish((Symbol::new(&env, "Paused"),), env.ledger().timestamp());
+    }
+
+    pub fn unpause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.events()
+            .publish((Symbol::new(&env, "Unpaused"),), env.ledger().timestamp());
+    }
+
+    // Coverage is capped at MAX_COVERAGE_TO_PREMIUM times the premium.
+    pub fn create_policy(
+        env: Env,
+        landlord: Address,
+        agreement_id: BytesN<32>,
+        premium: i128,
+        coverage_amount: i128,
+    ) -> BytesN<32> {
+        Self::check_not_paused(&env);
+
+        landlord.require_auth();
+
+        if premium <= 0 || coverage_amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if coverage_amount > premium.saturating_mul(MAX_COVERAGE_TO_PREMIUM) {
+            panic_with_error!(&env, Error::CoverageTooHigh);
+        }
+
+        let agreement = Self::agreement_client(&env).get_agreement(&agreement_id);
+        if agreement.landlord != landlord {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        match agreement.status {
+            AgreementStatus::Completed | AgreementStatus::Cancelled | AgreementStatus::Rejected => {
+                panic_with_error!(&env, Error::InvalidAgreementState)
+            }
+            _ => {}
+        }
+
+        let policy_id = env.prng().gen::<BytesN<32>>();
+        let policy = InsurancePolicy {
+            id: policy_id.clone(),
+            agreement_id: agreement_id.clone(),
+            landlord: landlord.clone(),
+            premium,
+            coverage_amount,
+            start_date: agreement.start_date,
+            end_date: agreement.end_date,
+            is_active: false,
+        };
+        Self::save_policy(&env, &policy);
+
+        let mut by_agreement = Self::get_policies_by_agreement(env.clone(), agreement_id.clone());
+        by_agreement.push_back(policy_id.clone());
+        env.storage().persistent().set(
+            &DataKey::PoliciesByAgreement(agreement_id.clone()),
+            &by_agreement,
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "PolicyCreated"),),
+            (
+                policy_id.clone(),
+                agreement_id,
+                landlord,
+                premium,
+                coverage_amount,
+            ),
+        );
+
+        policy_id
+    }
+
+    // Premiums go into the pool held by this contract and activate cover.
+    pub fn pay_premium(env: Env, landlord: Address, policy_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        landlord.require_auth();
+
+        let mut policy = Self::get_policy(env.clone(), policy_id.clone());
+        if policy.landlord != landlord {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        if policy.is_active {
+            panic_with_error!(&env, Error::PremiumAlreadyPaid);
+        }
+
+        Self::xlm_client(&env).transfer(
+            &landlord,
+            &env.current_contract_address(),
+            &policy.premium,
+        );
+        Self::set_pool_balance(&env, Self::get_pool_balance(env.clone()) + policy.premium);
+
+        policy.is_active = true;
+        Self::save_policy(&env, &policy);
+
+        env.events().publish(
+            (Symbol::new(&env, "PremiumPaid"),),
+            (policy_id, landlord, policy.premium),
+        );
+    }
+
+    pub fn file_claim(
+        env: Env,
+        claimant: Address,
+        policy_id: BytesN<32>,
+        amount: i128,
+        evidence_hash: BytesN<32>,
+    ) -> BytesN<32> {
+        Self::check_not_paused(&env);
+
+        claimant.require_auth();
+
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let policy = Self::get_policy(env.clone(), policy_id.clone());
+        if policy.landlord != claimant {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        let now = env.ledger().timestamp();
+        if !policy.is_active || now < policy.start_date || now > policy.end_date {
+            panic_with_error!(&env, Error::PolicyNotActive);
+        }
+
+        let claimed = Self::claimed_amount(&env, &policy_id);
+        if claimed + amount > policy.coverage_amount {
+            panic_with_error!(&env, Error::CoverageExceeded);
+        }
+        Self::set_claimed_amount(&env, &policy_id, claimed + amount);
+
+        let claim_id = env.prng().gen::<BytesN<32>>();
+        let claim = InsuranceClaim {
+            id: claim_id.clone(),
+            policy_id: policy_id.clone(),
+            claimant: claimant.clone(),
+            amount,
+            evidence_hash,
+            status: ClaimStatus::Submitted,
+        };
+        Self::save_claim(&env, &claim);
+
+        let mut by_policy = Self::get_claims_by_policy(env.clone(), policy_id.clone());
+        by_policy.push_back(claim_id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::ClaimsByPolicy(policy_id.clone()), &by_policy);
+
+        env.events().publish(
+            (Symbol::new(&env, "ClaimFiled"),),
+            (claim_id.clone(), policy_id, claimant, amount),
+        );
+
+        claim_id
+    }
+
+    pub fn approve_claim(env: Env, claim_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let mut claim = Self::claim_in(&env, claim_id.clone(), ClaimStatus::Submitted);
+        claim.status = ClaimStatus::Approved;
+        Self::save_claim(&env, &claim);
+
+        env.events().publish(
+            (Symbol::new(&env, "ClaimApproved"),),
+            (claim_id, claim.amount),
+        );
+    }
+
+    // Rejected claims free their amount back up against the policy's coverage.
+    pub fn reject_claim(env: Env, claim_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let mut claim = Self::claim_in(&env, claim_id.clone(), ClaimStatus::Submitted);
+        claim.status = ClaimStatus::Rejected;
+        Self::save_claim(&env, &claim);
+
+        let claimed = Self::claimed_amount(&env, &claim.policy_id);
+        Self::set_claimed_amount(&env, &claim.policy_id, claimed - claim.amount);
+
+        env.events()
+            .publish((Symbol::new(&env, "ClaimRejected"),), claim_id);
+    }
+
+    // Anyone can trigger payout of an approved claim from the pool.
+    pub fn pay_claim(env: Env, claim_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        let mut claim = Self::claim_in(&env, claim_id.clone(), ClaimStatus::Approved);
+        let pool = Self::get_pool_balance(env.clone());
+        if pool < claim.amount {
+            panic_with_error!(&env, Error::InsufficientPool);
+        }
+
+        Self::xlm_client(&env).transfer(
+            &env.current_contract_address(),
+            &claim.claimant,
+            &claim.amount,
+        );
+        Self::set_pool_balance(&env, pool - claim.amount);
+
+        claim.status = ClaimStatus::Paid;
+        Self::save_claim(&env, &claim);
+
+        env.events().publish(
+            (Symbol::new(&env, "ClaimPaid"),),
+            (claim_id, claim.claimant, claim.amount),
+        );
+    }
+
+    pub fn get_policy(env: Env, policy_id: BytesN<32>) -> InsurancePolicy {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Policy(policy_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::PolicyNotFound))
+    }
+
+    pub fn get_claim(env: Env, claim_id: BytesN<32>) -> InsuranceClaim {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Claim(claim_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ClaimNotFound))
+    }
+
+    pub fn get_policies_by_agreement(env: Env, agreement_id: BytesN<32>) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PoliciesByAgreement(agreement_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_claims_by_policy(env: Env, policy_id: BytesN<32>) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ClaimsByPolicy(policy_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_pool_balance(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::PoolBalance)
+            .unwrap_or(0)
+    }
+
+    // -----------------------------
+    // Internal helpers
+    // -----------------------------
+
+    fn claim_in(env: &Env, claim_id: BytesN<32>, status: ClaimStatus) -> InsuranceClaim {
+        let claim = Self::get_claim(env.clone(), claim_id);
+        if claim.status != status {
+            panic_with_error!(env, Error::InvalidClaimState);
+        }
+        claim
+    }
+
+    fn claimed_amount(env: &Env, policy_id: &BytesN<32>) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ClaimedAmount(policy_id.clone()))
+            .unwrap_or(0)
+    }
+
+    fn set_claimed_amount(env: &Env, policy_id: &BytesN<32>, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::ClaimedAmount(policy_id.clone()), &amount);
+    }
+
+    fn set_pool_balance(env: &Env, amount: i128) {
+        env.storage().instance().set(&DataKey::PoolBalance, &amount);
+    }
+
+    fn save_policy(env: &Env, policy: &InsurancePolicy) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Policy(policy.id.clone()), policy);
+    }
+
+    fn save_claim(env: &Env, claim: &InsuranceClaim) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Claim(claim.id.clone()), claim);
+    }
+
+    fn agreement_client(env: &Env) -> RentalAgreementClient<'_> {
+        let addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::AgreementContract)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        RentalAgreementClient::new(env, &addr)
+    }
+
+    fn xlm_client(env: &Env) -> token::Client<'_> {
+        let token_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::XlmToken)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        token::Client::new(env, &token_addr)
+    }
+
+    fn check_not_paused(env: &Env) {
+        let paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        if paused {
+            panic_with_error!(env, Error::ContractPaused);
+        }
+    }
+
+    fn require_admin(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[contract]
+    struct MockAgreement;
+
+    #[contractimpl]
+    impl MockAgreement {
+        pub fn set_agreement(env: Env, agreement: RentalAgreement) {
+            env.storage().instance().set(&agreement.id, &agreement);
+        }
+
+        pub fn get_agreement(env: Env, agreement_id: BytesN<32>) -> RentalAgreement {
+            env.storage().instance().get(&agreement_id).unwrap()
+        }
+    }
+
+    // The landlord holds 10_000 XLM and an active agreement.
+    fn setup(env: &Env) -> (InsuranceClient<'_>, token::Client<'_>, RentalAgreement) {
+        env.mock_all_auths();
+
+        let agreement_contract = env.register_contract(None, MockAgreement);
+        let agreement = RentalAgreement {
+            id: BytesN::from_array(env, &[1; 32]),
+            property_id: BytesN::from_array(env, &[2; 32]),
+            landlord: Address::generate(env),
+            tenant: Address::generate(env),
+            monthly_rent: 1_000,
+            security_deposit: 500,
+            start_date: 0,
+            end_date: 365 * 24 * 60 * 60,
+            status: AgreementStatus::Active,
+            landlord_signed: true,
+            landlord_signed_at: 0,
+            tenant_signed: true,
+            tenant_signed_at: 0,
+            deposit_paid: true,
+            deposit_paid_at: 0,
+            total_rent_paid: 1_000,
+            months_paid: 1,
+            created_at: 0,
+            completed_at: 0,
+            notice_period_days: 30,
+            notice_served_at: 0,
+            notice_served_by: None,
+            document_hash: BytesN::from_array(env, &[0; 32]),
+            co_signer: None,
+            subletting_allowed: false,
+            custom_clauses: Vec::new(env),
+        };
+        MockAgreementClient::new(env, &agreement_contract).set_agreement(&agreement);
+
+        let xlm = env.register_stellar_asset_contract_v2(Address::generate(env));
+        token::StellarAssetClient::new(env, &xlm.address()).mint(&agreement.landlord, &10_000);
+
+        let contract_id = env.register_contract(None, Insurance);
+        let client = InsuranceClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &agreement_contract, &xlm.address());
+
+        (client, token::Client::new(env, &xlm.address()), agreement)
+    }
+
+    #[test]
+    fn initialize_smoke() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, Insurance);
+        let client = InsuranceClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let agreement_contract = Address::generate(&env);
+        let xlm_token = Address::generate(&env);
+        client.initialize(&admin, &agreement_contract, &xlm_token);
+
+        assert_eq!(client.get_pool_balance(), 0);
+
+        client.pause();
+        client.unpause();
+    }
+
+    #[test]
+    fn coverage_is_bounded_by_the_premium() {
+        let env = Env::default();
+        let (client, _, agreement) = setup(&env);
+
+        assert_eq!(
+            client.try_create_policy(&agreement.landlord, &agreement.id, &100, &2_001),
+            Err(Ok(Error::CoverageTooHigh.into()))
+        );
+        let policy_id = client.create_policy(&agreement.landlord, &agreement.id, &100, &2_000);
+        assert_eq!(client.get_policy(&policy_id).coverage_amount, 2_000);
+    }
+
+    #[test]
+    fn premiums_fund_approved_claims() {
+        let env = Env::default();
+        let (client, xlm, agreement) = setup(&env);
+        let landlord = agreement.landlord.clone();
+        let evidence = BytesN::from_array(&env, &[7; 32]);
+
+        let policy_id = client.create_policy(&landlord, &agreement.id, &100, &2_000);
+        assert_eq!(
+            client.try_file_claim(&landlord, &policy_id, &50, &evidence),
+            Err(Ok(Error::PolicyNotActive.into()))
+        );
+        client.pay_premium(&landlord, &policy_id);
+        assert!(client.get_policy(&policy_id).is_active);
+        assert_eq!(client.get_pool_balance(), 100);
+        assert_eq!(
+            client.try_pay_premium(&landlord, &policy_id),
+            Err(Ok(Error::PremiumAlreadyPaid.into()))
+        );
+
+        let claim_id = client.file_claim(&landlord, &policy_id, &150, &evidence);
+        assert_eq!(
+            client.try_pay_claim(&claim_id),
+            Err(Ok(Error::InvalidClaimState.into()))
+        );
+        client.approve_claim(&claim_id);
+        assert_eq!(
+            client.try_pay_claim(&claim_id),
+            Err(Ok(Error::InsufficientPool.into()))
+        );
+
+        // A second premium tops the pool up enough to pay out.
+        let second = client.create_policy(&landlord, &agreement.id, &100, &1_000);
+        client.pay_premium(&landlord, &second);
+        client.pay_claim(&claim_id);
+        assert_eq!(client.get_claim(&claim_id).status, ClaimStatus::Paid);
+        assert_eq!(client.get_pool_balance(), 50);
+        assert_eq!(xlm.balance(&landlord), 10_000 - 200 + 150);
+    }
+
+    #[test]
+    fn rejected_claims_free_up_coverage() {
+        let env = Env::default();
+        let (client, _, agreement) = setup(&env);
+        let landlord = agreement.landlord.clone();
+        let evidence = BytesN::from_array(&env, &[7; 32]);
+
+        let policy_id = client.create_policy(&landlord, &agreement.id, &100, &2_000);
+        client.pay_premium(&landlord, &policy_id);
+        let claim_id = client.file_claim(&landlord, &policy_id, &1_500, &evidence);
+        assert_eq!(
+            client.try_file_claim(&landlord, &policy_id, &600, &evidence),
+            Err(Ok(Error::CoverageExceeded.into()))
+        );
+
+        client.reject_claim(&claim_id);
+        assert_eq!(client.get_claim(&claim_id).status, ClaimStatus::Rejected);
+        assert_eq!(
+            client.try_approve_claim(&claim_id),
+            Err(Ok(Error::InvalidClaimState.into()))
+        );
+        client.file_claim(&landlord, &policy_id, &2_000, &evidence);
+        assert_eq!(client.get_claims_by_policy(&policy_id).len(), 2);
+    }
+}
@@ -0,0 +1,583 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error, Address,
+    BytesN, Env, String, Symbol, Vec,
+};
+
+// -----------------------------
+// Cross-contract: RentalAgreement
+// -----------------------------
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AgreementStatus {
+    PendingLandlordApproval,
+    Rejected,
+    Draft,
+    PendingTenantSign,
+    PendingLandlordSign,
+    PendingCoSignerSign,
+    PendingPayment,
+    Active,
+    Completed,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentalAgreement {
+    pub id: BytesN<32>,
+    pub property_id: BytesN<32>,
+    pub landlord: Address,
+    pub tenant: Address,
+    pub monthly_rent: i128,
+    pub security_deposit: i128,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub status: AgreementStatus,
+    pub landlord_signed: bool,
+    pub landlord_signed_at: u64,
+    pub tenant_signed: bool,
+    pub tenant_signed_at: u64,
+    pub deposit_paid: bool,
+    pub deposit_paid_at: u64,
+    pub total_rent_paid: i128,
+    pub months_paid: u32,
+    pub created_at: u64,
+    pub completed_at: u64,
+    pub notice_period_days: u32,
+    pub notice_served_at: u64,
+    pub notice_served_by: Option<Address>,
+    pub document_hash: BytesN<32>,
+    pub co_signer: Option<Address>,
+    pub subletting_allowed: bool,
+    pub custom_clauses: Vec<String>,
+}
+
+#[contractclient(name = "RentalAgreementClient")]
+pub trait RentalAgreementContract {
+    fn get_agreement(agreement_id: BytesN<32>) -> RentalAgreement;
+}
+
+// -----------------------------
+// MaintenanceRequest contract
+// -----------------------------
+
+const MAX_DESCRIPTION_LEN: u32 = 1000;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    ContractPaused = 1,
+    Unauthorized = 2,
+    AlreadyInitialized = 3,
+
+    RequestNotFound = 800,
+    AgreementNotActive = 801,
+    InvalidStatusTransition = 802,
+    DescriptionTooLong = 803,
+    InvalidCost = 804,
+    AlreadyApproved = 805,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Urgency {
+    Low,
+    Medium,
+    High,
+    Emergency,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MaintenanceStatus {
+    Submitted,
+    Acknowledged,
+    InProgress,
+    Completed,
+    Rejected,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceRequest {
+    pub id: BytesN<32>,
+    pub agreement_id: BytesN<32>,
+    pub property_id: BytesN<32>,
+    pub tenant: Address,
+    pub landlord: Address,
+    pub description: String,
+    pub urgency: Urgency,
+    pub status: MaintenanceStatus,
+    pub submitted_at: u64,
+    pub completed_at: u64,
+    pub cost: i128,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Paused,
+    AgreementContract,
+    Request(BytesN<32>),
+    RequestsByProperty(BytesN<32>),
+    RequestsByAgreement(BytesN<32>),
+    CompletionApproved(BytesN<32>),
+}
+
+#[contract]
+pub struct MaintenanceRequestContract;
+
+#[contractimpl]
+impl MaintenanceRequestContract {
+    pub fn initialize(env: Env, admin: Address, agreement_contract: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.storage()
+            .instance()
+            .set(&DataKey::AgreementContract, &agreement_contract);
+
+        env.events().publish(
+            (Symbol::new(&env, "Initialized"),),
+            (admin, agreement_contract, env.ledger().timestamp()),
+        );
+    }
+
+    pub fn pause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.events()
+            .publish((Symbol::new(&env, "Paused"),), env.ledger().timestamp());
+    }
+
+    pub fn unpause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.events()
+            .publish((Symbol::new(&env, "Unpaused"),), env.ledger().timestamp());
+    }
+
+    // Only the tenant of an active agreement can raise a request.
+    pub fn submit_request(
+        env: Env,
+        tenant: Address,
+        agreement_id: BytesN<32>,
+        description: String,
+        urgency: Urgency,
+    ) -> BytesN<32> {
+        Self::check_not_paused(&env);
+
+        tenant.require_auth();
+
+        if description.len() > MAX_DESCRIPTION_LEN {
+            panic_with_error!(&env, Error::DescriptionTooLong);
+        }
+
+        let agreement = Self::fetch_agreement(&env, agreement_id.clone());
+        if agreement.tenant != tenant {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        if agreement.status != AgreementStatus::Active {
+            panic_with_error!(&env, Error::AgreementNotActive);
+        }
+
+        let request_id = env.prng().gen::<BytesN<32>>();
+        let request = MaintenanceRequest {
+            id: request_id.clone(),
+            agreement_id: agreement_id.clone(),
+            property_id: agreement.property_id.clone(),
+            tenant: tenant.clone(),
+            landlord: agreement.landlord,
+            description,
+            urgency,
+            status: MaintenanceStatus::Submitted,
+            submitted_at: env.ledger().timestamp(),
+            completed_at: 0,
+            cost: 0,
+        };
+        Self::save_request(&env, &request);
+
+        Self::append_index(
+            &env,
+            DataKey::RequestsByProperty(agreement.property_id.clone()),
+            &request_id,
+        );
+        Self::append_index(
+            &env,
+            DataKey::RequestsByAgreement(agreement_id.clone()),
+            &request_id,
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "MaintenanceSubmitted"),),
+            (
+                request_id.clone(),
+                agreement_id,
+                agreement.property_id,
+                tenant,
+                urgency,
+            ),
+        );
+
+        request_id
+    }
+
+    pub fn acknowledge(env: Env, landlord: Address, request_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        landlord.require_auth();
+
+        let mut request = Self::landlord_request(&env, &landlord, request_id);
+        if request.status != MaintenanceStatus::Submitted {
+            panic_with_error!(&env, Error::InvalidStatusTransition);
+        }
+
+        Self::transition(&env, &mut request, MaintenanceStatus::Acknowledged);
+    }
+
+    // The landlord moves an acknowledged request into progress, or rejects a
+    // request that hasn't started. Completion goes through complete_request.
+    pub fn update_status(
+        env: Env,
+        landlord: Address,
+        request_id: BytesN<32>,
+        status: MaintenanceStatus,
+    ) {
+        Self::check_not_paused(&env);
+
+        landlord.require_auth();
+
+        let mut request = Self::landlord_request(&env, &landlord, request_id);
+        let allowed = matches!(
+            (request.status, status),
+            (
+                MaintenanceStatus::Acknowledged,
+                MaintenanceStatus::InProgress
+            ) | (MaintenanceStatus::Submitted, MaintenanceStatus::Rejected)
+                | (MaintenanceStatus::Acknowledged, MaintenanceStatus::Rejected)
+        );
+        if !allowed {
+            panic_with_error!(&env, Error::InvalidStatusTransition);
+        }
+
+        Self::transition(&env, &mut request, status);
+    }
+
+    pub fn complete_request(env: Env, landlord: Address, request_id: BytesN<32>, cost: i128) {
+        Self::check_not_paused(&env);
+
+        landlord.require_auth();
+
+        if cost < 0 {
+            panic_with_error!(&env, Error::InvalidCost);
+        }
+
+        let mut request = Self::landlord_request(&env, &landlord, request_id);
+        if request.status != MaintenanceStatus::InProgress {
+            panic_with_error!(&env, Error::InvalidStatusTransition);
+        }
+
+        request.completed_at = env.ledger().timestamp();
+        request.cost = cost;
+        Self::transition(&env, &mut request, MaintenanceStatus::Completed);
+    }
+
+    // The tenant signs off that completed work was actually done.
+    pub fn tenant_approve_completion(env: Env, tenant: Address, request_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        tenant.require_auth();
+
+        let request = Self::get_request(env.clone(), request_id.clone());
+        if request.tenant != tenant {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        if request.status != MaintenanceStatus::Completed {
+            panic_with_error!(&env, Error::InvalidStatusTransition);
+        }
+
+        let key = DataKey::CompletionApproved(request_id.clone());
+        if env.storage().persistent().has(&key) {
+            panic_with_error!(&env, Error::AlreadyApproved);
+        }
+        env.storage()
+            .persistent()
+            .set(&key, &env.ledger().timestamp());
+
+        env.events().publish(
+            (Symbol::new(&env, "MaintenanceApproved"),),
+            (request_id, tenant, env.ledger().timestamp()),
+        );
+    }
+
+    pub fn is_completion_approved(env: Env, request_id: BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::CompletionApproved(request_id))
+    }
+
+    pub fn get_request(env: Env, request_id: BytesN<32>) -> MaintenanceRequest {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Request(request_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::RequestNotFound))
+    }
+
+    pub fn get_requests_by_property(env: Env, property_id: BytesN<32>) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RequestsByProperty(property_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_requests_by_agreement(env: Env, agreement_id: BytesN<32>) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RequestsByAgreement(agreement_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // -----------------------------
+    // Internal helpers
+    // -----------------------------
+
+    fn landlord_request(
+        env: &Env,
+        landlord: &Address,
+        request_id: BytesN<32>,
+    ) -> MaintenanceRequest {
+        let request = Self::get_request(env.clone(), request_id);
+        if request.landlord != *landlord {
+            panic_with_error!(env, Error::Unauthorized);
+        }
+        request
+    }
+
+    fn transition(env: &Env, request: &mut MaintenanceRequest, status: MaintenanceStatus) {
+        let from = request.status;
+        request.status = status;
+        Self::save_request(env, request);
+
+        env.events().publish(
+            (Symbol::new(env, "MaintenanceStatusChanged"),),
+            (request.id.clone(), from, status, env.ledger().timestamp()),
+        );
+    }
+
+    fn save_request(env: &Env, request: &MaintenanceRequest) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Request(request.id.clone()), request);
+    }
+
+    fn append_index(env: &Env, key: DataKey, request_id: &BytesN<32>) {
+        let mut ids: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        ids.push_back(request_id.clone());
+        env.storage().persistent().set(&key, &ids);
+    }
+
+    fn fetch_agreement(env: &Env, agreement_id: BytesN<32>) -> RentalAgreement {
+        let addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::AgreementContract)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        RentalAgreementClient::new(env, &addr).get_agreement(&agreement_id)
+    }
+
+    fn check_not_paused(env: &Env) {
+        let paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        if paused {
+            panic_with_error!(env, Error::ContractPaused);
+        }
+    }
+
+    fn require_admin(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[contract]
+    struct MockAgreement;
+
+    #[contractimpl]
+    impl MockAgreement {
+        pub fn set_agreement(env: Env, agreement: RentalAgreement) {
+            env.storage().instance().set(&agreement.id, &agreement);
+        }
+
+        pub fn get_agreement(env: Env, agreement_id: BytesN<32>) -> RentalAgreement {
+            env.storage().instance().get(&agreement_id).unwrap()
+        }
+    }
+
+    fn setup(env: &Env) -> (MaintenanceRequestContractClient<'_>, RentalAgreement) {
+        env.mock_all_auths();
+
+        let agreement_contract = env.register_contract(None, MockAgreement);
+        let agreement = RentalAgreement {
+            id: BytesN::from_array(env, &[1; 32]),
+            property_id: BytesN::from_array(env, &[2; 32]),
+            landlord: Address::generate(env),
+            tenant: Address::generate(env),
+            monthly_rent: 1_000,
+            security_deposit: 500,
+            start_date: 0,
+            end_date: 365 * 24 * 60 * 60,
+            status: AgreementStatus::Active,
+            landlord_signed: true,
+            landlord_signed_at: 0,
+            tenant_signed: true,
+            tenant_signed_at: 0,
+            deposit_paid: true,
+            deposit_paid_at: 0,
+            total_rent_paid: 1_000,
+            months_paid: 1,
+            created_at: 0,
+            completed_at: 0,
+            notice_period_days: 30,
+            notice_served_at: 0,
+            notice_served_by: None,
+            document_hash: BytesN::from_array(env, &[0; 32]),
+            co_signer: None,
+            subletting_allowed: false,
+            custom_clauses: Vec::new(env),
+        };
+        MockAgreementClient::new(env, &agreement_contract).set_agreement(&agreement);
+
+        let contract_id = env.register_contract(None, MaintenanceRequestContract);
+        let client = MaintenanceRequestContractClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &agreement_contract);
+        (client, agreement)
+    }
+
+    fn submit(
+        env: &Env,
+        client: &MaintenanceRequestContractClient<'_>,
+        agreement: &RentalAgreement,
+    ) -> BytesN<32> {
+        client.submit_request(
+            &agreement.tenant,
+            &agreement.id,
+            &String::from_str(env, "Kitchen tap is leaking"),
+            &Urgency::Medium,
+        )
+    }
+
+    #[test]
+    fn initialize_smoke() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, MaintenanceRequestContract);
+        let client = MaintenanceRequestContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let agreement_contract = Address::generate(&env);
+        client.initialize(&admin, &agreement_contract);
+
+        client.pause();
+        client.unpause();
+    }
+
+    #[test]
+    fn request_runs_from_submission_to_approval() {
+        let env = Env::default();
+        let (client, agreement) = setup(&env);
+        let landlord = agreement.landlord.clone();
+
+        let request_id = submit(&env, &client, &agreement);
+        let request = client.get_request(&request_id);
+        assert_eq!(request.status, MaintenanceStatus::Submitted);
+        assert_eq!(request.landlord, landlord);
+        assert_eq!(
+            client.get_requests_by_property(&agreement.property_id),
+            Vec::from_array(&env, [request_id.clone()])
+        );
+        assert_eq!(
+            client.get_requests_by_agreement(&agreement.id),
+            Vec::from_array(&env, [request_id.clone()])
+        );
+
+        client.acknowledge(&landlord, &request_id);
+        client.update_status(&landlord, &request_id, &MaintenanceStatus::InProgress);
+        client.complete_request(&landlord, &request_id, &250);
+        let request = client.get_request(&request_id);
+        assert_eq!(request.status, MaintenanceStatus::Completed);
+        assert_eq!(request.cost, 250);
+
+        client.tenant_approve_completion(&agreement.tenant, &request_id);
+        assert!(client.is_completion_approved(&request_id));
+        assert_eq!(
+            client.try_tenant_approve_completion(&agreement.tenant, &request_id),
+            Err(Ok(Error::AlreadyApproved.into()))
+        );
+    }
+
+    #[test]
+    fn out_of_order_transitions_are_rejected() {
+        let env = Env::default();
+        let (client, agreement) = setup(&env);
+        let landlord = agreement.landlord.clone();
+        let request_id = submit(&env, &client, &agreement);
+
+        assert_eq!(
+            client.try_complete_request(&landlord, &request_id, &100),
+            Err(Ok(Error::InvalidStatusTransition.into()))
+        );
+        assert_eq!(
+            client.try_update_status(&landlord, &request_id, &MaintenanceStatus::InProgress),
+            Err(Ok(Error::InvalidStatusTransition.into()))
+        );
+        assert_eq!(
+            client.try_tenant_approve_completion(&agreement.tenant, &request_id),
+            Err(Ok(Error::InvalidStatusTransition.into()))
+        );
+        assert_eq!(
+            client.try_acknowledge(&agreement.tenant, &request_id),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+
+        client.acknowledge(&landlord, &request_id);
+        // Completion only goes through complete_request.
+        assert_eq!(
+            client.try_update_status(&landlord, &request_id, &MaintenanceStatus::Completed),
+            Err(Ok(Error::InvalidStatusTransition.into()))
+        );
+        client.update_status(&landlord, &request_id, &MaintenanceStatus::Rejected);
+        assert_eq!(
+            client.try_update_status(&landlord, &request_id, &MaintenanceStatus::InProgress),
+            Err(Ok(Error::InvalidStatusTransition.into()))
+        );
+        assert_eq!(
+            client.try_acknowledge(&landlord, &request_id),
+            Err(Ok(Error::InvalidStatusTransition.into()))
+        );
+    }
+}
@@ -0,0 +1,394 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, xdr::FromXdr, Address,
+    Bytes, BytesN, Env, Symbol, Val, Vec,
+};
+
+// -----------------------------
+// MultiSigAdmin contract
+// -----------------------------
+
+const PROPOSAL_TTL_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    Unauthorized = 2,
+    AlreadyInitialized = 3,
+
+    ProposalNotFound = 1100,
+    ProposalExpired = 1101,
+    AlreadyExecuted = 1102,
+    AlreadySigned = 1103,
+    NotSigned = 1104,
+    ThresholdNotMet = 1105,
+    InvalidThreshold = 1106,
+    ArgsMismatch = 1107,
+    InvalidArguments = 1108,
+    DuplicateSigner = 1109,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiSigConfig {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
+
+/// `args_hash` is the SHA-256 of the XDR-encoded `Vec<Val>` that will be
+/// passed to `function_name`; the args themselves are supplied at execution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiSigProposal {
+    pub id: BytesN<32>,
+    pub target_contract: Address,
+    pub function_name: Symbol,
+    pub args_hash: BytesN<32>,
+    pub signatures: Vec<Address>,
+    pub executed: bool,
+    pub expiry: u64,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Config,
+    Proposal(BytesN<32>),
+}
+
+#[contract]
+pub struct MultiSigAdmin;
+
+#[contractimpl]
+impl MultiSigAdmin {
+    // Register this contract as the admin of each sub-contract so privileged
+    // calls can only happen through an executed proposal.
+    // Every initial signer must authorize, so nobody can front-run the
+    // deployment with a signer set they control.
+    pub fn initialize(env: Env, signers: Vec<Address>, threshold: u32) {
+        if env.storage().instance().has(&DataKey::Config) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+
+        Self::validate_signers(&env, &signers, threshold);
+        for signer in signers.iter() {
+            signer.require_auth();
+        }
+        env.storage().instance().set(
+            &DataKey::Config,
+            &MultiSigConfig {
+                signers: signers.clone(),
+                threshold,
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "Initialized"),),
+            (signers, threshold, env.ledger().timestamp()),
+        );
+    }
+
+    // The proposer's signature is counted straight away.
+    pub fn create_proposal(
+        env: Env,
+        proposer: Address,
+        target_contract: Address,
+        function_name: Symbol,
+        args_hash: BytesN<32>,
+    ) -> BytesN<32> {
+        proposer.require_auth();
+        Self::require_signer(&env, &proposer);
+
+        let proposal_id = env.prng().gen::<BytesN<32>>();
+        let mut signatures = Vec::new(&env);
+        signatures.push_back(proposer.clone());
+        let proposal = MultiSigProposal {
+            id: proposal_id.clone(),
+            target_contract: target_contract.clone(),
+            function_name: function_name.clone(),
+            args_hash,
+            signatures,
+            executed: false,
+            expiry: env.ledger().timestamp() + PROPOSAL_TTL_SECONDS,
+        };
+        Self::save_proposal(&env, &proposal);
+
+        env.events().publish(
+            (Symbol::new(&env, "MultiSigProposalCreated"),),
+            (
+                proposal_id.clone(),
+                proposer,
+                target_contract,
+                function_name,
+            ),
+        );
+
+        proposal_id
+    }
+
+    pub fn sign_proposal(env: Env, signer: Address, proposal_id: BytesN<32>) {
+        signer.require_auth();
+        Self::require_signer(&env, &signer);
+
+        let mut proposal = Self::open_proposal(&env, proposal_id.clone());
+        if proposal.signatures.contains(&signer) {
+            panic_with_error!(&env, Error::AlreadySigned);
+        }
+        proposal.signatures.push_back(signer.clone());
+        Self::save_proposal(&env, &proposal);
+
+        env.events().publish(
+            (Symbol::new(&env, "MultiSigProposalSigned"),),
+            (proposal_id, signer, proposal.signatures.len()),
+        );
+    }
+
+    pub fn revoke_signature(env: Env, signer: Address, proposal_id: BytesN<32>) {
+        signer.require_auth();
+
+        let mut proposal = Self::open_proposal(&env, proposal_id.clone());
+        let Some(i) = proposal.signatures.first_index_of(&signer) else {
+            panic_with_error!(&env, Error::NotSigned);
+        };
+        proposal.signatures.remove(i);
+        Self::save_proposal(&env, &proposal);
+
+        env.events().publish(
+            (Symbol::new(&env, "MultiSigSignatureRevoked"),),
+            (proposal_id, signer),
+        );
+    }
+
+    // Anyone can execute once enough current signers have signed. `args` must
+    // hash to the proposal's `args_hash`.
+    pub fn execute_proposal(env: Env, proposal_id: BytesN<32>, args: Bytes) {
+        let mut proposal = Self::open_proposal(&env, proposal_id.clone());
+
+        let config = Self::get_config(env.clone());
+        let valid_signatures = proposal
+            .signatures
+            .iter()
+            .filter(|s| config.signers.contains(s))
+            .count() as u32;
+        if valid_signatures < config.threshold {
+            panic_with_error!(&env, Error::ThresholdNotMet);
+        }
+
+        let args_hash: BytesN<32> = env.crypto().sha256(&args).into();
+        if args_hash != proposal.args_hash {
+            panic_with_error!(&env, Error::ArgsMismatch);
+        }
+        let call_args = Vec::<Val>::from_xdr(&env, &args)
+            .unwrap_or_else(|_| panic_with_error!(&env, Error::InvalidArguments));
+
+        proposal.executed = true;
+        Self::save_proposal(&env, &proposal);
+
+        env.invoke_contract::<Val>(
+            &proposal.target_contract,
+            &proposal.function_name,
+            call_args,
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "MultiSigProposalExecuted"),),
+            (
+                proposal_id,
+                proposal.target_contract,
+                proposal.function_name,
+            ),
+        );
+    }
+
+    // A contract can't invoke itself, so signer changes aren't routed through
+    // proposals: at least `threshold` current signers authorize this call.
+    pub fn update_signers(
+        env: Env,
+        approvers: Vec<Address>,
+        new_signers: Vec<Address>,
+        new_threshold: u32,
+    ) {
+        let config = Self::get_config(env.clone());
+
+        let mut seen: Vec<Address> = Vec::new(&env);
+        for approver in approvers.iter() {
+            if seen.contains(&approver) {
+                panic_with_error!(&env, Error::DuplicateSigner);
+            }
+            if !config.signers.contains(&approver) {
+                panic_with_error!(&env, Error::Unauthorized);
+            }
+            approver.require_auth();
+            seen.push_back(approver);
+        }
+        if seen.len() < config.threshold {
+            panic_with_error!(&env, Error::ThresholdNotMet);
+        }
+
+        Self::validate_signers(&env, &new_signers, new_threshold);
+        env.storage().instance().set(
+            &DataKey::Config,
+            &MultiSigConfig {
+                signers: new_signers.clone(),
+                threshold: new_threshold,
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "SignersUpdated"),),
+            (new_signers, new_threshold),
+        );
+    }
+
+    pub fn get_config(env: Env) -> MultiSigConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Unauthorized))
+    }
+
+    pub fn get_proposal(env: Env, proposal_id: BytesN<32>) -> MultiSigProposal {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Proposal(proposal_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ProposalNotFound))
+    }
+
+    // -----------------------------
+    // Internal helpers
+    // -----------------------------
+
+    fn open_proposal(env: &Env, proposal_id: BytesN<32>) -> MultiSigProposal {
+        let proposal = Self::get_proposal(env.clone(), proposal_id);
+        if proposal.executed {
+            panic_with_error!(env, Error::AlreadyExecuted);
+        }
+        if env.ledger().timestamp() >= proposal.expiry {
+            panic_with_error!(env, Error::ProposalExpired);
+        }
+        proposal
+    }
+
+    fn require_signer(env: &Env, signer: &Address) {
+        if !Self::get_config(env.clone()).signers.contains(signer) {
+            panic_with_error!(env, Error::Unauthorized);
+        }
+    }
+
+    fn validate_signers(env: &Env, signers: &Vec<Address>, threshold: u32) {
+        if threshold == 0 || threshold > signers.len() {
+            panic_with_error!(env, Error::InvalidThreshold);
+        }
+        for (i, signer) in signers.iter().enumerate() {
+            if signers.first_index_of(&signer) != Some(i as u32) {
+                panic_with_error!(env, Error::DuplicateSigner);
+            }
+        }
+    }
+
+    fn save_proposal(env: &Env, proposal: &MultiSigProposal) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(proposal.id.clone()), proposal);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+    use soroban_sdk::xdr::ToXdr;
+    use soroban_sdk::IntoVal;
+
+    #[contract]
+    struct MockTarget;
+
+    #[contractimpl]
+    impl MockTarget {
+        pub fn set_value(env: Env, value: u32) {
+            env.storage().instance().set(&0u32, &value);
+        }
+
+        pub fn value(env: Env) -> u32 {
+            env.storage().instance().get(&0u32).unwrap_or(0)
+        }
+    }
+
+    #[test]
+    fn executes_after_threshold() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, MultiSigAdmin);
+        let client = MultiSigAdminClient::new(&env, &contract_id);
+        let target_id = env.register_contract(None, MockTarget);
+        let target = MockTargetClient::new(&env, &target_id);
+
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        let c = Address::generate(&env);
+        let mut signers = Vec::new(&env);
+        signers.push_back(a.clone());
+        signers.push_back(b.clone());
+        signers.push_back(c.clone());
+        client.initialize(&signers, &2);
+
+        let mut call_args: Vec<Val> = Vec::new(&env);
+        call_args.push_back(7u32.into());
+        let args = call_args.to_xdr(&env);
+        let args_hash: BytesN<32> = env.crypto().sha256(&args).into();
+
+        let proposal_id =
+            client.create_proposal(&a, &target_id, &Symbol::new(&env, "set_value"), &args_hash);
+        assert_eq!(
+            client.try_execute_proposal(&proposal_id, &args),
+            Err(Ok(Error::ThresholdNotMet.into()))
+        );
+
+        client.sign_proposal(&b, &proposal_id);
+        client.execute_proposal(&proposal_id, &args);
+        assert_eq!(target.value(), 7);
+        assert!(client.get_proposal(&proposal_id).executed);
+    }
+
+    #[test]
+    fn initialize_needs_every_signer() {
+        let env = Env::default();
+
+        let contract_id = env.register_contract(None, MultiSigAdmin);
+        let client = MultiSigAdminClient::new(&env, &contract_id);
+
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        let mut signers = Vec::new(&env);
+        signers.push_back(a.clone());
+        signers.push_back(b.clone());
+
+        let args = (signers.clone(), 2u32).into_val(&env);
+        let invoke = MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "initialize",
+            args,
+            sub_invokes: &[],
+        };
+        env.mock_auths(&[MockAuth {
+            address: &a,
+            invoke: &invoke,
+        }]);
+        assert!(client.try_initialize(&signers, &2).is_err());
+
+        env.mock_auths(&[
+            MockAuth {
+                address: &a,
+                invoke: &invoke,
+            },
+            MockAuth {
+                address: &b,
+                invoke: &invoke,
+            },
+        ]);
+        client.initialize(&signers, &2);
+        assert_eq!(client.get_config().threshold, 2);
+    }
+}
@@ -0,0 +1,460 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error, token,
+    Address, Env, Symbol, Vec,
+};
+
+// -----------------------------
+// Cross-contract: BriqToken
+// -----------------------------
+
+#[contractclient(name = "BriqTokenClient")]
+pub trait BriqTokenContract {
+    fn get_stakers() -> Vec<Address>;
+    fn get_staked_balance(staker: Address) -> i128;
+}
+
+// -----------------------------
+// PlatformTreasury contract
+// -----------------------------
+
+const MAX_FEE_BPS: u32 = 1_000;
+// One transfer per staker; keeps distribute_to_stakers inside the
+// per-transaction budget.
+const MAX_STAKERS_PER_DISTRIBUTION: u32 = 100;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    ContractPaused = 1,
+    Unauthorized = 2,
+    AlreadyInitialized = 3,
+
+    InvalidAmount = 900,
+    InsufficientTreasuryBalance = 901,
+    InvalidFeeRate = 902,
+    NoStakers = 903,
+    TooManyStakers = 904,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Paused,
+    BriqToken,
+    EscrowContract,
+    Governance,
+    FeeBps,
+    Balance(Address),
+    CumulativeFees,
+    FeeExempt(Address),
+}
+
+#[contract]
+pub struct PlatformTreasury;
+
+#[contractimpl]
+impl PlatformTreasury {
+    pub fn initialize(env: Env, admin: Address, briq_token: Address, escrow_contract: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.storage()
+            .instance()
+            .set(&DataKey::BriqToken, &briq_token);
+        env.storage()
+            .instance()
+            .set(&DataKey::EscrowContract, &escrow_contract);
+
+        env.events().publish(
+            (Symbol::new(&env, "Initialized"),),
+            (admin, briq_token, escrow_contract, env.ledger().timestamp()),
+        );
+    }
+
+    pub fn pause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.events()
+            .publish((Symbol::new(&env, "Paused"),), env.ledger().timestamp());
+    }
+
+    pub fn unpause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.events()
+            .publish((Symbol::new(&env, "Unpaused"),), env.ledger().timestamp());
+    }
+
+    // Governance contract allowed to change the fee rate.
+    // Until one is set, the admin controls it.
+    pub fn set_governance(env: Env, governance: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Governance, &governance);
+
+        env.events().publish(
+            (Symbol::new(&env, "GovernanceSet"),),
+            (governance, env.ledger().timestamp()),
+        );
+    }
+
+    // Called by EscrowManager after it has transferred `amount` of `token`
+    // to the treasury.
+    pub fn deposit(env: Env, amount: i128, token: Address) {
+        Self::check_not_paused(&env);
+
+        let escrow: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::EscrowContract)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Unauthorized));
+        escrow.require_auth();
+
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let balance = Self::get_balance(env.clone(), token.clone());
+        Self::set_balance(&env, &token, balance + amount);
+        let cumulative = Self::get_cumulative_fees_collected(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::CumulativeFees, &(cumulative + amount));
+
+        env.events()
+            .publish((Symbol::new(&env, "FeeDeposited"),), (token, amount));
+    }
+
+    pub fn withdraw(env: Env, to: Address, amount: i128, token: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        Self::debit(&env, &token, amount);
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+
+        env.events().publish(
+            (Symbol::new(&env, "TreasuryWithdrawal"),),
+            (to, token, amount),
+        );
+    }
+
+    pub fn get_balance(env: Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Balance(token))
+            .unwrap_or(0)
+    }
+
+    pub fn set_fee_rate(env: Env, fee_bps: u32) {
+        Self::check_not_paused(&env);
+
+        let controller: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Governance)
+            .unwrap_or_else(|| Self::require_admin(&env));
+        controller.require_auth();
+
+        if fee_bps > MAX_FEE_BPS {
+            panic_with_error!(&env, Error::InvalidFeeRate);
+        }
+
+        env.storage().instance().set(&DataKey::FeeBps, &fee_bps);
+
+        env.events()
+            .publish((Symbol::new(&env, "FeeRateUpdated"),), fee_bps);
+    }
+
+    pub fn get_fee_rate(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::FeeBps).unwrap_or(0)
+    }
+
+    // Splits `amount` of `token_contract` across BRIQ stakers in proportion to
+    // their staked balance. Rounding dust stays in the treasury. Fails with
+    // TooManyStakers past MAX_STAKERS_PER_DISTRIBUTION stakers.
+    pub fn distribute_to_stakers(env: Env, token_contract: Address, amount: i128) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if Self::get_balance(env.clone(), token_contract.clone()) < amount {
+            panic_with_error!(&env, Error::InsufficientTreasuryBalance);
+        }
+
+        let briq = Self::briq_client(&env);
+        let stakers = briq.get_stakers();
+        if stakers.len() > MAX_STAKERS_PER_DISTRIBUTION {
+            panic_with_error!(&env, Error::TooManyStakers);
+        }
+        let mut stakes: Vec<(Address, i128)> = Vec::new(&env);
+        let mut total_staked: i128 = 0;
+        for staker in stakers.iter() {
+            let staked = briq.get_staked_balance(&staker);
+            if staked > 0 {
+                total_staked += staked;
+                stakes.push_back((staker, staked));
+            }
+        }
+        if total_staked == 0 {
+            panic_with_error!(&env, Error::NoStakers);
+        }
+
+        let payout_token = token::Client::new(&env, &token_contract);
+        let treasury = env.current_contract_address();
+        let mut distributed: i128 = 0;
+        for (staker, staked) in stakes.iter() {
+            let share = amount * staked / total_staked;
+            if share > 0 {
+                payout_token.transfer(&treasury, &staker, &share);
+                distributed += share;
+            }
+        }
+        Self::debit(&env, &token_contract, distributed);
+
+        env.events().publish(
+            (Symbol::new(&env, "DistributedToStakers"),),
+            (token_contract, distributed, stakes.len()),
+        );
+    }
+
+    pub fn get_cumulative_fees_collected(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CumulativeFees)
+            .unwrap_or(0)
+    }
+
+    pub fn add_fee_exemption(env: Env, address: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::FeeExempt(address.clone()), &true);
+
+        env.events()
+            .publish((Symbol::new(&env, "FeeExemptionAdded"),), address);
+    }
+
+    pub fn remove_fee_exemption(env: Env, address: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::FeeExempt(address.clone()));
+
+        env.events()
+            .publish((Symbol::new(&env, "FeeExemptionRemoved"),), address);
+    }
+
+    pub fn is_fee_exempt(env: Env, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FeeExempt(address))
+            .unwrap_or(false)
+    }
+
+    // -----------------------------
+    // Internal helpers
+    // -----------------------------
+
+    fn debit(env: &Env, token: &Address, amount: i128) {
+        if amount <= 0 {
+            panic_with_error!(env, Error::InvalidAmount);
+        }
+        let balance = Self::get_balance(env.clone(), token.clone());
+        if balance < amount {
+            panic_with_error!(env, Error::InsufficientTreasuryBalance);
+        }
+        Self::set_balance(env, token, balance - amount);
+    }
+
+    fn set_balance(env: &Env, token: &Address, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Balance(token.clone()), &amount);
+    }
+
+    fn briq_client(env: &Env) -> BriqTokenClient<'_> {
+        let addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::BriqToken)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        BriqTokenClient::new(env, &addr)
+    }
+
+    fn check_not_paused(env: &Env) {
+        let paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        if paused {
+            panic_with_error!(env, Error::ContractPaused);
+        }
+    }
+
+    fn require_admin(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[contract]
+    struct MockBriq;
+
+    #[contractimpl]
+    impl MockBriq {
+        pub fn set_stake(env: Env, staker: Address, amount: i128) {
+            let mut stakers = Self::get_stakers(env.clone());
+            stakers.push_back(staker.clone());
+            env.storage()
+                .instance()
+                .set(&Symbol::new(&env, "stakers"), &stakers);
+            env.storage().instance().set(&staker, &amount);
+        }
+
+        pub fn get_stakers(env: Env) -> Vec<Address> {
+            env.storage()
+                .instance()
+                .get(&Symbol::new(&env, "stakers"))
+                .unwrap_or(Vec::new(&env))
+        }
+
+        pub fn get_staked_balance(env: Env, staker: Address) -> i128 {
+            env.storage().instance().get(&staker).unwrap_or(0)
+        }
+    }
+
+    // Treasury holding 1_000 of a fresh asset in fees.
+    fn setup_funded(
+        env: &Env,
+    ) -> (
+        PlatformTreasuryClient<'_>,
+        MockBriqClient<'_>,
+        token::Client<'_>,
+    ) {
+        env.mock_all_auths();
+
+        let briq = env.register_contract(None, MockBriq);
+        let contract_id = env.register_contract(None, PlatformTreasury);
+        let client = PlatformTreasuryClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &briq, &Address::generate(env));
+
+        let asset = env.register_stellar_asset_contract_v2(Address::generate(env));
+        token::StellarAssetClient::new(env, &asset.address()).mint(&contract_id, &1_000);
+        client.deposit(&1_000, &asset.address());
+
+        (
+            client,
+            MockBriqClient::new(env, &briq),
+            token::Client::new(env, &asset.address()),
+        )
+    }
+
+    #[test]
+    fn initialize_smoke() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, PlatformTreasury);
+        let client = PlatformTreasuryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let briq_token = Address::generate(&env);
+        let escrow_contract = Address::generate(&env);
+        client.initialize(&admin, &briq_token, &escrow_contract);
+
+        client.set_fee_rate(&250);
+        assert_eq!(client.get_fee_rate(), 250);
+        assert_eq!(
+            client.try_set_fee_rate(&(MAX_FEE_BPS + 1)),
+            Err(Ok(Error::InvalidFeeRate.into()))
+        );
+
+        client.pause();
+        client.unpause();
+    }
+
+    #[test]
+    fn distribution_follows_stake_and_keeps_dust() {
+        let env = Env::default();
+        let (client, briq, asset) = setup_funded(&env);
+        let (alice, bob, idle) = (
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        );
+        briq.set_stake(&alice, &300);
+        briq.set_stake(&bob, &100);
+        briq.set_stake(&idle, &0);
+
+        client.distribute_to_stakers(&asset.address, &500);
+        assert_eq!(asset.balance(&alice), 375);
+        assert_eq!(asset.balance(&bob), 125);
+        assert_eq!(asset.balance(&idle), 0);
+        assert_eq!(client.get_balance(&asset.address), 500);
+
+        // 7 splits as 5.25 and 1.75; the remainder stays in the treasury.
+        client.distribute_to_stakers(&asset.address, &7);
+        assert_eq!(asset.balance(&alice), 380);
+        assert_eq!(asset.balance(&bob), 126);
+        assert_eq!(client.get_balance(&asset.address), 494);
+
+        assert_eq!(
+            client.try_distribute_to_stakers(&asset.address, &495),
+            Err(Ok(Error::InsufficientTreasuryBalance.into()))
+        );
+    }
+
+    #[test]
+    fn distribution_needs_a_bounded_set_of_stakers() {
+        let env = Env::default();
+        let (client, briq, asset) = setup_funded(&env);
+        assert_eq!(
+            client.try_distribute_to_stakers(&asset.address, &100),
+            Err(Ok(Error::NoStakers.into()))
+        );
+
+        for _ in 0..=MAX_STAKERS_PER_DISTRIBUTION {
+            briq.set_stake(&Address::generate(&env), &10);
+        }
+        assert_eq!(
+            client.try_distribute_to_stakers(&asset.address, &100),
+            Err(Ok(Error::TooManyStakers.into()))
+        );
+    }
+}
@@ -0,0 +1,799 @@
+#![no_std]
+#![allow(clippy::too_many_arguments)]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error, Address,
+    BytesN, Env, String, Symbol, Vec,
+};
+
+use soroban_sdk::token;
+
+// -----------------------------
+// Cross-contract: RentalAgreement
+// -----------------------------
+
+#[contractclient(name = "RentalAgreementClient")]
+pub trait RentalAgreementContract {
+    fn create_agreement(
+        landlord: Address,
+        property_id: BytesN<32>,
+        tenant: Address,
+        start_date: u64,
+        end_date: u64,
+        notice_period_days: u32,
+        co_signer: Option<Address>,
+        subletting_allowed: bool,
+        custom_clauses: Vec<String>,
+    ) -> BytesN<32>;
+}
+
+// -----------------------------
+// Cross-contract: PropertyRegistry
+// -----------------------------
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PropertyType {
+    Apartment,
+    House,
+    Condo,
+    Studio,
+    Commercial,
+    Other,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PetPolicy {
+    NoPets,
+    CatsOnly,
+    SmallDogsOnly,
+    AllPets,
+    NegotiableCase,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Property {
+    pub id: BytesN<32>,
+    pub owner: Address,
+    pub title: String,
+    pub description: String,
+    pub location: String,
+    pub price_per_month: i128,
+    pub security_deposit: i128,
+    pub min_stay_days: u32,
+    pub max_stay_days: u32,
+    pub image_url: String,
+    pub amenities: Vec<String>,
+    pub property_type: PropertyType,
+    pub latitude: i64,
+    pub longitude: i64,
+    pub floor_area_sqft: u32,
+    pub furnished: bool,
+    pub parking_spaces: u32,
+    pub pet_policy: PetPolicy,
+    pub is_verified: bool,
+    pub is_available: bool,
+    pub is_active: bool,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+#[contractclient(name = "PropertyRegistryClient")]
+pub trait PropertyRegistry {
+    fn get_property(property_id: BytesN<32>) -> Property;
+}
+
+// -----------------------------
+// PropertyAuction contract
+// -----------------------------
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+const MIN_AUCTION_SECONDS: u64 = 60 * 60;
+const MAX_AUCTION_SECONDS: u64 = 30 * SECONDS_PER_DAY;
+const AGREEMENT_NOTICE_PERIOD_DAYS: u32 = 30;
+// How long the landlord has to end an auction with a winner before the
+// winner may take their bid back.
+const SETTLEMENT_GRACE_SECONDS: u64 = 7 * SECONDS_PER_DAY;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    ContractPaused = 1,
+    Unauthorized = 2,
+    AlreadyInitialized = 3,
+
+    AuctionNotFound = 1300,
+    AuctionNotActive = 1301,
+    AuctionEnded = 1302,
+    AuctionStillRunning = 1303,
+    BidTooLow = 1304,
+    InvalidAmount = 1305,
+    InvalidDuration = 1306,
+    AuctionHasBids = 1307,
+    SettlementPending = 1308,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuctionStatus {
+    Active,
+    Ended,
+    Cancelled,
+}
+
+/// The winner's lease runs for `lease_days` from the moment the auction ends.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Auction {
+    pub id: BytesN<32>,
+    pub property_id: BytesN<32>,
+    pub landlord: Address,
+    pub start_bid: i128,
+    pub current_bid: i128,
+    pub current_bidder: Option<Address>,
+    pub end_timestamp: u64,
+    pub status: AuctionStatus,
+    pub lease_days: u32,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Paused,
+    AgreementContract,
+    PropertyRegistry,
+    XlmToken,
+    Auction(BytesN<32>),
+    ActiveAuctions,
+    AuctionAgreement(BytesN<32>),
+}
+
+#[contract]
+pub struct PropertyAuction;
+
+#[contractimpl]
+impl PropertyAuction {
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        agreement_contract: Address,
+        property_registry: Address,
+        xlm_token: Address,
+    ) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.storage()
+            .instance()
+            .set(&DataKey::AgreementContract, &agreement_contract);
+        env.storage()
+            .instance()
+            .set(&DataKey::PropertyRegistry, &property_registry);
+        env.storage().instance().set(&DataKey::XlmToken, &xlm_token);
+
+        env.events().publish(
+            (Symbol::new(&env, "Initialized"),),
+            (
+                admin,
+                agreement_contract,
+                property_registry,
+                xlm_token,
+                env.ledger().timestamp(),
+            ),
+        );
+    }
+
+    pub fn pause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.events()
+            .publish((Symbol::new(&env, "Paused"),), env.ledger().timestamp());
+    }
+
+    pub fn unpause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.events()
+            .publish((Symbol::new(&env, "Unpaused"),), env.ledger().timestamp());
+    }
+
+    // Only the property's registered owner can auction it, for a lease
+    // within the property's minimum and maximum stay.
+    pub fn create_auction(
+        env: Env,
+        landlord: Address,
+        property_id: BytesN<32>,
+        start_bid: i128,
+        duration_seconds: u64,
+        lease_days: u32,
+    ) -> BytesN<32> {
+        Self::check_not_paused(&env);
+
+        landlord.require_auth();
+
+        if start_bid <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if !(MIN_AUCTION_SECONDS..=MAX_AUCTION_SECONDS).contains(&duration_seconds) {
+            panic_with_error!(&env, Error::InvalidDuration);
+        }
+        if lease_days == 0 {
+            panic_with_error!(&env, Error::InvalidDuration);
+        }
+        let property = Self::registry_client(&env).get_property(&property_id);
+        if property.owner != landlord {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        // RentalAgreement enforces the stay limits when the winner's lease is
+        // created, so an auction outside them could never settle.
+        if lease_days < property.min_stay_days || lease_days > property.max_stay_days {
+            panic_with_error!(&env, Error::InvalidDuration);
+        }
+
+        let auction_id = env.prng().gen::<BytesN<32>>();
+        let auction = Auction {
+            id: auction_id.clone(),
+            property_id: property_id.clone(),
+            landlord: landlord.clone(),
+            start_bid,
+            current_bid: 0,
+            current_bidder: None,
+            end_timestamp: env.ledger().timestamp() + duration_seconds,
+            status: AuctionStatus::Active,
+            lease_days,
+        };
+        Self::save_auction(&env, &auction);
+
+        let mut active = Self::get_active_auctions(env.clone());
+        active.push_back(auction_id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::ActiveAuctions, &active);
+
+        env.events().publish(
+            (Symbol::new(&env, "AuctionCreated"),),
+            (
+                auction_id.clone(),
+                property_id,
+                landlord,
+                start_bid,
+                auction.end_timestamp,
+            ),
+        );
+
+        auction_id
+    }
+
+    // The bid is held by this contract; the previous highest bidder is
+    // refunded in the same call.
+    pub fn place_bid(env: Env, bidder: Address, auction_id: BytesN<32>, amount: i128) {
+        Self::check_not_paused(&env);
+
+        bidder.require_auth();
+
+        let mut auction = Self::active_auction(&env, auction_id.clone());
+        if env.ledger().timestamp() >= auction.end_timestamp {
+            panic_with_error!(&env, Error::AuctionEnded);
+        }
+        if bidder == auction.landlord {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        if amount < auction.start_bid || amount <= auction.current_bid {
+            panic_with_error!(&env, Error::BidTooLow);
+        }
+
+        let token_client = Self::xlm_client(&env);
+        let contract_addr = env.current_contract_address();
+        token_client.transfer(&bidder, &contract_addr, &amount);
+        if let Some(previous) = auction.current_bidder.clone() {
+            token_client.transfer(&contract_addr, &previous, &auction.current_bid);
+            env.events().publish(
+                (Symbol::new(&env, "BidRefunded"),),
+                (auction_id.clone(), previous, auction.current_bid),
+            );
+        }
+
+        auction.current_bid = amount;
+        auction.current_bidder = Some(bidder.clone());
+        Self::save_auction(&env, &auction);
+
+        env.events().publish(
+            (Symbol::new(&env, "BidPlaced"),),
+            (auction_id, bidder, amount),
+        );
+    }
+
+    // Pays the winning bid to the landlord and creates the winner's agreement.
+    // RentalAgreement requires the landlord's auth for that, so the landlord
+    // authorizes this call when there is a winner. If they don't within
+    // SETTLEMENT_GRACE_SECONDS, the winner can use `reclaim_winning_bid`.
+    pub fn end_auction(env: Env, auction_id: BytesN<32>) -> Option<BytesN<32>> {
+        Self::check_not_paused(&env);
+
+        let mut auction = Self::active_auction(&env, auction_id.clone());
+        let now = env.ledger().timestamp();
+        if now < auction.end_timestamp {
+            panic_with_error!(&env, Error::AuctionStillRunning);
+        }
+
+        auction.status = AuctionStatus::Ended;
+        Self::save_auction(&env, &auction);
+        Self::remove_active(&env, &auction_id);
+
+        let agreement_id = auction.current_bidder.clone().map(|winner| {
+            Self::xlm_client(&env).transfer(
+                &env.current_contract_address(),
+                &auction.landlord,
+                &auction.current_bid,
+            );
+
+            let agreement_id = Self::agreement_client(&env).create_agreement(
+                &auction.landlord,
+                &auction.property_id,
+                &winner,
+                &now,
+                &(now + auction.lease_days as u64 * SECONDS_PER_DAY),
+                &AGREEMENT_NOTICE_PERIOD_DAYS,
+                &None,
+                &false,
+                &Vec::new(&env),
+            );
+            env.storage().persistent().set(
+                &DataKey::AuctionAgreement(auction_id.clone()),
+                &agreement_id,
+            );
+            agreement_id
+        });
+
+        env.events().publish(
+            (Symbol::new(&env, "AuctionEnded"),),
+            (
+                auction_id,
+                auction.current_bidder,
+                auction.current_bid,
+                agreement_id.clone(),
+            ),
+        );
+
+        agreement_id
+    }
+
+    // Only before the first bid and the deadline; after that the auction has
+    // to be ended.
+    pub fn cancel_auction(env: Env, landlord: Address, auction_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        landlord.require_auth();
+
+        let mut auction = Self::active_auction(&env, auction_id.clone());
+        if auction.landlord != landlord {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        if auction.current_bidder.is_some() {
+            panic_with_error!(&env, Error::AuctionHasBids);
+        }
+        if env.ledger().timestamp() >= auction.end_timestamp {
+            panic_with_error!(&env, Error::AuctionEnded);
+        }
+
+        auction.status = AuctionStatus::Cancelled;
+        Self::save_auction(&env, &auction);
+        Self::remove_active(&env, &auction_id);
+
+        env.events().publish(
+            (Symbol::new(&env, "AuctionCancelled"),),
+            (auction_id, landlord),
+        );
+    }
+
+    // Lets the winner recover their bid when the landlord never ends the
+    // auction. The auction is closed as cancelled.
+    pub fn reclaim_winning_bid(env: Env, bidder: Address, auction_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        bidder.require_auth();
+
+        let mut auction = Self::active_auction(&env, auction_id.clone());
+        if auction.current_bidder != Some(bidder.clone()) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        if env.ledger().timestamp()
+            < auction
+                .end_timestamp
+                .saturating_add(SETTLEMENT_GRACE_SECONDS)
+        {
+            panic_with_error!(&env, Error::SettlementPending);
+        }
+
+        Self::xlm_client(&env).transfer(
+            &env.current_contract_address(),
+            &bidder,
+            &auction.current_bid,
+        );
+
+        auction.status = AuctionStatus::Cancelled;
+        Self::save_auction(&env, &auction);
+        Self::remove_active(&env, &auction_id);
+
+        env.events().publish(
+            (Symbol::new(&env, "BidRefunded"),),
+            (auction_id, bidder, auction.current_bid),
+        );
+    }
+
+    pub fn get_auction(env: Env, auction_id: BytesN<32>) -> Auction {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Auction(auction_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::AuctionNotFound))
+    }
+
+    pub fn get_active_auctions(env: Env) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ActiveAuctions)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_auction_agreement(env: Env, auction_id: BytesN<32>) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AuctionAgreement(auction_id))
+    }
+
+    // -----------------------------
+    // Internal helpers
+    // -----------------------------
+
+    fn active_auction(env: &Env, auction_id: BytesN<32>) -> Auction {
+        let auction = Self::get_auction(env.clone(), auction_id);
+        if auction.status != AuctionStatus::Active {
+            panic_with_error!(env, Error::AuctionNotActive);
+        }
+        auction
+    }
+
+    fn remove_active(env: &Env, auction_id: &BytesN<32>) {
+        let mut active = Self::get_active_auctions(env.clone());
+        if let Some(i) = active.first_index_of(auction_id) {
+            active.remove(i);
+            env.storage()
+                .persistent()
+                .set(&DataKey::ActiveAuctions, &active);
+        }
+    }
+
+    fn save_auction(env: &Env, auction: &Auction) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Auction(auction.id.clone()), auction);
+    }
+
+    fn agreement_client(env: &Env) -> RentalAgreementClient<'_> {
+        let addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::AgreementContract)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        RentalAgreementClient::new(env, &addr)
+    }
+
+    fn registry_client(env: &Env) -> PropertyRegistryClient<'_> {
+        let addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PropertyRegistry)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        PropertyRegistryClient::new(env, &addr)
+    }
+
+    fn xlm_client(env: &Env) -> token::Client<'_> {
+        let token_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::XlmToken)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        token::Client::new(env, &token_addr)
+    }
+
+    fn check_not_paused(env: &Env) {
+        let paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        if paused {
+            panic_with_error!(env, Error::ContractPaused);
+        }
+    }
+
+    fn require_admin(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    #[contract]
+    struct MockRegistry;
+
+    #[contractimpl]
+    impl MockRegistry {
+        pub fn set_owner(env: Env, property_id: BytesN<32>, owner: Address) {
+            env.storage().instance().set(&property_id, &owner);
+        }
+
+        pub fn get_property(env: Env, property_id: BytesN<32>) -> Property {
+            let owner: Address = env.storage().instance().get(&property_id).unwrap();
+            Property {
+                id: property_id,
+                owner,
+                title: String::from_str(&env, "Loft"),
+                description: String::from_str(&env, "Top floor"),
+                location: String::from_str(&env, "Downtown"),
+                price_per_month: 1_000,
+                security_deposit: 500,
+                min_stay_days: 30,
+                max_stay_days: 365,
+                image_url: String::from_str(&env, ""),
+                amenities: Vec::new(&env),
+                property_type: PropertyType::Apartment,
+                latitude: 0,
+                longitude: 0,
+                floor_area_sqft: 800,
+                furnished: true,
+                parking_spaces: 0,
+                pet_policy: PetPolicy::NoPets,
+                is_verified: true,
+                is_available: true,
+                is_active: true,
+                created_at: 0,
+                updated_at: 0,
+            }
+        }
+    }
+
+    #[contract]
+    struct MockAgreement;
+
+    #[contractimpl]
+    impl MockAgreement {
+        pub fn create_agreement(
+            env: Env,
+            landlord: Address,
+            property_id: BytesN<32>,
+            tenant: Address,
+            start_date: u64,
+            end_date: u64,
+            _notice_period_days: u32,
+            _co_signer: Option<Address>,
+            _subletting_allowed: bool,
+            _custom_clauses: Vec<String>,
+        ) -> BytesN<32> {
+            env.storage().instance().set(
+                &Symbol::new(&env, "created"),
+                &(landlord, property_id, tenant, start_date, end_date),
+            );
+            BytesN::from_array(&env, &[9; 32])
+        }
+
+        pub fn get_created(env: Env) -> (Address, BytesN<32>, Address, u64, u64) {
+            env.storage()
+                .instance()
+                .get(&Symbol::new(&env, "created"))
+                .unwrap()
+        }
+    }
+
+    struct Setup<'a> {
+        client: PropertyAuctionClient<'a>,
+        contract_id: Address,
+        agreements: MockAgreementClient<'a>,
+        xlm: token::Client<'a>,
+        landlord: Address,
+        alice: Address,
+        bob: Address,
+        property_id: BytesN<32>,
+    }
+
+    // Alice and Bob hold 1_000 XLM each; the landlord owns the property.
+    fn setup(env: &Env) -> Setup<'_> {
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, PropertyAuction);
+        let client = PropertyAuctionClient::new(env, &contract_id);
+
+        let xlm = env.register_stellar_asset_contract_v2(Address::generate(env));
+        let xlm_admin = token::StellarAssetClient::new(env, &xlm.address());
+
+        let registry = env.register_contract(None, MockRegistry);
+        let agreements = env.register_contract(None, MockAgreement);
+        let landlord = Address::generate(env);
+        let property_id = BytesN::from_array(env, &[1; 32]);
+        MockRegistryClient::new(env, &registry).set_owner(&property_id, &landlord);
+
+        client.initialize(
+            &Address::generate(env),
+            &agreements,
+            &registry,
+            &xlm.address(),
+        );
+
+        let alice = Address::generate(env);
+        let bob = Address::generate(env);
+        xlm_admin.mint(&alice, &1_000);
+        xlm_admin.mint(&bob, &1_000);
+
+        Setup {
+            client,
+            contract_id,
+            agreements: MockAgreementClient::new(env, &agreements),
+            xlm: token::Client::new(env, &xlm.address()),
+            landlord,
+            alice,
+            bob,
+            property_id,
+        }
+    }
+
+    #[test]
+    fn outbid_bidder_is_refunded() {
+        let env = Env::default();
+        let s = setup(&env);
+        let auction_id = s
+            .client
+            .create_auction(&s.landlord, &s.property_id, &100, &86_400, &30);
+
+        assert_eq!(
+            s.client.try_place_bid(&s.alice, &auction_id, &50),
+            Err(Ok(Error::BidTooLow.into()))
+        );
+        s.client.place_bid(&s.alice, &auction_id, &150);
+        s.client.place_bid(&s.bob, &auction_id, &200);
+
+        assert_eq!(s.xlm.balance(&s.alice), 1_000);
+        assert_eq!(s.xlm.balance(&s.bob), 800);
+        assert_eq!(s.xlm.balance(&s.contract_id), 200);
+
+        assert_eq!(
+            s.client.try_cancel_auction(&s.landlord, &auction_id),
+            Err(Ok(Error::AuctionHasBids.into()))
+        );
+    }
+
+    #[test]
+    fn winner_gets_agreement_and_landlord_gets_bid() {
+        let env = Env::default();
+        let s = setup(&env);
+        let auction_id = s
+            .client
+            .create_auction(&s.landlord, &s.property_id, &100, &86_400, &30);
+        s.client.place_bid(&s.alice, &auction_id, &150);
+        s.client.place_bid(&s.bob, &auction_id, &200);
+
+        env.ledger().with_mut(|l| l.timestamp += 86_400);
+        let agreement_id = s.client.end_auction(&auction_id);
+        assert_eq!(agreement_id, Some(BytesN::from_array(&env, &[9; 32])));
+        assert_eq!(s.client.get_auction_agreement(&auction_id), agreement_id);
+        assert_eq!(
+            s.client.get_auction(&auction_id).status,
+            AuctionStatus::Ended
+        );
+        assert_eq!(s.client.get_active_auctions().len(), 0);
+
+        assert_eq!(s.xlm.balance(&s.landlord), 200);
+        assert_eq!(s.xlm.balance(&s.alice), 1_000);
+        assert_eq!(s.xlm.balance(&s.contract_id), 0);
+
+        let now = env.ledger().timestamp();
+        assert_eq!(
+            s.agreements.get_created(),
+            (
+                s.landlord.clone(),
+                s.property_id.clone(),
+                s.bob.clone(),
+                now,
+                now + 30 * SECONDS_PER_DAY
+            )
+        );
+    }
+
+    #[test]
+    fn lease_must_fit_the_property_stay_limits() {
+        let env = Env::default();
+        let s = setup(&env);
+        for lease_days in [0, 29, 366] {
+            assert_eq!(
+                s.client.try_create_auction(
+                    &s.landlord,
+                    &s.property_id,
+                    &100,
+                    &86_400,
+                    &lease_days
+                ),
+                Err(Ok(Error::InvalidDuration.into()))
+            );
+        }
+        s.client
+            .create_auction(&s.landlord, &s.property_id, &100, &86_400, &365);
+    }
+
+    #[test]
+    fn only_the_owner_auctions_and_cancels_before_bids() {
+        let env = Env::default();
+        let s = setup(&env);
+        assert_eq!(
+            s.client
+                .try_create_auction(&s.alice, &s.property_id, &100, &86_400, &30),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+
+        let auction_id = s
+            .client
+            .create_auction(&s.landlord, &s.property_id, &100, &86_400, &30);
+        s.client.cancel_auction(&s.landlord, &auction_id);
+        assert_eq!(
+            s.client.get_auction(&auction_id).status,
+            AuctionStatus::Cancelled
+        );
+        assert_eq!(s.client.get_active_auctions().len(), 0);
+
+        let auction_id = s
+            .client
+            .create_auction(&s.landlord, &s.property_id, &100, &86_400, &30);
+        env.ledger().with_mut(|l| l.timestamp += 86_400);
+        assert_eq!(
+            s.client.try_cancel_auction(&s.landlord, &auction_id),
+            Err(Ok(Error::AuctionEnded.into()))
+        );
+    }
+
+    #[test]
+    fn winner_reclaims_bid_when_auction_is_never_ended() {
+        let env = Env::default();
+        let s = setup(&env);
+        let auction_id = s
+            .client
+            .create_auction(&s.landlord, &s.property_id, &100, &86_400, &30);
+        s.client.place_bid(&s.alice, &auction_id, &150);
+
+        env.ledger().with_mut(|l| l.timestamp += 86_400);
+        assert_eq!(
+            s.client.try_reclaim_winning_bid(&s.alice, &auction_id),
+            Err(Ok(Error::SettlementPending.into()))
+        );
+
+        env.ledger()
+            .with_mut(|l| l.timestamp += SETTLEMENT_GRACE_SECONDS);
+        assert_eq!(
+            s.client.try_reclaim_winning_bid(&s.bob, &auction_id),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+        s.client.reclaim_winning_bid(&s.alice, &auction_id);
+        assert_eq!(s.xlm.balance(&s.alice), 1_000);
+        assert_eq!(s.xlm.balance(&s.contract_id), 0);
+        assert_eq!(
+            s.client.get_auction(&auction_id).status,
+            AuctionStatus::Cancelled
+        );
+        assert_eq!(
+            s.client.try_end_auction(&auction_id),
+            Err(Ok(Error::AuctionNotActive.into()))
+        );
+    }
+}
@@ -1,10 +1,78 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, panic_with_error, Address, BytesN, Env,
-    String, Symbol, Vec,
+    contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error, Address,
+    BytesN, Env, String, Symbol, Vec,
 };
 
+// -----------------------------
+// Cross-contract: RentalAgreement (for property stats)
+// -----------------------------
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AgreementStatus {
+    PendingLandlordApproval,
+    Rejected,
+    Draft,
+    PendingTenantSign,
+    PendingLandlordSign,
+    PendingCoSignerSign,
+    PendingPayment,
+    Active,
+    Completed,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentalAgreement {
+    pub id: BytesN<32>,
+    pub property_id: BytesN<32>,
+    pub landlord: Address,
+    pub tenant: Address,
+    pub monthly_rent: i128,
+    pub security_deposit: i128,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub status: AgreementStatus,
+    pub landlord_signed: bool,
+    pub landlord_signed_at: u64,
+    pub tenant_signed: bool,
+    pub tenant_signed_at: u64,
+    pub deposit_paid: bool,
+    pub deposit_paid_at: u64,
+    pub total_rent_paid: i128,
+    pub months_paid: u32,
+    pub created_at: u64,
+    pub completed_at: u64,
+    pub notice_period_days: u32,
+    pub notice_served_at: u64,
+    pub notice_served_by: Option<Address>,
+    pub document_hash: BytesN<32>,
+    pub co_signer: Option<Address>,
+    pub subletting_allowed: bool,
+    pub custom_clauses: Vec<String>,
+}
+
+#[contractclient(name = "RentalAgreementClient")]
+pub trait RentalAgreementContract {
+    fn get_agreements_by_property(property_id: BytesN<32>) -> Vec<RentalAgreement>;
+}
+
+// -----------------------------
+// Cross-contract: BriqToken (optional, listing fee)
+// -----------------------------
+
+#[contractclient(name = "BriqTokenClient")]
+pub trait BriqToken {
+    fn burn_for_service(from: Address, amount: i128, service: Symbol);
+}
+
+// -----------------------------
+// PropertyRegistry contract
+// -----------------------------
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
@@ -20,6 +88,65 @@ pub enum Error {
     InvalidMinStay = 104,
     InvalidMaxStay = 105,
     PropertyNotFound = 106,
+    InvalidAmenity = 107,
+    PropertyForcedDeactivated = 108,
+    InvalidCoordinates = 109,
+    PropertyInactive = 110,
+    BatchTooLarge = 111,
+    InvalidFloorArea = 112,
+    InvalidMaxStayRange = 113,
+    NoPendingAdminTransfer = 114,
+    AdminTransferExpired = 115,
+    InvalidListingFee = 116,
+    SecurityDepositTooLow = 117,
+    SecurityDepositTooHigh = 118,
+    InvalidDepositBounds = 119,
+    PropertyAlreadyExists = 120,
+}
+
+const DEFAULT_MIN_STAY_FLOOR_DAYS: u32 = 30;
+const MAX_STAY_CEILING_DAYS: u32 = 3650;
+
+const MAX_FILTER_RESULTS: u32 = 50;
+const MAX_LOCATION_RESULTS: u32 = 30;
+const MAX_LOCATION_LEN: usize = 200;
+const MAX_FLOOR_AREA_SQFT: u32 = 100_000;
+
+// Coordinates are stored in microdegrees (37.774929 -> 37_774_929).
+const MAX_LATITUDE: i64 = 90_000_000;
+const MAX_LONGITUDE: i64 = 180_000_000;
+const MAX_PAGE_SIZE: u32 = 30;
+const MAX_BULK_UPDATE: u32 = 10;
+const MAX_AMENITIES: u32 = 20;
+const MAX_AMENITY_LEN: u32 = 50;
+const MAX_PRICE_HISTORY: u32 = 50;
+const BPS_DENOMINATOR: u32 = 10_000;
+// Security deposit bounds relative to monthly rent: 50% .. 3x by default.
+const DEFAULT_MIN_DEPOSIT_RATIO_BPS: u32 = 5_000;
+const DEFAULT_MAX_DEPOSIT_MULTIPLIER: u32 = 3;
+const ADMIN_TRANSFER_WINDOW_SECONDS: u64 = 7 * 24 * 60 * 60;
+// 100 BRIQ (7 decimals).
+const DEFAULT_LISTING_FEE: i128 = 100 * 10_000_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PropertyType {
+    Apartment,
+    House,
+    Condo,
+    Studio,
+    Commercial,
+    Other,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PetPolicy {
+    NoPets,
+    CatsOnly,
+    SmallDogsOnly,
+    AllPets,
+    NegotiableCase,
 }
 
 #[contracttype]
@@ -35,12 +162,57 @@ pub struct Property {
     pub min_stay_days: u32,
     pub max_stay_days: u32,
     pub image_url: String,
+    pub amenities: Vec<String>,
+    pub property_type: PropertyType,
+    pub latitude: i64,
+    pub longitude: i64,
+    pub floor_area_sqft: u32,
+    pub furnished: bool,
+    pub parking_spaces: u32,
+    pub pet_policy: PetPolicy,
+    pub is_verified: bool,
     pub is_available: bool,
     pub is_active: bool,
     pub created_at: u64,
     pub updated_at: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PropertyStats {
+    pub total_agreements: u32,
+    pub active_agreements: u32,
+    pub completed_agreements: u32,
+    pub total_rent_collected: i128,
+}
+
+/// Optional listing attributes passed to `create_property` / `update_property`.
+/// Grouped into one argument because contract functions take at most 10 parameters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PropertyDetails {
+    pub image_url: String,
+    pub amenities: Vec<String>,
+    pub property_type: PropertyType,
+    pub latitude: i64,
+    pub longitude: i64,
+    /// 0 means unknown.
+    pub floor_area_sqft: u32,
+    pub furnished: bool,
+    // Contract types have no u8; u32 is the smallest unsigned integer available.
+    pub parking_spaces: u32,
+    pub pet_policy: PetPolicy,
+}
+
+/// A superseded price: what the property cost until `changed_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceChange {
+    pub price: i128,
+    pub security_deposit: i128,
+    pub changed_at: u64,
+}
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -48,7 +220,19 @@ pub enum DataKey {
     Property(BytesN<32>),
     PropertyList,
     OwnerIndex(Address),
+    ByType(PropertyType),
+    DeactivationReason(BytesN<32>),
+    ViewCount(BytesN<32>),
+    FeaturedUntil(BytesN<32>),
     RentalContract,
+    MinStayFloor,
+    PendingAdmin,
+    AdminTransferExpiry,
+    ListingFeeAmount,
+    BriqTokenContract,
+    PriceHistory(BytesN<32>),
+    MinDepositRatioBps,
+    MaxDepositMultiplier,
 }
 
 #[contract]
@@ -64,6 +248,9 @@ impl PropertyRegistry {
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Paused, &false);
+        env.storage()
+            .instance()
+            .set(&DataKey::MinStayFloor, &DEFAULT_MIN_STAY_FLOOR_DAYS);
         env.storage()
             .persistent()
             .set(&DataKey::PropertyList, &Vec::<BytesN<32>>::new(&env));
@@ -92,6 +279,78 @@ impl PropertyRegistry {
             .publish((Symbol::new(&env, "Unpaused"),), env.ledger().timestamp());
     }
 
+    /// Starts a two-step admin handover. The proposed admin has 7 days to
+    /// call `accept_admin_transfer`.
+    pub fn propose_admin_transfer(env: Env, current_admin: Address, proposed_admin: Address) {
+        if current_admin != Self::require_admin(&env) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        current_admin.require_auth();
+
+        let expiry = env.ledger().timestamp() + ADMIN_TRANSFER_WINDOW_SECONDS;
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &proposed_admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::AdminTransferExpiry, &expiry);
+
+        env.events().publish(
+            (Symbol::new(&env, "AdminTransferProposed"),),
+            (current_admin, proposed_admin, expiry),
+        );
+    }
+
+    pub fn accept_admin_transfer(env: Env, proposed_admin: Address) {
+        proposed_admin.require_auth();
+
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoPendingAdminTransfer));
+        if pending != proposed_admin {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        let expiry: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AdminTransferExpiry)
+            .unwrap_or(0);
+        if env.ledger().timestamp() > expiry {
+            panic_with_error!(&env, Error::AdminTransferExpired);
+        }
+
+        let previous_admin = Self::require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::Admin, &proposed_admin);
+        Self::clear_pending_admin(&env);
+
+        env.events().publish(
+            (Symbol::new(&env, "AdminTransferred"),),
+            (previous_admin, proposed_admin),
+        );
+    }
+
+    pub fn cancel_admin_transfer(env: Env, current_admin: Address) {
+        if current_admin != Self::require_admin(&env) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        current_admin.require_auth();
+
+        if !env.storage().instance().has(&DataKey::PendingAdmin) {
+            panic_with_error!(&env, Error::NoPendingAdminTransfer);
+        }
+        Self::clear_pending_admin(&env);
+
+        env.events().publish(
+            (Symbol::new(&env, "AdminTransferCancelled"),),
+            current_admin,
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn create_property(
         env: Env,
         owner: Address,
@@ -103,7 +362,7 @@ impl PropertyRegistry {
         security_deposit: i128,
         min_stay_days: u32,
         max_stay_days: u32,
-        image_url: String,
+        details: PropertyDetails,
     ) -> BytesN<32> {
         Self::check_not_paused(&env);
         owner.require_auth();
@@ -118,6 +377,18 @@ impl PropertyRegistry {
             min_stay_days,
             max_stay_days,
         );
+        Self::validate_details(&env, &details);
+
+        // Re-creating an existing ID would reset its state, including an admin ban.
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Property(property_id.clone()))
+        {
+            panic_with_error!(&env, Error::PropertyAlreadyExists);
+        }
+
+        Self::maybe_charge_listing_fee(&env, &owner, &property_id);
 
         let id = property_id;
         let now = env.ledger().timestamp();
@@ -132,7 +403,16 @@ impl PropertyRegistry {
             security_deposit,
             min_stay_days,
             max_stay_days,
-            image_url,
+            image_url: details.image_url,
+            amenities: details.amenities,
+            property_type: details.property_type,
+            latitude: details.latitude,
+            longitude: details.longitude,
+            floor_area_sqft: details.floor_area_sqft,
+            furnished: details.furnished,
+            parking_spaces: details.parking_spaces,
+            pet_policy: details.pet_policy,
+            is_verified: false,
             is_available: true,
             is_active: true,
             created_at: now,
@@ -155,14 +435,31 @@ impl PropertyRegistry {
             .persistent()
             .set(&DataKey::OwnerIndex(owner.clone()), &owner_list);
 
+        let mut type_list = Self::type_index(&env, &property.property_type);
+        type_list.push_back(id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::ByType(property.property_type.clone()), &type_list);
+
         env.events().publish(
             (Symbol::new(&env, "PropertyCreated"),),
-            (id.clone(), owner, price_per_month, security_deposit),
+            (
+                id.clone(),
+                owner,
+                price_per_month,
+                security_deposit,
+                property.property_type,
+                property.floor_area_sqft,
+                property.furnished,
+                property.parking_spaces,
+                property.pet_policy,
+            ),
         );
 
         id
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update_property(
         env: Env,
         owner: Address,
@@ -174,7 +471,7 @@ impl PropertyRegistry {
         security_deposit: i128,
         min_stay_days: u32,
         max_stay_days: u32,
-        image_url: String,
+        details: PropertyDetails,
     ) {
         Self::check_not_paused(&env);
 
@@ -194,6 +491,13 @@ impl PropertyRegistry {
             min_stay_days,
             max_stay_days,
         );
+        Self::validate_details(&env, &details);
+
+        if property.price_per_month != price_per_month
+            || property.security_deposit != security_deposit
+        {
+            Self::record_price_change(&env, &property);
+        }
 
         property.title = title;
         property.description = description;
@@ -202,7 +506,23 @@ impl PropertyRegistry {
         property.security_deposit = security_deposit;
         property.min_stay_days = min_stay_days;
         property.max_stay_days = max_stay_days;
-        property.image_url = image_url;
+        property.image_url = details.image_url;
+        property.amenities = details.amenities;
+        property.latitude = details.latitude;
+        property.longitude = details.longitude;
+        property.floor_area_sqft = details.floor_area_sqft;
+        property.furnished = details.furnished;
+        property.parking_spaces = details.parking_spaces;
+        property.pet_policy = details.pet_policy;
+        if property.property_type != details.property_type {
+            Self::reindex_type(
+                &env,
+                &property_id,
+                &property.property_type,
+                &details.property_type,
+            );
+            property.property_type = details.property_type;
+        }
         property.updated_at = env.ledger().timestamp();
 
         env.storage()
@@ -211,7 +531,7 @@ impl PropertyRegistry {
 
         env.events().publish(
             (Symbol::new(&env, "PropertyUpdated"),),
-            (property_id, property.updated_at),
+            (property_id, property.updated_at, property.pet_policy),
         );
     }
 
@@ -237,6 +557,227 @@ impl PropertyRegistry {
         );
     }
 
+    /// Hands a listing over to `new_owner`. Existing rental agreements keep their
+    /// original landlord until `update_agreement_landlord` is called on RentalAgreement.
+    pub fn transfer_property_ownership(
+        env: Env,
+        current_owner: Address,
+        property_id: BytesN<32>,
+        new_owner: Address,
+    ) {
+        Self::check_not_paused(&env);
+
+        let mut property = Self::get_property(env.clone(), property_id.clone());
+        current_owner.require_auth();
+        if current_owner != property.owner {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        if !property.is_active {
+            panic_with_error!(&env, Error::PropertyInactive);
+        }
+
+        property.owner = new_owner.clone();
+        property.updated_at = env.ledger().timestamp();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Property(property_id.clone()), &property);
+
+        let mut old_list = Self::owner_index(&env, &current_owner);
+        if let Some(i) = old_list.first_index_of(&property_id) {
+            old_list.remove(i);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::OwnerIndex(current_owner.clone()), &old_list);
+
+        let mut new_list = Self::owner_index(&env, &new_owner);
+        new_list.push_back(property_id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::OwnerIndex(new_owner.clone()), &new_list);
+
+        env.events().publish(
+            (Symbol::new(&env, "PropertyOwnershipTransferred"),),
+            (property_id, current_owner, new_owner, property.updated_at),
+        );
+    }
+
+    // Verification is a badge, not an edit, so `updated_at` is left untouched.
+    pub fn verify_property(env: Env, property_id: BytesN<32>) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let mut property = Self::get_property(env.clone(), property_id.clone());
+        if property.owner == admin {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+
+        property.is_verified = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Property(property_id.clone()), &property);
+
+        env.events().publish(
+            (Symbol::new(&env, "PropertyVerified"),),
+            (property_id, env.ledger().timestamp()),
+        );
+    }
+
+    pub fn unverify_property(env: Env, property_id: BytesN<32>) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let mut property = Self::get_property(env.clone(), property_id.clone());
+        property.is_verified = false;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Property(property_id.clone()), &property);
+
+        env.events().publish(
+            (Symbol::new(&env, "PropertyUnverified"),),
+            (property_id, env.ledger().timestamp()),
+        );
+    }
+
+    /// Previous prices, oldest first. Keeps the last 50 changes.
+    pub fn get_price_history(env: Env, property_id: BytesN<32>) -> Vec<PriceChange> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PriceHistory(property_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_current_price(env: Env, property_id: BytesN<32>) -> i128 {
+        Self::get_property(env, property_id).price_per_month
+    }
+
+    /// Sets availability on up to 10 properties, all owned by `owner`, in one call.
+    pub fn bulk_set_availability(
+        env: Env,
+        owner: Address,
+        property_ids: Vec<BytesN<32>>,
+        is_available: bool,
+    ) {
+        Self::check_not_paused(&env);
+
+        owner.require_auth();
+        if property_ids.len() > MAX_BULK_UPDATE {
+            panic_with_error!(&env, Error::BatchTooLarge);
+        }
+
+        let now = env.ledger().timestamp();
+        for property_id in property_ids.iter() {
+            let mut property = Self::get_property(env.clone(), property_id.clone());
+            if owner != property.owner {
+                panic_with_error!(&env, Error::Unauthorized);
+            }
+
+            property.is_available = is_available;
+            property.updated_at = now;
+
+            env.storage()
+                .persistent()
+                .set(&DataKey::Property(property_id), &property);
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "BulkAvailabilityChanged"),),
+            (owner, property_ids.len(), is_available),
+        );
+    }
+
+    /// Promotes a listing until `featured_until` (payment is handled off-chain for
+    /// now). Passing a past timestamp effectively un-features it.
+    pub fn set_featured(env: Env, property_id: BytesN<32>, featured_until: u64) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        // Panics if the property doesn't exist.
+        Self::get_property(env.clone(), property_id.clone());
+
+        env.storage().persistent().set(
+            &DataKey::FeaturedUntil(property_id.clone()),
+            &featured_until,
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "PropertyFeatured"),),
+            (property_id, featured_until),
+        );
+    }
+
+    pub fn is_featured(env: Env, property_id: BytesN<32>) -> bool {
+        Self::featured_now(&env, &property_id)
+    }
+
+    /// Platform-wide minimum for `min_stay_days` on new and updated listings.
+    pub fn set_min_stay_floor(env: Env, floor_days: u32) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        if floor_days == 0 || floor_days > MAX_STAY_CEILING_DAYS {
+            panic_with_error!(&env, Error::InvalidMinStay);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MinStayFloor, &floor_days);
+
+        env.events().publish(
+            (Symbol::new(&env, "MinStayFloorSet"),),
+            (floor_days, env.ledger().timestamp()),
+        );
+    }
+
+    pub fn get_min_stay_floor(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinStayFloor)
+            .unwrap_or(DEFAULT_MIN_STAY_FLOOR_DAYS)
+    }
+
+    /// Minimum security deposit as a share of monthly rent, in basis points.
+    pub fn set_min_deposit_ratio(env: Env, ratio_bps: u32) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        let max_bps = Self::get_max_deposit_multiplier(env.clone()).saturating_mul(BPS_DENOMINATOR);
+        if ratio_bps > max_bps {
+            panic_with_error!(&env, Error::InvalidDepositBounds);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MinDepositRatioBps, &ratio_bps);
+    }
+
+    pub fn get_min_deposit_ratio(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinDepositRatioBps)
+            .unwrap_or(DEFAULT_MIN_DEPOSIT_RATIO_BPS)
+    }
+
+    /// Maximum security deposit as a multiple of monthly rent.
+    pub fn set_max_deposit_multiplier(env: Env, multiplier: u32) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        if multiplier == 0
+            || multiplier.saturating_mul(BPS_DENOMINATOR) < Self::get_min_deposit_ratio(env.clone())
+        {
+            panic_with_error!(&env, Error::InvalidDepositBounds);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxDepositMultiplier, &multiplier);
+    }
+
+    pub fn get_max_deposit_multiplier(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxDepositMultiplier)
+            .unwrap_or(DEFAULT_MAX_DEPOSIT_MULTIPLIER)
+    }
+
     pub fn set_rental_contract(env: Env, contract: Address) {
         let admin = Self::require_admin(&env);
         admin.require_auth();
@@ -245,6 +786,33 @@ impl PropertyRegistry {
             .set(&DataKey::RentalContract, &contract);
     }
 
+    // Listing fees are only charged once a BRIQ token contract is set.
+    pub fn set_briq_token_contract(env: Env, address: Address) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::BriqTokenContract, &address);
+    }
+
+    pub fn set_listing_fee(env: Env, amount: i128) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        if amount < 0 {
+            panic_with_error!(&env, Error::InvalidListingFee);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ListingFeeAmount, &amount);
+    }
+
+    pub fn get_listing_fee(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ListingFeeAmount)
+            .unwrap_or(DEFAULT_LISTING_FEE)
+    }
+
     pub fn update_availability_by_contract(env: Env, property_id: BytesN<32>, is_available: bool) {
         Self::check_not_paused(&env);
 
@@ -294,12 +862,158 @@ impl PropertyRegistry {
         );
     }
 
-    pub fn get_property(env: Env, property_id: BytesN<32>) -> Property {
-        env.storage()
+    pub fn reactivate_property(
+        env: Env,
+        owner: Address,
+        property_id: BytesN<32>,
+        make_available: bool,
+    ) {
+        Self::check_not_paused(&env);
+
+        let mut property = Self::get_property(env.clone(), property_id.clone());
+        owner.require_auth();
+        if owner != property.owner {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        if env
+            .storage()
             .persistent()
-            .get(&DataKey::Property(property_id))
-            .unwrap_or_else(|| panic_with_error!(&env, Error::PropertyNotFound))
-    }
+            .has(&DataKey::DeactivationReason(property_id.clone()))
+        {
+            panic_with_error!(&env, Error::PropertyForcedDeactivated);
+        }
+
+        property.is_active = true;
+        if make_available {
+            property.is_available = true;
+        }
+        property.updated_at = env.ledger().timestamp();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Property(property_id.clone()), &property);
+
+        env.events().publish(
+            (Symbol::new(&env, "PropertyReactivated"),),
+            (property_id, property.is_available, property.updated_at),
+        );
+    }
+
+    /// Admin takedown for fraudulent or abusive listings. Only
+    /// `admin_reactivate_property` can bring the listing back.
+    pub fn admin_force_deactivate(env: Env, property_id: BytesN<32>, reason: String) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let mut property = Self::get_property(env.clone(), property_id.clone());
+        property.is_active = false;
+        property.is_available = false;
+        property.updated_at = env.ledger().timestamp();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Property(property_id.clone()), &property);
+        env.storage()
+            .persistent()
+            .set(&DataKey::DeactivationReason(property_id.clone()), &reason);
+
+        env.events().publish(
+            (Symbol::new(&env, "PropertyForceDeactivated"),),
+            (property_id, reason, property.updated_at),
+        );
+    }
+
+    pub fn admin_reactivate_property(env: Env, property_id: BytesN<32>) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let mut property = Self::get_property(env.clone(), property_id.clone());
+        property.is_active = true;
+        property.updated_at = env.ledger().timestamp();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Property(property_id.clone()), &property);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::DeactivationReason(property_id.clone()));
+
+        env.events().publish(
+            (Symbol::new(&env, "PropertyAdminReactivated"),),
+            (property_id, property.updated_at),
+        );
+    }
+
+    /// Reason recorded by `admin_force_deactivate`; empty if the property isn't banned.
+    pub fn get_deactivation_reason(env: Env, property_id: BytesN<32>) -> String {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DeactivationReason(property_id))
+            .unwrap_or(String::from_str(&env, ""))
+    }
+
+    /// Bumps the listing's view counter. Anyone can call this, so the count is
+    /// trivially gameable and should be treated as informational only.
+    /// Inactive listings are counted too.
+    pub fn record_property_view(env: Env, property_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        // Panics if the property doesn't exist.
+        Self::get_property(env.clone(), property_id.clone());
+
+        let count =
+            Self::get_property_view_count(env.clone(), property_id.clone()).saturating_add(1);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ViewCount(property_id.clone()), &count);
+
+        env.events()
+            .publish((Symbol::new(&env, "PropertyViewed"),), (property_id, count));
+    }
+
+    pub fn get_property_view_count(env: Env, property_id: BytesN<32>) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ViewCount(property_id))
+            .unwrap_or(0)
+    }
+
+    pub fn get_property(env: Env, property_id: BytesN<32>) -> Property {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Property(property_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::PropertyNotFound))
+    }
+
+    /// Aggregates the property's agreements from the registered rental contract.
+    /// Recomputed on every call; there is no cached copy.
+    pub fn get_property_stats(env: Env, property_id: BytesN<32>) -> PropertyStats {
+        let rental_contract: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::RentalContract)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Unauthorized));
+        let agreements = RentalAgreementClient::new(&env, &rental_contract)
+            .get_agreements_by_property(&property_id);
+
+        let mut stats = PropertyStats {
+            total_agreements: agreements.len(),
+            active_agreements: 0,
+            completed_agreements: 0,
+            total_rent_collected: 0,
+        };
+        for a in agreements.iter() {
+            match a.status {
+                AgreementStatus::Active => stats.active_agreements += 1,
+                AgreementStatus::Completed => stats.completed_agreements += 1,
+                _ => {}
+            }
+            stats.total_rent_collected =
+                stats.total_rent_collected.saturating_add(a.total_rent_paid);
+        }
+
+        stats
+    }
 
     pub fn get_properties_by_owner(env: Env, owner: Address) -> Vec<Property> {
         let ids = Self::owner_index(&env, &owner);
@@ -337,6 +1051,230 @@ impl PropertyRegistry {
         out
     }
 
+    /// Available listings within the `[offset, offset + limit)` window of
+    /// `PropertyList`; inactive/unavailable entries in the window are skipped, so a
+    /// page may be short. `limit` is capped at 30.
+    /// (Named `_paged` because contract function names are limited to 32 chars.)
+    pub fn get_available_properties_paged(env: Env, offset: u32, limit: u32) -> Vec<Property> {
+        let list = Self::property_list(&env);
+        let start = offset.min(list.len());
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(list.len());
+        let mut out = Vec::<Property>::new(&env);
+
+        for id in list.slice(start..end).iter() {
+            if let Some(p) = env
+                .storage()
+                .persistent()
+                .get::<_, Property>(&DataKey::Property(id))
+            {
+                if p.is_active && p.is_available {
+                    out.push_back(p);
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Total number of listings ever created, including inactive ones.
+    pub fn get_property_list_count(env: Env) -> u32 {
+        Self::property_list(&env).len()
+    }
+
+    /// Same as `get_property_list_count`; kept alongside the other counters.
+    pub fn get_total_property_count(env: Env) -> u32 {
+        Self::property_list(&env).len()
+    }
+
+    pub fn get_available_property_count(env: Env) -> u32 {
+        let ids = Self::property_list(&env);
+        let mut count = 0u32;
+
+        for id in ids.iter() {
+            if let Some(p) = env
+                .storage()
+                .persistent()
+                .get::<_, Property>(&DataKey::Property(id))
+            {
+                if p.is_active && p.is_available {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    pub fn get_properties_by_owner_count(env: Env, owner: Address) -> u32 {
+        Self::owner_index(&env, &owner).len()
+    }
+
+    /// Active, available listings priced within `[min_price, max_price]`.
+    /// Returns at most 50 results; larger markets should use
+    /// `get_available_properties_paged` and filter client-side.
+    pub fn get_properties_by_price_range(
+        env: Env,
+        min_price: i128,
+        max_price: i128,
+    ) -> Vec<Property> {
+        if min_price <= 0 || max_price <= 0 || min_price > max_price {
+            panic_with_error!(&env, Error::InvalidPrice);
+        }
+
+        Self::filter_available(&env, MAX_FILTER_RESULTS, |p| {
+            p.price_per_month >= min_price && p.price_per_month <= max_price
+        })
+    }
+
+    pub fn get_verified_properties(env: Env) -> Vec<Property> {
+        let ids = Self::property_list(&env);
+        let mut out = Vec::<Property>::new(&env);
+
+        for id in ids.iter() {
+            if let Some(p) = env
+                .storage()
+                .persistent()
+                .get::<_, Property>(&DataKey::Property(id))
+            {
+                if p.is_verified {
+                    out.push_back(p);
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Active, available listings of the given type, read from the `ByType` index.
+    pub fn get_properties_by_type(env: Env, property_type: PropertyType) -> Vec<Property> {
+        let ids = Self::type_index(&env, &property_type);
+        let mut out = Vec::<Property>::new(&env);
+
+        for id in ids.iter() {
+            if let Some(p) = env
+                .storage()
+                .persistent()
+                .get::<_, Property>(&DataKey::Property(id))
+            {
+                if p.is_active && p.is_available {
+                    out.push_back(p);
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Active, available listings whose location starts with `prefix`
+    /// (case-sensitive, byte-wise). Linear scan over `PropertyList`, capped at 30.
+    /// (Abbreviated because contract function names are limited to 32 chars.)
+    pub fn get_properties_by_loc_prefix(env: Env, prefix: String) -> Vec<Property> {
+        let prefix_len = prefix.len() as usize;
+        if prefix_len == 0 || prefix_len > MAX_LOCATION_LEN {
+            panic_with_error!(&env, Error::InvalidLocation);
+        }
+        let mut prefix_buf = [0u8; MAX_LOCATION_LEN];
+        prefix.copy_into_slice(&mut prefix_buf[..prefix_len]);
+
+        Self::filter_available(&env, MAX_LOCATION_RESULTS, |p| {
+            let loc_len = p.location.len() as usize;
+            if loc_len < prefix_len || loc_len > MAX_LOCATION_LEN {
+                return false;
+            }
+            let mut loc_buf = [0u8; MAX_LOCATION_LEN];
+            p.location.copy_into_slice(&mut loc_buf[..loc_len]);
+            loc_buf[..prefix_len] == prefix_buf[..prefix_len]
+        })
+    }
+
+    /// Active, available listings inside the given box (inclusive, microdegrees),
+    /// capped at 50. Meant as a verifiable fallback; real map search belongs
+    /// to off-chain indexers.
+    pub fn get_properties_in_bbox(
+        env: Env,
+        lat_min: i64,
+        lat_max: i64,
+        lon_min: i64,
+        lon_max: i64,
+    ) -> Vec<Property> {
+        Self::validate_coordinates(&env, lat_min, lon_min);
+        Self::validate_coordinates(&env, lat_max, lon_max);
+        if lat_min > lat_max || lon_min > lon_max {
+            panic_with_error!(&env, Error::InvalidCoordinates);
+        }
+
+        Self::filter_available(&env, MAX_FILTER_RESULTS, |p| {
+            (lat_min..=lat_max).contains(&p.latitude) && (lon_min..=lon_max).contains(&p.longitude)
+        })
+    }
+
+    /// Active, available listings with at least `min_sqft` of floor area (listings
+    /// with unknown area are excluded for any non-zero minimum). Capped at 50.
+    pub fn get_properties_min_area(env: Env, min_sqft: u32) -> Vec<Property> {
+        Self::filter_available(&env, MAX_FILTER_RESULTS, |p| p.floor_area_sqft >= min_sqft)
+    }
+
+    pub fn get_properties_by_pet_policy(env: Env, policy: PetPolicy) -> Vec<Property> {
+        Self::filter_available(&env, MAX_FILTER_RESULTS, |p| p.pet_policy == policy)
+    }
+
+    /// Active, available listings whose featured window is still open, capped at 50.
+    pub fn get_featured_properties(env: Env) -> Vec<Property> {
+        Self::filter_available(&env, MAX_FILTER_RESULTS, |p| {
+            Self::featured_now(&env, &p.id)
+        })
+    }
+
+    /// Active, available listings offering `amenity` (exact match), capped at 50.
+    pub fn get_properties_with_amenity(env: Env, amenity: String) -> Vec<Property> {
+        Self::filter_available(&env, MAX_FILTER_RESULTS, |p| p.amenities.contains(&amenity))
+    }
+
+    pub fn get_properties_by_max_price(env: Env, max_price: i128) -> Vec<Property> {
+        Self::get_properties_by_price_range(env, 1, max_price)
+    }
+
+    fn record_price_change(env: &Env, property: &Property) {
+        let mut history = Self::get_price_history(env.clone(), property.id.clone());
+        if history.len() >= MAX_PRICE_HISTORY {
+            history.pop_front();
+        }
+        history.push_back(PriceChange {
+            price: property.price_per_month,
+            security_deposit: property.security_deposit,
+            changed_at: env.ledger().timestamp(),
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::PriceHistory(property.id.clone()), &history);
+    }
+
+    fn maybe_charge_listing_fee(env: &Env, owner: &Address, property_id: &BytesN<32>) {
+        let Some(token_addr) = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::BriqTokenContract)
+        else {
+            return;
+        };
+        let fee = Self::get_listing_fee(env.clone());
+        if fee <= 0 {
+            return;
+        }
+
+        BriqTokenClient::new(env, &token_addr).burn_for_service(
+            owner,
+            &fee,
+            &Symbol::new(env, "property_listing"),
+        );
+        env.events().publish(
+            (Symbol::new(env, "ListingFeePaid"),),
+            (property_id.clone(), owner.clone(), fee),
+        );
+    }
+
     fn check_not_paused(env: &Env) {
         let paused: bool = env
             .storage()
@@ -348,6 +1286,13 @@ impl PropertyRegistry {
         }
     }
 
+    fn clear_pending_admin(env: &Env) {
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        env.storage()
+            .instance()
+            .remove(&DataKey::AdminTransferExpiry);
+    }
+
     fn require_admin(env: &Env) -> Address {
         env.storage()
             .instance()
@@ -362,6 +1307,30 @@ impl PropertyRegistry {
             .unwrap_or(Vec::<BytesN<32>>::new(env))
     }
 
+    // Scans `PropertyList` for active, available properties matching `pred`,
+    // stopping once `max` results have been collected.
+    fn filter_available(env: &Env, max: u32, pred: impl Fn(&Property) -> bool) -> Vec<Property> {
+        let ids = Self::property_list(env);
+        let mut out = Vec::<Property>::new(env);
+
+        for id in ids.iter() {
+            if out.len() >= max {
+                break;
+            }
+            if let Some(p) = env
+                .storage()
+                .persistent()
+                .get::<_, Property>(&DataKey::Property(id))
+            {
+                if p.is_active && p.is_available && pred(&p) {
+                    out.push_back(p);
+                }
+            }
+        }
+
+        out
+    }
+
     fn owner_index(env: &Env, owner: &Address) -> Vec<BytesN<32>> {
         env.storage()
             .persistent()
@@ -369,6 +1338,47 @@ impl PropertyRegistry {
             .unwrap_or(Vec::<BytesN<32>>::new(env))
     }
 
+    fn validate_coordinates(env: &Env, latitude: i64, longitude: i64) {
+        if !(-MAX_LATITUDE..=MAX_LATITUDE).contains(&latitude)
+            || !(-MAX_LONGITUDE..=MAX_LONGITUDE).contains(&longitude)
+        {
+            panic_with_error!(env, Error::InvalidCoordinates);
+        }
+    }
+
+    fn featured_now(env: &Env, property_id: &BytesN<32>) -> bool {
+        let featured_until: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::FeaturedUntil(property_id.clone()))
+            .unwrap_or(0);
+        featured_until > env.ledger().timestamp()
+    }
+
+    fn type_index(env: &Env, property_type: &PropertyType) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ByType(property_type.clone()))
+            .unwrap_or(Vec::<BytesN<32>>::new(env))
+    }
+
+    fn reindex_type(env: &Env, property_id: &BytesN<32>, old: &PropertyType, new: &PropertyType) {
+        let mut old_list = Self::type_index(env, old);
+        if let Some(i) = old_list.first_index_of(property_id) {
+            old_list.remove(i);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::ByType(old.clone()), &old_list);
+
+        let mut new_list = Self::type_index(env, new);
+        new_list.push_back(property_id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::ByType(new.clone()), &new_list);
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn validate_property_fields(
         env: &Env,
         title: &String,
@@ -389,7 +1399,7 @@ impl PropertyRegistry {
         }
 
         let loc_len = location.len();
-        if loc_len == 0 || loc_len > 200 {
+        if loc_len == 0 || loc_len as usize > MAX_LOCATION_LEN {
             panic_with_error!(env, Error::InvalidLocation);
         }
 
@@ -401,15 +1411,50 @@ impl PropertyRegistry {
             panic_with_error!(env, Error::InvalidPrice);
         }
 
-        if min_stay_days < 30 {
+        let min_deposit = price_per_month
+            .saturating_mul(Self::get_min_deposit_ratio(env.clone()) as i128)
+            / BPS_DENOMINATOR as i128;
+        if security_deposit < min_deposit {
+            panic_with_error!(env, Error::SecurityDepositTooLow);
+        }
+        let max_deposit =
+            price_per_month.saturating_mul(Self::get_max_deposit_multiplier(env.clone()) as i128);
+        if security_deposit > max_deposit {
+            panic_with_error!(env, Error::SecurityDepositTooHigh);
+        }
+
+        if min_stay_days < Self::get_min_stay_floor(env.clone()) {
             panic_with_error!(env, Error::InvalidMinStay);
         }
 
         if max_stay_days < min_stay_days {
             panic_with_error!(env, Error::InvalidMaxStay);
         }
+
+        if max_stay_days > MAX_STAY_CEILING_DAYS {
+            panic_with_error!(env, Error::InvalidMaxStayRange);
+        }
+    }
+
+    fn validate_details(env: &Env, details: &PropertyDetails) {
+        Self::validate_coordinates(env, details.latitude, details.longitude);
+
+        if details.floor_area_sqft >= MAX_FLOOR_AREA_SQFT {
+            panic_with_error!(env, Error::InvalidFloorArea);
+        }
+
+        if details.amenities.len() > MAX_AMENITIES {
+            panic_with_error!(env, Error::InvalidAmenity);
+        }
+        for amenity in details.amenities.iter() {
+            let len = amenity.len();
+            if len == 0 || len > MAX_AMENITY_LEN {
+                panic_with_error!(env, Error::InvalidAmenity);
+            }
+        }
     }
 
+    #[allow(dead_code)]
     fn new_id(env: &Env) -> BytesN<32> {
         // Random, collision-resistant ID generation; suitable for testnet MVP.
         // On-chain IDs are returned as BytesN<32>.
@@ -418,9 +1463,10 @@ impl PropertyRegistry {
 }
 
 #[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping)]
 mod tests {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::testutils::{Address as _, Ledger};
 
     #[test]
     fn initialize_and_create_property() {
@@ -428,18 +1474,28 @@ mod tests {
         let contract_id = env.register_contract(None, PropertyRegistry);
         let client = PropertyRegistryClient::new(&env, &contract_id);
 
+        env.mock_all_auths();
         let admin = Address::generate(&env);
         client.initialize(&admin);
 
         let owner = Address::generate(&env);
         // Generate property ID for test
-        let property_id: BytesN<32> = env.prng().gen();
+        let property_id = BytesN::from_array(&env, &[1; 32]);
         let title = String::from_str(&env, "Cozy Apartment");
         let desc = String::from_str(&env, "A nice place to stay");
         let loc = String::from_str(&env, "NYC");
-        let img = String::from_str(&env, "");
+        let details = PropertyDetails {
+            image_url: String::from_str(&env, ""),
+            amenities: Vec::new(&env),
+            property_type: PropertyType::Apartment,
+            latitude: 40_712_776,
+            longitude: -74_005_974,
+            floor_area_sqft: 850,
+            furnished: true,
+            parking_spaces: 1,
+            pet_policy: PetPolicy::CatsOnly,
+        };
 
-        env.mock_all_auths();
         let id = client.create_property(
             &owner,
             &property_id,
@@ -450,7 +1506,7 @@ mod tests {
             &500_0000,
             &30,
             &365,
-            &img,
+            &details,
         );
 
         let p = client.get_property(&id);
@@ -459,4 +1515,854 @@ mod tests {
         assert!(p.is_available);
         assert!(p.is_active);
     }
+
+    #[test]
+    fn admin_transfer_expires_after_window() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, PropertyRegistry);
+        let client = PropertyRegistryClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let next_admin = Address::generate(&env);
+        client.propose_admin_transfer(&admin, &next_admin);
+        env.ledger()
+            .with_mut(|li| li.timestamp += ADMIN_TRANSFER_WINDOW_SECONDS + 1);
+        assert_eq!(
+            client.try_accept_admin_transfer(&next_admin),
+            Err(Ok(Error::AdminTransferExpired.into()))
+        );
+
+        client.propose_admin_transfer(&admin, &next_admin);
+        client.accept_admin_transfer(&next_admin);
+        assert_eq!(
+            client.try_cancel_admin_transfer(&admin),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+        assert_eq!(
+            client.try_cancel_admin_transfer(&next_admin),
+            Err(Ok(Error::NoPendingAdminTransfer.into()))
+        );
+    }
+
+    fn create_test_property(
+        env: &Env,
+        client: &PropertyRegistryClient,
+        owner: &Address,
+        seed: u8,
+    ) -> BytesN<32> {
+        let details = PropertyDetails {
+            image_url: String::from_str(env, ""),
+            amenities: Vec::new(env),
+            property_type: PropertyType::House,
+            latitude: 30_267_153,
+            longitude: -97_743_061,
+            floor_area_sqft: 0,
+            furnished: false,
+            parking_spaces: 0,
+            pet_policy: PetPolicy::NoPets,
+        };
+        client.create_property(
+            owner,
+            &BytesN::from_array(env, &[seed; 32]),
+            &String::from_str(env, "House"),
+            &String::from_str(env, ""),
+            &String::from_str(env, "Austin"),
+            &1_000_0000,
+            &500_0000,
+            &30,
+            &365,
+            &details,
+        )
+    }
+
+    #[test]
+    fn amenity_search() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, PropertyRegistry);
+        let client = PropertyRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let owner = Address::generate(&env);
+        let id = create_test_property(&env, &client, &owner, 1);
+        create_test_property(&env, &client, &owner, 2);
+
+        let p = client.get_property(&id);
+        let mut details = PropertyDetails {
+            image_url: p.image_url.clone(),
+            amenities: Vec::from_array(&env, [String::from_str(&env, "wifi")]),
+            property_type: p.property_type.clone(),
+            latitude: p.latitude,
+            longitude: p.longitude,
+            floor_area_sqft: p.floor_area_sqft,
+            furnished: p.furnished,
+            parking_spaces: p.parking_spaces,
+            pet_policy: p.pet_policy.clone(),
+        };
+        let update = |details: &PropertyDetails| {
+            client.try_update_property(
+                &owner,
+                &id,
+                &p.title,
+                &p.description,
+                &p.location,
+                &p.price_per_month,
+                &p.security_deposit,
+                &p.min_stay_days,
+                &p.max_stay_days,
+                details,
+            )
+        };
+        update(&details).unwrap().unwrap();
+
+        let with_wifi = client.get_properties_with_amenity(&String::from_str(&env, "wifi"));
+        assert_eq!(with_wifi.len(), 1);
+        assert_eq!(with_wifi.get(0).unwrap().id, id);
+        let with_gym = client.get_properties_with_amenity(&String::from_str(&env, "gym"));
+        assert_eq!(with_gym.len(), 0);
+
+        details.amenities = Vec::from_array(&env, [String::from_str(&env, "")]);
+        assert_eq!(update(&details), Err(Ok(Error::InvalidAmenity.into())));
+    }
+
+    fn details_of(p: &Property) -> PropertyDetails {
+        PropertyDetails {
+            image_url: p.image_url.clone(),
+            amenities: p.amenities.clone(),
+            property_type: p.property_type.clone(),
+            latitude: p.latitude,
+            longitude: p.longitude,
+            floor_area_sqft: p.floor_area_sqft,
+            furnished: p.furnished,
+            parking_spaces: p.parking_spaces,
+            pet_policy: p.pet_policy.clone(),
+        }
+    }
+
+    fn set_price(client: &PropertyRegistryClient, p: &Property, price: i128, deposit: i128) {
+        client.update_property(
+            &p.owner,
+            &p.id,
+            &p.title,
+            &p.description,
+            &p.location,
+            &price,
+            &deposit,
+            &p.min_stay_days,
+            &p.max_stay_days,
+            &details_of(p),
+        );
+    }
+
+    #[test]
+    fn price_history_records_changes_only() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, PropertyRegistry);
+        let client = PropertyRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let id = create_test_property(&env, &client, &Address::generate(&env), 1);
+        let p = client.get_property(&id);
+
+        set_price(&client, &p, p.price_per_month, p.security_deposit);
+        assert!(client.get_price_history(&id).is_empty());
+
+        env.ledger().set_timestamp(500);
+        set_price(&client, &p, 1_200_0000, 600_0000);
+        let history = client.get_price_history(&id);
+        assert_eq!(history.len(), 1);
+        assert_eq!(
+            history.get(0).unwrap(),
+            PriceChange {
+                price: 1_000_0000,
+                security_deposit: 500_0000,
+                changed_at: 500,
+            }
+        );
+        assert_eq!(client.get_current_price(&id), 1_200_0000);
+
+        // A deposit-only change is recorded too.
+        set_price(&client, &p, 1_200_0000, 700_0000);
+        assert_eq!(client.get_price_history(&id).len(), 2);
+    }
+
+    #[test]
+    fn price_history_keeps_last_fifty_changes() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, PropertyRegistry);
+        let client = PropertyRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let id = create_test_property(&env, &client, &Address::generate(&env), 1);
+        let p = client.get_property(&id);
+        for i in 1..=MAX_PRICE_HISTORY as i128 + 5 {
+            set_price(&client, &p, 1_000_0000 + i, 600_0000);
+        }
+
+        let history = client.get_price_history(&id);
+        assert_eq!(history.len(), MAX_PRICE_HISTORY);
+        // The first five entries (1_000_0000 .. 1_000_0004) were dropped.
+        assert_eq!(history.get(0).unwrap().price, 1_000_0005);
+        assert_eq!(history.last().unwrap().price, 1_000_0000 + 54);
+    }
+
+    #[test]
+    fn location_prefix_search() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, PropertyRegistry);
+        let client = PropertyRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let owner = Address::generate(&env);
+        create_test_property(&env, &client, &owner, 1);
+        let hidden = create_test_property(&env, &client, &owner, 2);
+        client.set_availability(&owner, &hidden, &false);
+
+        let austin = client.get_properties_by_loc_prefix(&String::from_str(&env, "Aus"));
+        assert_eq!(austin.len(), 1);
+        assert_eq!(
+            client
+                .get_properties_by_loc_prefix(&String::from_str(&env, "aus"))
+                .len(),
+            0
+        );
+        assert_eq!(
+            client
+                .get_properties_by_loc_prefix(&String::from_str(&env, "Austin, TX"))
+                .len(),
+            0
+        );
+        assert_eq!(
+            client.try_get_properties_by_loc_prefix(&String::from_str(&env, "")),
+            Err(Ok(Error::InvalidLocation.into()))
+        );
+    }
+
+    #[test]
+    fn force_deactivated_property_needs_admin_to_reactivate() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, PropertyRegistry);
+        let client = PropertyRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let owner = Address::generate(&env);
+        let id = create_test_property(&env, &client, &owner, 1);
+
+        client.deactivate_property(&owner, &id);
+        client.reactivate_property(&owner, &id, &true);
+        assert!(client.get_property(&id).is_available);
+
+        client.admin_force_deactivate(&id, &String::from_str(&env, "fraud"));
+        assert_eq!(
+            client.try_reactivate_property(&owner, &id, &true),
+            Err(Ok(Error::PropertyForcedDeactivated.into()))
+        );
+        // Re-listing under the same ID would wipe the ban.
+        let p = client.get_property(&id);
+        assert_eq!(
+            client.try_create_property(
+                &owner,
+                &id,
+                &p.title,
+                &p.description,
+                &p.location,
+                &p.price_per_month,
+                &p.security_deposit,
+                &p.min_stay_days,
+                &p.max_stay_days,
+                &PropertyDetails {
+                    image_url: p.image_url,
+                    amenities: p.amenities,
+                    property_type: p.property_type,
+                    latitude: p.latitude,
+                    longitude: p.longitude,
+                    floor_area_sqft: p.floor_area_sqft,
+                    furnished: p.furnished,
+                    parking_spaces: p.parking_spaces,
+                    pet_policy: p.pet_policy,
+                },
+            ),
+            Err(Ok(Error::PropertyAlreadyExists.into()))
+        );
+        assert_eq!(
+            client.get_deactivation_reason(&id),
+            String::from_str(&env, "fraud")
+        );
+
+        client.admin_reactivate_property(&id);
+        assert!(client.get_property(&id).is_active);
+        assert_eq!(
+            client.get_deactivation_reason(&id),
+            String::from_str(&env, "")
+        );
+    }
+
+    // Stands in for RentalAgreement's per-property agreement lookup.
+    #[contract]
+    struct MockRental;
+
+    #[contractimpl]
+    impl MockRental {
+        pub fn add_agreement(env: Env, agreement: RentalAgreement) {
+            let mut all: Vec<RentalAgreement> = env
+                .storage()
+                .instance()
+                .get(&0u32)
+                .unwrap_or(Vec::new(&env));
+            all.push_back(agreement);
+            env.storage().instance().set(&0u32, &all);
+        }
+
+        pub fn get_agreements_by_property(
+            env: Env,
+            property_id: BytesN<32>,
+        ) -> Vec<RentalAgreement> {
+            let all: Vec<RentalAgreement> = env
+                .storage()
+                .instance()
+                .get(&0u32)
+                .unwrap_or(Vec::new(&env));
+            let mut out = Vec::new(&env);
+            for a in all.iter() {
+                if a.property_id == property_id {
+                    out.push_back(a);
+                }
+            }
+            out
+        }
+    }
+
+    fn rental_agreement(
+        env: &Env,
+        property_id: &BytesN<32>,
+        status: AgreementStatus,
+        rent_paid: i128,
+    ) -> RentalAgreement {
+        RentalAgreement {
+            id: BytesN::from_array(env, &[rent_paid as u8; 32]),
+            property_id: property_id.clone(),
+            landlord: Address::generate(env),
+            tenant: Address::generate(env),
+            monthly_rent: 100,
+            security_deposit: 0,
+            start_date: 0,
+            end_date: 0,
+            status,
+            landlord_signed: true,
+            landlord_signed_at: 0,
+            tenant_signed: true,
+            tenant_signed_at: 0,
+            deposit_paid: true,
+            deposit_paid_at: 0,
+            total_rent_paid: rent_paid,
+            months_paid: 0,
+            created_at: 0,
+            completed_at: 0,
+            notice_period_days: 0,
+            notice_served_at: 0,
+            notice_served_by: None,
+            document_hash: BytesN::from_array(env, &[0; 32]),
+            co_signer: None,
+            subletting_allowed: false,
+            custom_clauses: Vec::new(env),
+        }
+    }
+
+    #[test]
+    fn property_stats_aggregate_agreements() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, PropertyRegistry);
+        let client = PropertyRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let property_id = create_test_property(&env, &client, &Address::generate(&env), 1);
+        let other_id = create_test_property(&env, &client, &Address::generate(&env), 2);
+        assert_eq!(
+            client.try_get_property_stats(&property_id),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+
+        let rental_id = env.register_contract(None, MockRental);
+        let rental = MockRentalClient::new(&env, &rental_id);
+        client.set_rental_contract(&rental_id);
+        rental.add_agreement(&rental_agreement(
+            &env,
+            &property_id,
+            AgreementStatus::Completed,
+            30,
+        ));
+        rental.add_agreement(&rental_agreement(
+            &env,
+            &property_id,
+            AgreementStatus::Active,
+            20,
+        ));
+        rental.add_agreement(&rental_agreement(
+            &env,
+            &property_id,
+            AgreementStatus::Cancelled,
+            0,
+        ));
+        rental.add_agreement(&rental_agreement(
+            &env,
+            &other_id,
+            AgreementStatus::Active,
+            50,
+        ));
+
+        let stats = client.get_property_stats(&property_id);
+        assert_eq!(stats.total_agreements, 3);
+        assert_eq!(stats.active_agreements, 1);
+        assert_eq!(stats.completed_agreements, 1);
+        assert_eq!(stats.total_rent_collected, 50);
+    }
+
+    fn setup(env: &Env) -> PropertyRegistryClient<'_> {
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, PropertyRegistry);
+        let client = PropertyRegistryClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env));
+        client
+    }
+
+    #[test]
+    fn price_range_filters() {
+        let env = Env::default();
+        let client = setup(&env);
+        let owner = Address::generate(&env);
+
+        create_test_property(&env, &client, &owner, 1);
+        let mid = create_test_property(&env, &client, &owner, 2);
+        set_price(&client, &client.get_property(&mid), 2_000_0000, 1_000_0000);
+        let high = create_test_property(&env, &client, &owner, 3);
+        set_price(&client, &client.get_property(&high), 3_000_0000, 1_500_0000);
+
+        assert_eq!(
+            client
+                .get_properties_by_price_range(&1_500_0000, &3_000_0000)
+                .len(),
+            2
+        );
+        assert_eq!(client.get_properties_by_max_price(&2_000_0000).len(), 2);
+
+        client.set_availability(&owner, &high, &false);
+        assert_eq!(
+            client
+                .get_properties_by_price_range(&1_500_0000, &3_000_0000)
+                .len(),
+            1
+        );
+
+        assert_eq!(
+            client.try_get_properties_by_price_range(&0, &100),
+            Err(Ok(Error::InvalidPrice.into()))
+        );
+        assert_eq!(
+            client.try_get_properties_by_price_range(&200, &100),
+            Err(Ok(Error::InvalidPrice.into()))
+        );
+    }
+
+    #[test]
+    fn available_properties_paged() {
+        let env = Env::default();
+        let client = setup(&env);
+        let owner = Address::generate(&env);
+
+        let mut ids = Vec::new(&env);
+        for seed in 0..(MAX_PAGE_SIZE as u8 + 2) {
+            ids.push_back(create_test_property(&env, &client, &owner, seed));
+        }
+        client.set_availability(&owner, &ids.get(1).unwrap(), &false);
+
+        // Unavailable entries inside the window are skipped, not backfilled.
+        let first = client.get_available_properties_paged(&0, &2);
+        assert_eq!(first.len(), 1);
+        assert_eq!(first.get(0).unwrap().id, ids.get(0).unwrap());
+
+        let second = client.get_available_properties_paged(&2, &2);
+        assert_eq!(second.get(0).unwrap().id, ids.get(2).unwrap());
+
+        assert_eq!(
+            client.get_available_properties_paged(&0, &100).len(),
+            MAX_PAGE_SIZE - 1
+        );
+        assert_eq!(client.get_available_properties_paged(&30, &100).len(), 2);
+        assert!(client.get_available_properties_paged(&100, &5).is_empty());
+    }
+
+    #[test]
+    fn properties_by_type_follow_updates() {
+        let env = Env::default();
+        let client = setup(&env);
+        let owner = Address::generate(&env);
+
+        create_test_property(&env, &client, &owner, 1);
+        let id = create_test_property(&env, &client, &owner, 2);
+        assert_eq!(client.get_properties_by_type(&PropertyType::House).len(), 2);
+
+        let p = client.get_property(&id);
+        let mut details = details_of(&p);
+        details.property_type = PropertyType::Condo;
+        client.update_property(
+            &owner,
+            &id,
+            &p.title,
+            &p.description,
+            &p.location,
+            &p.price_per_month,
+            &p.security_deposit,
+            &p.min_stay_days,
+            &p.max_stay_days,
+            &details,
+        );
+
+        assert_eq!(client.get_properties_by_type(&PropertyType::House).len(), 1);
+        let condos = client.get_properties_by_type(&PropertyType::Condo);
+        assert_eq!(condos.len(), 1);
+        assert_eq!(condos.get(0).unwrap().property_type, PropertyType::Condo);
+
+        client.set_availability(&owner, &id, &false);
+        assert!(client
+            .get_properties_by_type(&PropertyType::Condo)
+            .is_empty());
+    }
+
+    #[test]
+    fn ownership_transfer_moves_owner_index() {
+        let env = Env::default();
+        let client = setup(&env);
+        let owner = Address::generate(&env);
+        let buyer = Address::generate(&env);
+
+        let id = create_test_property(&env, &client, &owner, 1);
+        client.transfer_property_ownership(&owner, &id, &buyer);
+
+        assert_eq!(client.get_property(&id).owner, buyer);
+        assert_eq!(client.get_properties_by_owner_count(&owner), 0);
+        assert_eq!(client.get_properties_by_owner(&buyer).len(), 1);
+        assert_eq!(
+            client.try_set_availability(&owner, &id, &false),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+
+        client.deactivate_property(&buyer, &id);
+        assert_eq!(
+            client.try_transfer_property_ownership(&buyer, &id, &owner),
+            Err(Ok(Error::PropertyInactive.into()))
+        );
+    }
+
+    #[test]
+    fn admin_verification_badge() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, PropertyRegistry);
+        let client = PropertyRegistryClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let id = create_test_property(&env, &client, &Address::generate(&env), 1);
+        let own = create_test_property(&env, &client, &admin, 2);
+
+        env.ledger().set_timestamp(100);
+        client.verify_property(&id);
+        let p = client.get_property(&id);
+        assert!(p.is_verified);
+        assert_eq!(p.updated_at, 0);
+        assert_eq!(
+            client.try_verify_property(&own),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+        assert_eq!(client.get_verified_properties().len(), 1);
+
+        client.unverify_property(&id);
+        assert!(client.get_verified_properties().is_empty());
+    }
+
+    #[test]
+    fn bulk_availability_update() {
+        let env = Env::default();
+        let client = setup(&env);
+        let owner = Address::generate(&env);
+
+        let a = create_test_property(&env, &client, &owner, 1);
+        let b = create_test_property(&env, &client, &owner, 2);
+        let foreign = create_test_property(&env, &client, &Address::generate(&env), 3);
+
+        client.bulk_set_availability(
+            &owner,
+            &Vec::from_array(&env, [a.clone(), b.clone()]),
+            &false,
+        );
+        assert!(!client.get_property(&a).is_available);
+        assert!(!client.get_property(&b).is_available);
+
+        // One foreign listing fails the whole batch.
+        assert_eq!(
+            client.try_bulk_set_availability(
+                &owner,
+                &Vec::from_array(&env, [a.clone(), foreign]),
+                &true
+            ),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+        assert!(!client.get_property(&a).is_available);
+
+        let mut too_many = Vec::new(&env);
+        for _ in 0..=MAX_BULK_UPDATE {
+            too_many.push_back(a.clone());
+        }
+        assert_eq!(
+            client.try_bulk_set_availability(&owner, &too_many, &true),
+            Err(Ok(Error::BatchTooLarge.into()))
+        );
+    }
+
+    #[test]
+    fn listing_counts() {
+        let env = Env::default();
+        let client = setup(&env);
+        let owner = Address::generate(&env);
+
+        let a = create_test_property(&env, &client, &owner, 1);
+        let b = create_test_property(&env, &client, &owner, 2);
+        create_test_property(&env, &client, &Address::generate(&env), 3);
+        client.set_availability(&owner, &a, &false);
+        client.deactivate_property(&owner, &b);
+
+        assert_eq!(client.get_total_property_count(), 3);
+        assert_eq!(client.get_property_list_count(), 3);
+        assert_eq!(client.get_available_property_count(), 1);
+        assert_eq!(client.get_properties_by_owner_count(&owner), 2);
+        assert_eq!(
+            client.get_properties_by_owner_count(&Address::generate(&env)),
+            0
+        );
+    }
+
+    #[test]
+    fn property_view_counter() {
+        let env = Env::default();
+        let client = setup(&env);
+        let owner = Address::generate(&env);
+        let id = create_test_property(&env, &client, &owner, 1);
+
+        assert_eq!(client.get_property_view_count(&id), 0);
+        client.record_property_view(&id);
+        client.record_property_view(&id);
+        assert_eq!(client.get_property_view_count(&id), 2);
+
+        // Inactive listings still count views.
+        client.deactivate_property(&owner, &id);
+        client.record_property_view(&id);
+        assert_eq!(client.get_property_view_count(&id), 3);
+
+        assert_eq!(
+            client.try_record_property_view(&BytesN::from_array(&env, &[9; 32])),
+            Err(Ok(Error::PropertyNotFound.into()))
+        );
+    }
+
+    #[test]
+    fn featured_listing_expires() {
+        let env = Env::default();
+        let client = setup(&env);
+        let owner = Address::generate(&env);
+        let id = create_test_property(&env, &client, &owner, 1);
+        create_test_property(&env, &client, &owner, 2);
+
+        env.ledger().set_timestamp(1_000);
+        client.set_featured(&id, &2_000);
+        assert!(client.is_featured(&id));
+        let featured = client.get_featured_properties();
+        assert_eq!(featured.len(), 1);
+        assert_eq!(featured.get(0).unwrap().id, id);
+
+        env.ledger().set_timestamp(2_000);
+        assert!(!client.is_featured(&id));
+        assert!(client.get_featured_properties().is_empty());
+
+        assert_eq!(
+            client.try_set_featured(&BytesN::from_array(&env, &[9; 32]), &5_000),
+            Err(Ok(Error::PropertyNotFound.into()))
+        );
+    }
+
+    #[test]
+    fn minimum_stay_floor_is_enforced() {
+        let env = Env::default();
+        let client = setup(&env);
+        let owner = Address::generate(&env);
+        let id = create_test_property(&env, &client, &owner, 1);
+        let p = client.get_property(&id);
+
+        let create = |seed: u8, min_stay: u32, max_stay: u32| {
+            client.try_create_property(
+                &owner,
+                &BytesN::from_array(&env, &[seed; 32]),
+                &p.title,
+                &p.description,
+                &p.location,
+                &p.price_per_month,
+                &p.security_deposit,
+                &min_stay,
+                &max_stay,
+                &details_of(&p),
+            )
+        };
+
+        assert_eq!(client.get_min_stay_floor(), DEFAULT_MIN_STAY_FLOOR_DAYS);
+        assert_eq!(create(2, 29, 365), Err(Ok(Error::InvalidMinStay.into())));
+        assert_eq!(create(2, 60, 59), Err(Ok(Error::InvalidMaxStay.into())));
+        assert_eq!(
+            create(2, 60, MAX_STAY_CEILING_DAYS + 1),
+            Err(Ok(Error::InvalidMaxStayRange.into()))
+        );
+
+        client.set_min_stay_floor(&90);
+        assert_eq!(create(2, 60, 365), Err(Ok(Error::InvalidMinStay.into())));
+        assert!(create(2, 90, 365).is_ok());
+
+        assert_eq!(
+            client.try_set_min_stay_floor(&0),
+            Err(Ok(Error::InvalidMinStay.into()))
+        );
+        assert_eq!(
+            client.try_set_min_stay_floor(&(MAX_STAY_CEILING_DAYS + 1)),
+            Err(Ok(Error::InvalidMinStay.into()))
+        );
+    }
+
+    // Minimal BRIQ balance book that only supports service burns.
+    #[contract]
+    struct MockBriq;
+
+    #[contractimpl]
+    impl MockBriq {
+        pub fn set_balance(env: Env, who: Address, amount: i128) {
+            env.storage().instance().set(&who, &amount);
+        }
+
+        pub fn balance(env: Env, who: Address) -> i128 {
+            env.storage().instance().get(&who).unwrap_or(0)
+        }
+
+        pub fn burn_for_service(env: Env, from: Address, amount: i128, _service: Symbol) {
+            from.require_auth();
+            let balance = Self::balance(env.clone(), from.clone());
+            assert!(balance >= amount, "insufficient balance");
+            Self::set_balance(env, from, balance - amount);
+        }
+    }
+
+    #[test]
+    fn listing_fee_is_burned_once_token_is_set() {
+        let env = Env::default();
+        let client = setup(&env);
+        let owner = Address::generate(&env);
+
+        // No token configured: listing is free.
+        create_test_property(&env, &client, &owner, 1);
+
+        let briq_id = env.register_contract(None, MockBriq);
+        let briq = MockBriqClient::new(&env, &briq_id);
+        client.set_briq_token_contract(&briq_id);
+        briq.set_balance(&owner, &(DEFAULT_LISTING_FEE + 5));
+
+        create_test_property(&env, &client, &owner, 2);
+        assert_eq!(briq.balance(&owner), 5);
+
+        let id = BytesN::from_array(&env, &[3; 32]);
+        let p = client.get_property(&BytesN::from_array(&env, &[1; 32]));
+        let create = || {
+            client.try_create_property(
+                &owner,
+                &id,
+                &p.title,
+                &p.description,
+                &p.location,
+                &p.price_per_month,
+                &p.security_deposit,
+                &p.min_stay_days,
+                &p.max_stay_days,
+                &details_of(&p),
+            )
+        };
+        assert!(create().is_err());
+        assert_eq!(
+            client.try_get_property(&id),
+            Err(Ok(Error::PropertyNotFound.into()))
+        );
+
+        client.set_listing_fee(&0);
+        assert!(create().is_ok());
+        assert_eq!(briq.balance(&owner), 5);
+
+        assert_eq!(
+            client.try_set_listing_fee(&-1),
+            Err(Ok(Error::InvalidListingFee.into()))
+        );
+    }
+
+    #[test]
+    fn security_deposit_bounds() {
+        let env = Env::default();
+        let client = setup(&env);
+        let id = create_test_property(&env, &client, &Address::generate(&env), 1);
+        let p = client.get_property(&id);
+
+        let update = |deposit: i128| {
+            client.try_update_property(
+                &p.owner,
+                &id,
+                &p.title,
+                &p.description,
+                &p.location,
+                &1_000_0000,
+                &deposit,
+                &p.min_stay_days,
+                &p.max_stay_days,
+                &details_of(&p),
+            )
+        };
+
+        // Defaults: at least half a month, at most three months.
+        assert_eq!(
+            update(499_9999),
+            Err(Ok(Error::SecurityDepositTooLow.into()))
+        );
+        assert_eq!(
+            update(3_000_0001),
+            Err(Ok(Error::SecurityDepositTooHigh.into()))
+        );
+        assert!(update(3_000_0000).is_ok());
+
+        client.set_min_deposit_ratio(&0);
+        assert!(update(0).is_ok());
+        client.set_max_deposit_multiplier(&1);
+        assert_eq!(
+            update(1_000_0001),
+            Err(Ok(Error::SecurityDepositTooHigh.into()))
+        );
+
+        // The minimum can't exceed the maximum, whichever is set last.
+        assert_eq!(
+            client.try_set_min_deposit_ratio(&(BPS_DENOMINATOR + 1)),
+            Err(Ok(Error::InvalidDepositBounds.into()))
+        );
+        client.set_max_deposit_multiplier(&3);
+        client.set_min_deposit_ratio(&(2 * BPS_DENOMINATOR));
+        assert_eq!(
+            client.try_set_max_deposit_multiplier(&1),
+            Err(Ok(Error::InvalidDepositBounds.into()))
+        );
+    }
 }
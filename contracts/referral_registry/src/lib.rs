@@ -0,0 +1,498 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error, Address,
+    BytesN, Env, String, Symbol, Vec,
+};
+
+// -----------------------------
+// Cross-contract: RentalAgreement
+// -----------------------------
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AgreementStatus {
+    PendingLandlordApproval,
+    Rejected,
+    Draft,
+    PendingTenantSign,
+    PendingLandlordSign,
+    PendingCoSignerSign,
+    PendingPayment,
+    Active,
+    Completed,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentalAgreement {
+    pub id: BytesN<32>,
+    pub property_id: BytesN<32>,
+    pub landlord: Address,
+    pub tenant: Address,
+    pub monthly_rent: i128,
+    pub security_deposit: i128,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub status: AgreementStatus,
+    pub landlord_signed: bool,
+    pub landlord_signed_at: u64,
+    pub tenant_signed: bool,
+    pub tenant_signed_at: u64,
+    pub deposit_paid: bool,
+    pub deposit_paid_at: u64,
+    pub total_rent_paid: i128,
+    pub months_paid: u32,
+    pub created_at: u64,
+    pub completed_at: u64,
+    pub notice_period_days: u32,
+    pub notice_served_at: u64,
+    pub notice_served_by: Option<Address>,
+    pub document_hash: BytesN<32>,
+    pub co_signer: Option<Address>,
+    pub subletting_allowed: bool,
+    pub custom_clauses: Vec<String>,
+}
+
+#[contractclient(name = "RentalAgreementClient")]
+pub trait RentalAgreementContract {
+    fn get_agreement(agreement_id: BytesN<32>) -> RentalAgreement;
+}
+
+// -----------------------------
+// Cross-contract: BriqToken
+// -----------------------------
+
+#[contractclient(name = "BriqTokenClient")]
+pub trait BriqTokenContract {
+    fn reward_referral(agreement_id: BytesN<32>, referrer: Address, referee: Address) -> i128;
+}
+
+// -----------------------------
+// ReferralRegistry contract
+// -----------------------------
+
+const SECONDS_PER_MONTH: u64 = 30 * 24 * 60 * 60;
+const MAX_REFERRALS_PER_MONTH: u32 = 5;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    ContractPaused = 1,
+    Unauthorized = 2,
+    AlreadyInitialized = 3,
+
+    SelfReferral = 1500,
+    ReferralAlreadyRegistered = 1501,
+    ReferralNotFound = 1502,
+    AgreementNotCompleted = 1503,
+    ReferralAlreadyCompleted = 1504,
+    ReferralLimitReached = 1505,
+    AgreementPredatesReferral = 1506,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Referral {
+    pub referrer: Address,
+    pub referee: Address,
+    pub registered_at: u64,
+    pub first_agreement_completed: bool,
+    pub reward_claimed: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralStats {
+    pub total_referrals: u32,
+    pub pending: u32,
+    pub completed: u32,
+    pub total_rewards_earned: i128,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Paused,
+    AgreementContract,
+    TokenContract,
+    Referral(Address),
+    ReferralsByReferrer(Address),
+    RewardsEarned(Address),
+    MonthlyReferralCount(Address, u64),
+}
+
+#[contract]
+pub struct ReferralRegistry;
+
+#[contractimpl]
+impl ReferralRegistry {
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        agreement_contract: Address,
+        token_contract: Address,
+    ) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.storage()
+            .instance()
+            .set(&DataKey::AgreementContract, &agreement_contract);
+        env.storage()
+            .instance()
+            .set(&DataKey::TokenContract, &token_contract);
+
+        env.events().publish(
+            (Symbol::new(&env, "Initialized"),),
+            (
+                admin,
+                agreement_contract,
+                token_contract,
+                env.ledger().timestamp(),
+            ),
+        );
+    }
+
+    pub fn pause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.events()
+            .publish((Symbol::new(&env, "Paused"),), env.ledger().timestamp());
+    }
+
+    pub fn unpause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.events()
+            .publish((Symbol::new(&env, "Unpaused"),), env.ledger().timestamp());
+    }
+
+    // The referee names their referrer, once.
+    pub fn register_referral(env: Env, referee: Address, referrer: Address) {
+        Self::check_not_paused(&env);
+
+        referee.require_auth();
+
+        if referrer == referee {
+            panic_with_error!(&env, Error::SelfReferral);
+        }
+        let key = DataKey::Referral(referee.clone());
+        if env.storage().persistent().has(&key) {
+            panic_with_error!(&env, Error::ReferralAlreadyRegistered);
+        }
+
+        let referral = Referral {
+            referrer: referrer.clone(),
+            referee: referee.clone(),
+            registered_at: env.ledger().timestamp(),
+            first_agreement_completed: false,
+            reward_claimed: false,
+        };
+        env.storage().persistent().set(&key, &referral);
+
+        let mut referees = Self::referees_of(&env, &referrer);
+        referees.push_back(referee.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReferralsByReferrer(referrer.clone()), &referees);
+
+        env.events().publish(
+            (Symbol::new(&env, "ReferralRegistered"),),
+            (referrer, referee),
+        );
+    }
+
+    // Anyone can submit the referee's completed agreement to pay out the
+    // referral. The agreement must have been created after the referral was
+    // registered, so referring an existing tenant earns nothing. Each
+    // referrer is paid for at most 5 referrals per 30 days.
+    pub fn complete_referral(env: Env, agreement_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        let agreement = Self::agreement_client(&env).get_agreement(&agreement_id);
+        if agreement.status != AgreementStatus::Completed {
+            panic_with_error!(&env, Error::AgreementNotCompleted);
+        }
+
+        let key = DataKey::Referral(agreement.tenant.clone());
+        let mut referral: Referral = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ReferralNotFound));
+        if referral.reward_claimed {
+            panic_with_error!(&env, Error::ReferralAlreadyCompleted);
+        }
+        if agreement.created_at <= referral.registered_at {
+            panic_with_error!(&env, Error::AgreementPredatesReferral);
+        }
+
+        let month_key = env.ledger().timestamp() / SECONDS_PER_MONTH;
+        let count_key = DataKey::MonthlyReferralCount(referral.referrer.clone(), month_key);
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        if count >= MAX_REFERRALS_PER_MONTH {
+            panic_with_error!(&env, Error::ReferralLimitReached);
+        }
+        env.storage().persistent().set(&count_key, &(count + 1));
+
+        referral.first_agreement_completed = true;
+        referral.reward_claimed = true;
+        env.storage().persistent().set(&key, &referral);
+
+        let earned = Self::token_client(&env).reward_referral(
+            &agreement_id,
+            &referral.referrer,
+            &referral.referee,
+        );
+        let rewards_key = DataKey::RewardsEarned(referral.referrer.clone());
+        let total: i128 = env.storage().persistent().get(&rewards_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&rewards_key, &(total + earned));
+
+        env.events().publish(
+            (Symbol::new(&env, "ReferralCompleted"),),
+            (referral.referrer, referral.referee, agreement_id, earned),
+        );
+    }
+
+    pub fn get_referral_for(env: Env, referee: Address) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get::<_, Referral>(&DataKey::Referral(referee))
+            .map(|r| r.referrer)
+    }
+
+    pub fn get_referrals_by_referrer(env: Env, referrer: Address) -> Vec<Referral> {
+        let mut out = Vec::new(&env);
+        for referee in Self::referees_of(&env, &referrer).iter() {
+            if let Some(referral) = env
+                .storage()
+                .persistent()
+                .get::<_, Referral>(&DataKey::Referral(referee))
+            {
+                out.push_back(referral);
+            }
+        }
+        out
+    }
+
+    pub fn get_referral_stats(env: Env, referrer: Address) -> ReferralStats {
+        let referrals = Self::get_referrals_by_referrer(env.clone(), referrer.clone());
+        let completed = referrals
+            .iter()
+            .filter(|r| r.first_agreement_completed)
+            .count() as u32;
+        ReferralStats {
+            total_referrals: referrals.len(),
+            pending: referrals.len() - completed,
+            completed,
+            total_rewards_earned: env
+                .storage()
+                .persistent()
+                .get(&DataKey::RewardsEarned(referrer))
+                .unwrap_or(0),
+        }
+    }
+
+    // -----------------------------
+    // Internal helpers
+    // -----------------------------
+
+    fn referees_of(env: &Env, referrer: &Address) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReferralsByReferrer(referrer.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn agreement_client(env: &Env) -> RentalAgreementClient<'_> {
+        let addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::AgreementContract)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        RentalAgreementClient::new(env, &addr)
+    }
+
+    fn token_client(env: &Env) -> BriqTokenClient<'_> {
+        let addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::TokenContract)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        BriqTokenClient::new(env, &addr)
+    }
+
+    fn check_not_paused(env: &Env) {
+        let paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        if paused {
+            panic_with_error!(env, Error::ContractPaused);
+        }
+    }
+
+    fn require_admin(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    #[contract]
+    struct MockAgreement;
+
+    #[contractimpl]
+    impl MockAgreement {
+        pub fn set_agreement(env: Env, agreement: RentalAgreement) {
+            env.storage().instance().set(&agreement.id, &agreement);
+        }
+
+        pub fn get_agreement(env: Env, agreement_id: BytesN<32>) -> RentalAgreement {
+            env.storage().instance().get(&agreement_id).unwrap()
+        }
+    }
+
+    #[contract]
+    struct MockToken;
+
+    #[contractimpl]
+    impl MockToken {
+        pub fn reward_referral(
+            _env: Env,
+            _agreement_id: BytesN<32>,
+            _referrer: Address,
+            _referee: Address,
+        ) -> i128 {
+            100
+        }
+    }
+
+    fn completed_agreement(
+        env: &Env,
+        seed: u8,
+        tenant: &Address,
+        created_at: u64,
+    ) -> RentalAgreement {
+        RentalAgreement {
+            id: BytesN::from_array(env, &[seed; 32]),
+            property_id: BytesN::from_array(env, &[2; 32]),
+            landlord: Address::generate(env),
+            tenant: tenant.clone(),
+            monthly_rent: 1_000,
+            security_deposit: 500,
+            start_date: created_at,
+            end_date: created_at + 365 * 24 * 60 * 60,
+            status: AgreementStatus::Completed,
+            landlord_signed: true,
+            landlord_signed_at: created_at,
+            tenant_signed: true,
+            tenant_signed_at: created_at,
+            deposit_paid: true,
+            deposit_paid_at: created_at,
+            total_rent_paid: 12_000,
+            months_paid: 12,
+            created_at,
+            completed_at: created_at + 365 * 24 * 60 * 60,
+            notice_period_days: 30,
+            notice_served_at: 0,
+            notice_served_by: None,
+            document_hash: BytesN::from_array(env, &[0; 32]),
+            co_signer: None,
+            subletting_allowed: false,
+            custom_clauses: Vec::new(env),
+        }
+    }
+
+    #[test]
+    fn register_referral_once() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ReferralRegistry);
+        let client = ReferralRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let agreement_contract = Address::generate(&env);
+        let token_contract = Address::generate(&env);
+        client.initialize(&admin, &agreement_contract, &token_contract);
+
+        let referrer = Address::generate(&env);
+        let referee = Address::generate(&env);
+        assert_eq!(
+            client.try_register_referral(&referee, &referee),
+            Err(Ok(Error::SelfReferral.into()))
+        );
+
+        client.register_referral(&referee, &referrer);
+        assert_eq!(client.get_referral_for(&referee), Some(referrer.clone()));
+        assert_eq!(
+            client.try_register_referral(&referee, &referrer),
+            Err(Ok(Error::ReferralAlreadyRegistered.into()))
+        );
+
+        let stats = client.get_referral_stats(&referrer);
+        assert_eq!(stats.total_referrals, 1);
+        assert_eq!(stats.pending, 1);
+        assert_eq!(stats.total_rewards_earned, 0);
+    }
+
+    #[test]
+    fn only_agreements_after_registration_pay_out() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+        let agreement_contract = env.register_contract(None, MockAgreement);
+        let agreements = MockAgreementClient::new(&env, &agreement_contract);
+        let token_contract = env.register_contract(None, MockToken);
+        let contract_id = env.register_contract(None, ReferralRegistry);
+        let client = ReferralRegistryClient::new(&env, &contract_id);
+        client.initialize(
+            &Address::generate(&env),
+            &agreement_contract,
+            &token_contract,
+        );
+
+        let referrer = Address::generate(&env);
+        let referee = Address::generate(&env);
+        let earlier = completed_agreement(&env, 1, &referee, 500);
+        agreements.set_agreement(&earlier);
+        client.register_referral(&referee, &referrer);
+
+        assert_eq!(
+            client.try_complete_referral(&earlier.id),
+            Err(Ok(Error::AgreementPredatesReferral.into()))
+        );
+
+        let later = completed_agreement(&env, 2, &referee, 2_000);
+        agreements.set_agreement(&later);
+        client.complete_referral(&later.id);
+        let stats = client.get_referral_stats(&referrer);
+        assert_eq!(stats.completed, 1);
+        assert_eq!(stats.pending, 0);
+        assert_eq!(stats.total_rewards_earned, 100);
+        assert_eq!(
+            client.try_complete_referral(&later.id),
+            Err(Ok(Error::ReferralAlreadyCompleted.into()))
+        );
+    }
+}
@@ -1,14 +1,75 @@
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 
 use soroban_sdk::{
-    contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error, Address,
-    BytesN, Env, String, Symbol, Vec,
+    contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error,
+    xdr::ToXdr, Address, BytesN, Env, String, Symbol, Vec,
 };
 
+// -----------------------------
+// Cross-contract: TenantCredit (optional)
+// -----------------------------
+
+#[contractclient(name = "TenantCreditClient")]
+pub trait TenantCredit {
+    fn update_agreement_outcome(agreement_id: BytesN<32>, tenant: Address, completed: bool);
+}
+
+// -----------------------------
+// Cross-contract: EscrowManager (optional)
+// -----------------------------
+
+#[contractclient(name = "EscrowManagerClient")]
+pub trait EscrowManager {
+    fn initialize_escrow_account(
+        agreement_id: BytesN<32>,
+        landlord: Address,
+        tenant: Address,
+        security_deposit: i128,
+        monthly_rent: i128,
+    );
+    fn update_escrow_tenant(agreement_id: BytesN<32>, new_tenant: Address);
+}
+
+// -----------------------------
+// Cross-contract: BriqToken rental badges (optional)
+// -----------------------------
+
+#[contractclient(name = "BadgeTokenClient")]
+pub trait BadgeToken {
+    fn issue_rental_badge(
+        agreement_id: BytesN<32>,
+        tenant: Address,
+        months_completed: u32,
+        final_rating: u32,
+    );
+}
+
 // -----------------------------
 // Cross-contract: PropertyRegistry
 // -----------------------------
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PropertyType {
+    Apartment,
+    House,
+    Condo,
+    Studio,
+    Commercial,
+    Other,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PetPolicy {
+    NoPets,
+    CatsOnly,
+    SmallDogsOnly,
+    AllPets,
+    NegotiableCase,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Property {
@@ -22,6 +83,15 @@ pub struct Property {
     pub min_stay_days: u32,
     pub max_stay_days: u32,
     pub image_url: String,
+    pub amenities: Vec<String>,
+    pub property_type: PropertyType,
+    pub latitude: i64,
+    pub longitude: i64,
+    pub floor_area_sqft: u32,
+    pub furnished: bool,
+    pub parking_spaces: u32,
+    pub pet_policy: PetPolicy,
+    pub is_verified: bool,
     pub is_available: bool,
     pub is_active: bool,
     pub created_at: u64,
@@ -57,8 +127,46 @@ pub enum Error {
     InvalidState = 208,
     RequestNotPending = 209,
     RequestAlreadyRejected = 210,
+    InvalidNoticePeriod = 211,
+    NoticeAlreadyServed = 212,
+    NoPendingAdminTransfer = 213,
+    AdminTransferExpired = 214,
+    NotCoSigner = 215,
+    InvalidCoSigner = 216,
+    SubletNotAllowed = 217,
+    SubletAlreadyRequested = 218,
+    NoPendingSublet = 219,
+    InvalidClause = 220,
+    TooManyClauses = 221,
+    ClauseNotFound = 222,
+    TenantBlacklisted = 223,
+    NotBlacklisted = 224,
+    NoPendingTenancyTransfer = 225,
+    TenancyTransferExpired = 226,
+    LandlordAgreementLimitReached = 227,
+    InvalidAgreementLimit = 228,
 }
 
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+const SECONDS_PER_MONTH: u64 = 30 * SECONDS_PER_DAY;
+
+const MAX_OVERDUE_RESULTS: u32 = 20;
+const MAX_PAGE_SIZE: u32 = 30;
+const MAX_RANGE_RESULTS: u32 = 50;
+const MAX_STATS_AGREEMENTS: u32 = 50;
+const TENANCY_TRANSFER_WINDOW_SECONDS: u64 = 7 * SECONDS_PER_DAY;
+// Default and ceiling for active agreements per landlord; checking it loads
+// each of the landlord's agreements.
+const MAX_AGREEMENTS_PER_LANDLORD: u32 = 50;
+const MAX_CUSTOM_CLAUSES: u32 = 10;
+const MAX_CLAUSE_LEN: u32 = 200;
+
+const MIN_NOTICE_PERIOD_DAYS: u32 = 7;
+const MAX_NOTICE_PERIOD_DAYS: u32 = 90;
+// Used for tenant-initiated requests, where the landlord doesn't pick one up front.
+const DEFAULT_NOTICE_PERIOD_DAYS: u32 = 30;
+const ADMIN_TRANSFER_WINDOW_SECONDS: u64 = 7 * SECONDS_PER_DAY;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AgreementStatus {
@@ -67,6 +175,8 @@ pub enum AgreementStatus {
     Draft,
     PendingTenantSign,
     PendingLandlordSign,
+    // Tenant and landlord have signed; waiting on the guarantor.
+    PendingCoSignerSign,
     PendingPayment,
     Active,
     Completed,
@@ -95,6 +205,52 @@ pub struct RentalAgreement {
     pub months_paid: u32,
     pub created_at: u64,
     pub completed_at: u64,
+    pub notice_period_days: u32,
+    pub notice_served_at: u64,
+    pub notice_served_by: Option<Address>,
+    pub document_hash: BytesN<32>,
+    pub co_signer: Option<Address>,
+    pub subletting_allowed: bool,
+    pub custom_clauses: Vec<String>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentProgress {
+    pub expected_months: u32,
+    pub months_paid: u32,
+    pub expected_total_rent: i128,
+    pub total_rent_paid: i128,
+    pub is_overdue: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubletRequest {
+    pub proposed_subtenant: Address,
+    pub requested_at: u64,
+    pub pending: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TenancyTransfer {
+    pub new_tenant: Address,
+    pub expires_at: u64,
+}
+
+/// Aggregate over a landlord's most recent agreements.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LandlordStats {
+    pub total_agreements: u32,
+    pub active_agreements: u32,
+    pub completed_agreements: u32,
+    pub cancelled_agreements: u32,
+    pub total_rent_collected: i128,
+    pub total_months_paid: u32,
+    // Mean months paid across agreements that were ever funded.
+    pub average_tenancy_months: u32,
 }
 
 #[contracttype]
@@ -107,6 +263,19 @@ pub enum DataKey {
     ByTenant(Address),
     ByLandlord(Address),
     ByProperty(BytesN<32>),
+    CreditContract,
+    EscrowContract,
+    CoSignerSigned(BytesN<32>),
+    PaymentSchedule(BytesN<32>),
+    SubletRequest(BytesN<32>),
+    CurrentSubtenant(BytesN<32>),
+    LandlordBlacklist(Address),
+    TenancyTransfer(BytesN<32>),
+    MaxAgreementsPerLandlord,
+    ActiveAgreementCount(Address),
+    BadgeContract,
+    PendingAdmin,
+    AdminTransferExpiry,
 }
 
 #[contract]
@@ -151,6 +320,153 @@ impl RentalAgreementContract {
             .publish((Symbol::new(&env, "Unpaused"),), env.ledger().timestamp());
     }
 
+    /// Starts a two-step admin handover. The proposed admin has 7 days to
+    /// call `accept_admin_transfer`.
+    pub fn propose_admin_transfer(env: Env, current_admin: Address, proposed_admin: Address) {
+        if current_admin != Self::require_admin(&env) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        current_admin.require_auth();
+
+        let expiry = env.ledger().timestamp() + ADMIN_TRANSFER_WINDOW_SECONDS;
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &proposed_admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::AdminTransferExpiry, &expiry);
+
+        env.events().publish(
+            (Symbol::new(&env, "AdminTransferProposed"),),
+            (current_admin, proposed_admin, expiry),
+        );
+    }
+
+    pub fn accept_admin_transfer(env: Env, proposed_admin: Address) {
+        proposed_admin.require_auth();
+
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoPendingAdminTransfer));
+        if pending != proposed_admin {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        let expiry: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AdminTransferExpiry)
+            .unwrap_or(0);
+        if env.ledger().timestamp() > expiry {
+            panic_with_error!(&env, Error::AdminTransferExpired);
+        }
+
+        let previous_admin = Self::require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::Admin, &proposed_admin);
+        Self::clear_pending_admin(&env);
+
+        env.events().publish(
+            (Symbol::new(&env, "AdminTransferred"),),
+            (previous_admin, proposed_admin),
+        );
+    }
+
+    pub fn cancel_admin_transfer(env: Env, current_admin: Address) {
+        if current_admin != Self::require_admin(&env) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        current_admin.require_auth();
+
+        if !env.storage().instance().has(&DataKey::PendingAdmin) {
+            panic_with_error!(&env, Error::NoPendingAdminTransfer);
+        }
+        Self::clear_pending_admin(&env);
+
+        env.events().publish(
+            (Symbol::new(&env, "AdminTransferCancelled"),),
+            current_admin,
+        );
+    }
+
+    // Optional: tenant credit contract told when agreements complete or are cancelled.
+    pub fn set_credit_contract(env: Env, credit_contract: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::CreditContract, &credit_contract);
+
+        env.events().publish(
+            (Symbol::new(&env, "CreditContractSet"),),
+            (credit_contract, env.ledger().timestamp()),
+        );
+    }
+
+    pub fn set_max_agreements_per_landlord(env: Env, max: u32) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        if max == 0 || max > MAX_AGREEMENTS_PER_LANDLORD {
+            panic_with_error!(&env, Error::InvalidAgreementLimit);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxAgreementsPerLandlord, &max);
+
+        env.events()
+            .publish((Symbol::new(&env, "MaxAgreementsPerLandlordSet"),), max);
+    }
+
+    pub fn get_max_agreements_per_landlord(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxAgreementsPerLandlord)
+            .unwrap_or(MAX_AGREEMENTS_PER_LANDLORD)
+    }
+
+    // Escrow contract that gets an empty escrow account opened for each
+    // agreement as soon as it is awaiting payment.
+    pub fn set_escrow_contract(env: Env, escrow_contract: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::EscrowContract, &escrow_contract);
+
+        env.events().publish(
+            (Symbol::new(&env, "EscrowContractSet"),),
+            (escrow_contract, env.ledger().timestamp()),
+        );
+    }
+
+    // Optional: BriqToken that issues a proof-of-rental badge on completion.
+    pub fn set_badge_contract(env: Env, badge_contract: Address) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::BadgeContract, &badge_contract);
+
+        env.events().publish(
+            (Symbol::new(&env, "BadgeContractSet"),),
+            (badge_contract, env.ledger().timestamp()),
+        );
+    }
+
     pub fn create_agreement(
         env: Env,
         landlord: Address,
@@ -158,11 +474,30 @@ impl RentalAgreementContract {
         tenant: Address,
         start_date: u64,
         end_date: u64,
+        notice_period_days: u32,
+        co_signer: Option<Address>,
+        subletting_allowed: bool,
+        custom_clauses: Vec<String>,
     ) -> BytesN<32> {
         Self::check_not_paused(&env);
 
         landlord.require_auth();
 
+        if !(MIN_NOTICE_PERIOD_DAYS..=MAX_NOTICE_PERIOD_DAYS).contains(&notice_period_days) {
+            panic_with_error!(&env, Error::InvalidNoticePeriod);
+        }
+        if custom_clauses.len() > MAX_CUSTOM_CLAUSES {
+            panic_with_error!(&env, Error::TooManyClauses);
+        }
+        for clause in custom_clauses.iter() {
+            Self::validate_clause(&env, &clause);
+        }
+        if let Some(guarantor) = &co_signer {
+            if *guarantor == landlord || *guarantor == tenant {
+                panic_with_error!(&env, Error::InvalidCoSigner);
+            }
+        }
+
         let property = Self::fetch_property(&env, property_id.clone());
         if !property.is_active {
             panic_with_error!(&env, Error::PropertyNotFoundOrInactive);
@@ -173,6 +508,10 @@ impl RentalAgreementContract {
         if property.owner != landlord {
             panic_with_error!(&env, Error::Unauthorized);
         }
+        if Self::is_tenant_blacklisted(env.clone(), landlord.clone(), tenant.clone()) {
+            panic_with_error!(&env, Error::TenantBlacklisted);
+        }
+        Self::check_landlord_agreement_limit(&env, &landlord);
 
         Self::validate_dates_and_duration(
             &env,
@@ -204,6 +543,13 @@ impl RentalAgreementContract {
             months_paid: 0,
             created_at: now,
             completed_at: 0,
+            notice_period_days,
+            notice_served_at: 0,
+            notice_served_by: None,
+            document_hash: BytesN::from_array(&env, &[0; 32]),
+            co_signer,
+            subletting_allowed,
+            custom_clauses,
         };
 
         env.storage()
@@ -227,6 +573,99 @@ impl RentalAgreementContract {
         id
     }
 
+    /// Landlord attaches the hash of the off-chain legal document while the
+    /// agreement is still a `Draft`, so both signatures cover that document.
+    pub fn set_document_hash(
+        env: Env,
+        landlord: Address,
+        agreement_id: BytesN<32>,
+        hash: BytesN<32>,
+    ) {
+        Self::check_not_paused(&env);
+
+        landlord.require_auth();
+
+        let mut agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        if landlord != agreement.landlord {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        if agreement.status != AgreementStatus::Draft {
+            panic_with_error!(&env, Error::InvalidState);
+        }
+
+        agreement.document_hash = hash.clone();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+
+        env.events().publish(
+            (Symbol::new(&env, "DocumentHashSet"),),
+            (agreement_id, hash),
+        );
+    }
+
+    /// Checks `hash` against the signed terms: the document hash alone, or
+    /// combined with the custom clauses when there are any.
+    pub fn verify_document_hash(env: Env, agreement_id: BytesN<32>, hash: BytesN<32>) -> bool {
+        Self::get_terms_hash(env, agreement_id) == hash
+    }
+
+    /// Hash of the document hash together with the custom clauses, i.e. the
+    /// full set of terms the parties sign. Equal to `document_hash` when the
+    /// agreement has no clauses.
+    pub fn get_terms_hash(env: Env, agreement_id: BytesN<32>) -> BytesN<32> {
+        let agreement = Self::get_agreement(env.clone(), agreement_id);
+        if agreement.custom_clauses.is_empty() {
+            return agreement.document_hash;
+        }
+        let terms = (agreement.document_hash, agreement.custom_clauses).to_xdr(&env);
+        env.crypto().sha256(&terms).into()
+    }
+
+    /// Clauses can be edited until both parties have signed. Any edit clears
+    /// the signatures collected so far, so everyone signs the final terms.
+    pub fn add_custom_clause(
+        env: Env,
+        landlord: Address,
+        agreement_id: BytesN<32>,
+        clause: String,
+    ) {
+        let mut agreement = Self::editable_agreement(&env, &landlord, &agreement_id);
+        if agreement.custom_clauses.len() >= MAX_CUSTOM_CLAUSES {
+            panic_with_error!(&env, Error::TooManyClauses);
+        }
+        Self::validate_clause(&env, &clause);
+
+        agreement.custom_clauses.push_back(clause.clone());
+        Self::reset_signatures(&env, &mut agreement);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+
+        env.events()
+            .publish((Symbol::new(&env, "ClauseAdded"),), (agreement_id, clause));
+    }
+
+    pub fn remove_custom_clause(env: Env, landlord: Address, agreement_id: BytesN<32>, index: u32) {
+        let mut agreement = Self::editable_agreement(&env, &landlord, &agreement_id);
+        let clause = agreement
+            .custom_clauses
+            .get(index)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ClauseNotFound));
+
+        agreement.custom_clauses.remove(index);
+        Self::reset_signatures(&env, &mut agreement);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+
+        env.events().publish(
+            (Symbol::new(&env, "ClauseRemoved"),),
+            (agreement_id, index, clause),
+        );
+    }
+
     /// Tenant-initiated rental request
     /// Creates an agreement in PendingLandlordApproval status
     pub fn request_rental(
@@ -248,6 +687,9 @@ impl RentalAgreementContract {
         if !property.is_available {
             panic_with_error!(&env, Error::PropertyNotAvailable);
         }
+        if Self::is_tenant_blacklisted(env.clone(), property.owner.clone(), tenant.clone()) {
+            panic_with_error!(&env, Error::TenantBlacklisted);
+        }
 
         Self::validate_dates_and_duration(
             &env,
@@ -279,6 +721,13 @@ impl RentalAgreementContract {
             months_paid: 0,
             created_at: now,
             completed_at: 0,
+            notice_period_days: DEFAULT_NOTICE_PERIOD_DAYS,
+            notice_served_at: 0,
+            notice_served_by: None,
+            document_hash: BytesN::from_array(&env, &[0; 32]),
+            co_signer: None,
+            subletting_allowed: false,
+            custom_clauses: Vec::new(&env),
         };
 
         env.storage()
@@ -316,6 +765,7 @@ impl RentalAgreementContract {
         if agreement.status != AgreementStatus::PendingLandlordApproval {
             panic_with_error!(&env, Error::RequestNotPending);
         }
+        Self::check_landlord_agreement_limit(&env, &landlord);
 
         // Approve = landlord signs
         agreement.landlord_signed = true;
@@ -327,6 +777,8 @@ impl RentalAgreementContract {
             .persistent()
             .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
 
+        Self::notify_escrow_init(&env, &agreement);
+
         env.events().publish(
             (Symbol::new(&env, "RequestApproved"),),
             (agreement_id, landlord, env.ledger().timestamp()),
@@ -375,12 +827,16 @@ impl RentalAgreementContract {
 
         agreement.tenant_signed = true;
         agreement.tenant_signed_at = env.ledger().timestamp();
-        agreement.status = Self::next_status_after_signature(&agreement);
+        agreement.status = Self::next_status_after_signature(&env, &agreement);
 
         env.storage()
             .persistent()
             .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
 
+        if agreement.status == AgreementStatus::PendingPayment {
+            Self::notify_escrow_init(&env, &agreement);
+        }
+
         env.events().publish(
             (Symbol::new(&env, "AgreementSigned"),),
             (agreement_id, tenant, Symbol::new(&env, "Tenant")),
@@ -402,18 +858,98 @@ impl RentalAgreementContract {
 
         agreement.landlord_signed = true;
         agreement.landlord_signed_at = env.ledger().timestamp();
-        agreement.status = Self::next_status_after_signature(&agreement);
+        agreement.status = Self::next_status_after_signature(&env, &agreement);
 
         env.storage()
             .persistent()
             .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
 
+        if agreement.status == AgreementStatus::PendingPayment {
+            Self::notify_escrow_init(&env, &agreement);
+        }
+
         env.events().publish(
             (Symbol::new(&env, "AgreementSigned"),),
             (agreement_id, landlord, Symbol::new(&env, "Landlord")),
         );
     }
 
+    // The guarantor signs after being named on the agreement; an agreement
+    // with a co-signer can't go to PendingPayment until they have signed.
+    pub fn co_signer_sign(env: Env, co_signer: Address, agreement_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        co_signer.require_auth();
+
+        let mut agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        if agreement.co_signer != Some(co_signer.clone()) {
+            panic_with_error!(&env, Error::NotCoSigner);
+        }
+        if Self::co_signer_signed(&env, &agreement_id) {
+            panic_with_error!(&env, Error::AlreadySigned);
+        }
+        match agreement.status {
+            AgreementStatus::Draft
+            | AgreementStatus::PendingTenantSign
+            | AgreementStatus::PendingLandlordSign
+            | AgreementStatus::PendingCoSignerSign => {}
+            _ => panic_with_error!(&env, Error::InvalidState),
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::CoSignerSigned(agreement_id.clone()), &true);
+        agreement.status = Self::next_status_after_signature(&env, &agreement);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+
+        if agreement.status == AgreementStatus::PendingPayment {
+            Self::notify_escrow_init(&env, &agreement);
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "AgreementSigned"),),
+            (agreement_id, co_signer, Symbol::new(&env, "CoSigner")),
+        );
+    }
+
+    pub fn is_co_signer_signed(env: Env, agreement_id: BytesN<32>) -> bool {
+        Self::co_signer_signed(&env, &agreement_id)
+    }
+
+    // Called by the escrow contract when the co-signer covers rent the tenant
+    // has defaulted on. Checks the guarantee is in force; the escrow moves
+    // the funds.
+    pub fn co_signer_guarantee_payment(env: Env, co_signer: Address, agreement_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        let escrow_contract: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::EscrowContract)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Unauthorized));
+        escrow_contract.require_auth();
+        co_signer.require_auth();
+
+        let agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        if agreement.co_signer != Some(co_signer.clone()) {
+            panic_with_error!(&env, Error::NotCoSigner);
+        }
+        if !Self::co_signer_signed(&env, &agreement_id) {
+            panic_with_error!(&env, Error::InvalidState);
+        }
+        if agreement.status != AgreementStatus::Active {
+            panic_with_error!(&env, Error::InvalidState);
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "GuaranteeInvoked"),),
+            (agreement_id, co_signer, agreement.tenant),
+        );
+    }
+
     // Called by escrow contract when deposit + first month rent are received.
     pub fn mark_deposit_paid(env: Env, agreement_id: BytesN<32>) {
         Self::check_not_paused(&env);
@@ -433,22 +969,25 @@ impl RentalAgreementContract {
         env.storage()
             .persistent()
             .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+        Self::adjust_active_count(&env, &agreement.landlord, 1);
+
+        // Due dates for every month after the first, which is paid on activation.
+        let mut schedule = Vec::<u64>::new(&env);
+        for i in 1..Self::expected_months(&agreement) {
+            schedule.push_back(agreement.start_date + i as u64 * SECONDS_PER_MONTH);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::PaymentSchedule(agreement_id.clone()), &schedule);
 
         env.events().publish(
             (Symbol::new(&env, "AgreementActivated"),),
             (agreement_id, agreement.deposit_paid_at),
         );
 
-        // Update property availability to false
-        let registry: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::PropertyRegistry)
-            .unwrap_or_else(|| panic_with_error!(&env, Error::Unauthorized));
-        
-        let client = PropertyRegistryClient::new(&env, &registry);
+        // Update property availability to false.
         // This call will fail if the PropertyRegistry hasn't authorized this contract address
-        client.update_availability_by_contract(&agreement.property_id, &false);
+        Self::registry_client(&env).update_availability_by_contract(&agreement.property_id, &false);
     }
 
     // Called by escrow contract for each rent payment.
@@ -473,11 +1012,40 @@ impl RentalAgreementContract {
         );
     }
 
-    pub fn complete_agreement(env: Env, caller: Address, agreement_id: BytesN<32>) {
+    // Called by escrow contract for the prorated final payment after notice.
+    // Counts towards rent paid but not towards months paid.
+    pub fn record_prorated_payment(env: Env, agreement_id: BytesN<32>, amount: i128) {
         Self::check_not_paused(&env);
 
-        caller.require_auth();
-
+        let escrow_contract: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::EscrowContract)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Unauthorized));
+        escrow_contract.require_auth();
+
+        let mut agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        if agreement.status != AgreementStatus::Active {
+            panic_with_error!(&env, Error::InvalidState);
+        }
+
+        agreement.total_rent_paid = agreement.total_rent_paid.saturating_add(amount);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+
+        env.events().publish(
+            (Symbol::new(&env, "ProratedRentRecorded"),),
+            (agreement_id, amount, agreement.months_paid),
+        );
+    }
+
+    pub fn complete_agreement(env: Env, caller: Address, agreement_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        caller.require_auth();
+
         let mut agreement = Self::get_agreement(env.clone(), agreement_id.clone());
         if caller != agreement.tenant && caller != agreement.landlord {
             panic_with_error!(&env, Error::NotAgreementParty);
@@ -487,7 +1055,7 @@ impl RentalAgreementContract {
         }
 
         let now = env.ledger().timestamp();
-        if now < agreement.end_date {
+        if now < agreement.end_date && !Self::notice_elapsed(&agreement, now) {
             panic_with_error!(&env, Error::InvalidDates);
         }
 
@@ -497,6 +1065,7 @@ impl RentalAgreementContract {
         env.storage()
             .persistent()
             .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+        Self::adjust_active_count(&env, &agreement.landlord, -1);
 
         env.events().publish(
             (Symbol::new(&env, "AgreementCompleted"),),
@@ -504,14 +1073,143 @@ impl RentalAgreementContract {
         );
 
         // Make property available again
-        let registry: Address = env
+        Self::registry_client(&env).update_availability_by_contract(&agreement.property_id, &true);
+
+        Self::maybe_record_outcome(&env, &agreement, true);
+        Self::maybe_issue_badge(&env, &agreement);
+    }
+
+    /// Admin escape hatch for agreements left `Active` past their end date
+    /// (e.g. an abandoned tenancy), so the deposit release flow can proceed.
+    /// Counts as a normal completion for the tenant's credit and badge.
+    pub fn admin_force_complete(env: Env, agreement_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let mut agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        if agreement.status != AgreementStatus::Active {
+            panic_with_error!(&env, Error::InvalidState);
+        }
+
+        let now = env.ledger().timestamp();
+        if now <= agreement.end_date {
+            panic_with_error!(&env, Error::InvalidDates);
+        }
+
+        agreement.status = AgreementStatus::Completed;
+        agreement.completed_at = now;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+        Self::adjust_active_count(&env, &agreement.landlord, -1);
+
+        env.events().publish(
+            (Symbol::new(&env, "AgreementForceCompleted"),),
+            (agreement_id, admin, agreement.completed_at),
+        );
+
+        Self::registry_client(&env).update_availability_by_contract(&agreement.property_id, &true);
+
+        Self::maybe_record_outcome(&env, &agreement, true);
+        Self::maybe_issue_badge(&env, &agreement);
+    }
+
+    /// Moves an agreement over to the property's current owner after a
+    /// `transfer_property_ownership` in PropertyRegistry.
+    pub fn update_agreement_landlord(env: Env, new_landlord: Address, agreement_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        new_landlord.require_auth();
+
+        let mut agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        match agreement.status {
+            AgreementStatus::Completed | AgreementStatus::Cancelled | AgreementStatus::Rejected => {
+                panic_with_error!(&env, Error::InvalidState)
+            }
+            _ => {}
+        }
+
+        let property = Self::fetch_property(&env, agreement.property_id.clone());
+        if property.owner != new_landlord || agreement.landlord == new_landlord {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+
+        let old_landlord = agreement.landlord.clone();
+        agreement.landlord = new_landlord.clone();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+        if agreement.status == AgreementStatus::Active {
+            Self::adjust_active_count(&env, &old_landlord, -1);
+            Self::adjust_active_count(&env, &new_landlord, 1);
+        }
+
+        let mut old_list: Vec<BytesN<32>> = env
             .storage()
-            .instance()
-            .get(&DataKey::PropertyRegistry)
-            .unwrap_or_else(|| panic_with_error!(&env, Error::Unauthorized));
-        
-        let client = PropertyRegistryClient::new(&env, &registry);
-        client.update_availability_by_contract(&agreement.property_id, &true);
+            .persistent()
+            .get(&DataKey::ByLandlord(old_landlord.clone()))
+            .unwrap_or(Vec::new(&env));
+        if let Some(i) = old_list.first_index_of(&agreement_id) {
+            old_list.remove(i);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::ByLandlord(old_landlord.clone()), &old_list);
+
+        let mut new_list: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ByLandlord(new_landlord.clone()))
+            .unwrap_or(Vec::new(&env));
+        new_list.push_back(agreement_id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::ByLandlord(new_landlord.clone()), &new_list);
+
+        env.events().publish(
+            (Symbol::new(&env, "AgreementLandlordUpdated"),),
+            (agreement_id, old_landlord, new_landlord),
+        );
+    }
+
+    /// Either party serves notice on an active agreement. Once the agreement's
+    /// notice period has elapsed, `complete_agreement` may be called before `end_date`.
+    pub fn serve_notice(env: Env, caller: Address, agreement_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        caller.require_auth();
+
+        let mut agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        if caller != agreement.tenant && caller != agreement.landlord {
+            panic_with_error!(&env, Error::NotAgreementParty);
+        }
+        if agreement.status != AgreementStatus::Active {
+            panic_with_error!(&env, Error::InvalidState);
+        }
+        if agreement.notice_served_by.is_some() {
+            panic_with_error!(&env, Error::NoticeAlreadyServed);
+        }
+
+        agreement.notice_served_at = env.ledger().timestamp();
+        agreement.notice_served_by = Some(caller.clone());
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+
+        env.events().publish(
+            (Symbol::new(&env, "NoticeServed"),),
+            (
+                agreement_id,
+                caller,
+                agreement.notice_served_at,
+                agreement.notice_period_days,
+            ),
+        );
     }
 
     pub fn cancel_agreement(env: Env, caller: Address, agreement_id: BytesN<32>) {
@@ -542,6 +1240,8 @@ impl RentalAgreementContract {
             (agreement_id, env.ledger().timestamp()),
         );
 
+        Self::maybe_record_outcome(&env, &agreement, false);
+
         // Make property available again (if it was unavailable - though likely it was already available if no payment made)
         // But if we support cancellation after payment in future, this is needed.
         // For now, cancellation only happens before payment, so property is arguably already available.
@@ -596,133 +1296,1530 @@ impl RentalAgreementContract {
         Self::hydrate_agreements(&env, ids)
     }
 
-    fn check_not_paused(env: &Env) {
-        let paused: bool = env
-            .storage()
-            .instance()
-            .get(&DataKey::Paused)
-            .unwrap_or(false);
-        if paused {
-            panic_with_error!(env, Error::ContractPaused);
+    /// Rent expected vs. paid for an agreement, for progress indicators.
+    /// Months are counted in 30-day blocks from `start_date`.
+    pub fn get_payment_progress(env: Env, agreement_id: BytesN<32>) -> PaymentProgress {
+        let agreement = Self::get_agreement(env.clone(), agreement_id);
+        let expected_months = Self::expected_months(&agreement);
+
+        PaymentProgress {
+            expected_months,
+            months_paid: agreement.months_paid,
+            expected_total_rent: agreement
+                .monthly_rent
+                .saturating_mul(expected_months as i128),
+            total_rent_paid: agreement.total_rent_paid,
+            is_overdue: Self::is_overdue(&env, &agreement),
         }
     }
 
-    fn require_admin(env: &Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized))
-    }
-
-    fn fetch_property(env: &Env, property_id: BytesN<32>) -> Property {
-        let registry: Address = env
+    /// Active agreements of `landlord` whose rent is behind schedule, returning at
+    /// most `limit` (capped at 20) results. Larger portfolios should page through
+    /// `get_agreements_by_landlord` and check `get_payment_progress` individually.
+    pub fn get_overdue_agreements(env: Env, landlord: Address, limit: u32) -> Vec<RentalAgreement> {
+        let ids: Vec<BytesN<32>> = env
             .storage()
-            .instance()
-            .get(&DataKey::PropertyRegistry)
-            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+            .persistent()
+            .get(&DataKey::ByLandlord(landlord))
+            .unwrap_or(Vec::new(&env));
+        let limit = limit.min(MAX_OVERDUE_RESULTS);
 
-        let client = PropertyRegistryClient::new(env, &registry);
-        // If the registry panics for not found, we treat it as inactive/not found.
-        client.get_property(&property_id)
+        let mut out = Vec::<RentalAgreement>::new(&env);
+        for id in ids.iter() {
+            if out.len() >= limit {
+                break;
+            }
+            if let Some(a) = env
+                .storage()
+                .persistent()
+                .get::<_, RentalAgreement>(&DataKey::Agreement(id))
+            {
+                if a.status == AgreementStatus::Active && Self::is_overdue(&env, &a) {
+                    out.push_back(a);
+                }
+            }
+        }
+        out
     }
 
-    fn validate_dates_and_duration(
-        env: &Env,
-        start_date: u64,
-        end_date: u64,
-        min_days: u32,
-        max_days: u32,
-    ) {
-        if end_date <= start_date {
-            panic_with_error!(env, Error::InvalidDates);
-        }
+    /// Blocks `tenant` from new agreements with this landlord. The reason is
+    /// only recorded in the event.
+    pub fn blacklist_tenant(env: Env, landlord: Address, tenant: Address, reason: String) {
+        Self::check_not_paused(&env);
 
-        let seconds_per_day: u64 = 24 * 60 * 60;
-        let duration_seconds = end_date - start_date;
-        let duration_days = (duration_seconds / seconds_per_day) as u32;
+        landlord.require_auth();
 
-        if duration_days < min_days {
-            panic_with_error!(env, Error::DurationBelowMinimum);
-        }
-        if duration_days > max_days {
-            panic_with_error!(env, Error::DurationAboveMaximum);
+        let mut list = Self::get_blacklisted_tenants(env.clone(), landlord.clone());
+        if !list.contains(&tenant) {
+            list.push_back(tenant.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::LandlordBlacklist(landlord.clone()), &list);
         }
+
+        env.events().publish(
+            (Symbol::new(&env, "TenantBlacklisted"),),
+            (landlord, tenant, reason),
+        );
     }
 
-    fn next_status_after_signature(a: &RentalAgreement) -> AgreementStatus {
-        match (a.tenant_signed, a.landlord_signed) {
-            (true, true) => AgreementStatus::PendingPayment,
-            (true, false) => AgreementStatus::PendingLandlordSign,
-            (false, true) => AgreementStatus::PendingTenantSign,
-            (false, false) => AgreementStatus::Draft,
-        }
+    pub fn remove_from_blacklist(env: Env, landlord: Address, tenant: Address) {
+        Self::check_not_paused(&env);
+
+        landlord.require_auth();
+
+        Self::unlist_tenant(&env, &landlord, &tenant);
     }
 
-    fn index_agreement(env: &Env, a: &RentalAgreement) {
-        let mut list: Vec<BytesN<32>> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::AgreementList)
-            .unwrap_or(Vec::new(env));
-        list.push_back(a.id.clone());
-        env.storage()
-            .persistent()
-            .set(&DataKey::AgreementList, &list);
+    // Admin escape hatch, e.g. for a blacklisting made in error.
+    pub fn admin_override_blacklist(env: Env, landlord: Address, tenant: Address) {
+        Self::check_not_paused(&env);
 
-        let mut by_tenant: Vec<BytesN<32>> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ByTenant(a.tenant.clone()))
-            .unwrap_or(Vec::new(env));
-        by_tenant.push_back(a.id.clone());
-        env.storage()
-            .persistent()
-            .set(&DataKey::ByTenant(a.tenant.clone()), &by_tenant);
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
 
-        let mut by_landlord: Vec<BytesN<32>> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ByLandlord(a.landlord.clone()))
-            .unwrap_or(Vec::new(env));
-        by_landlord.push_back(a.id.clone());
-        env.storage()
-            .persistent()
-            .set(&DataKey::ByLandlord(a.landlord.clone()), &by_landlord);
+        Self::unlist_tenant(&env, &landlord, &tenant);
+    }
 
-        let mut by_property: Vec<BytesN<32>> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ByProperty(a.property_id.clone()))
-            .unwrap_or(Vec::new(env));
-        by_property.push_back(a.id.clone());
+    pub fn is_tenant_blacklisted(env: Env, landlord: Address, tenant: Address) -> bool {
+        Self::get_blacklisted_tenants(env, landlord).contains(&tenant)
+    }
+
+    pub fn get_blacklisted_tenants(env: Env, landlord: Address) -> Vec<Address> {
         env.storage()
             .persistent()
-            .set(&DataKey::ByProperty(a.property_id.clone()), &by_property);
+            .get(&DataKey::LandlordBlacklist(landlord))
+            .unwrap_or(Vec::new(&env))
     }
 
-    fn hydrate_agreements(env: &Env, ids: Vec<BytesN<32>>) -> Vec<RentalAgreement> {
-        let mut out = Vec::<RentalAgreement>::new(env);
-        for id in ids.iter() {
-            if let Some(a) = env
-                .storage()
-                .persistent()
-                .get::<_, RentalAgreement>(&DataKey::Agreement(id))
-            {
-                out.push_back(a);
-            }
+    /// Current tenant and landlord agree to hand the lease to `new_tenant`,
+    /// who then has 7 days to accept.
+    pub fn transfer_tenancy(
+        env: Env,
+        current_tenant: Address,
+        new_tenant: Address,
+        agreement_id: BytesN<32>,
+    ) {
+        Self::check_not_paused(&env);
+
+        current_tenant.require_auth();
+
+        let agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        if current_tenant != agreement.tenant {
+            panic_with_error!(&env, Error::NotAgreementParty);
+        }
+        agreement.landlord.require_auth();
+        if agreement.status != AgreementStatus::Active {
+            panic_with_error!(&env, Error::InvalidState);
+        }
+        if new_tenant == agreement.tenant || new_tenant == agreement.landlord {
+            panic_with_error!(&env, Error::InvalidState);
+        }
+        if Self::is_tenant_blacklisted(env.clone(), agreement.landlord.clone(), new_tenant.clone())
+        {
+            panic_with_error!(&env, Error::TenantBlacklisted);
         }
-        out
-    }
 
-    fn new_id(env: &Env) -> BytesN<32> {
-        env.prng().gen::<BytesN<32>>()
+        let expires_at = env
+            .ledger()
+            .timestamp()
+            .saturating_add(TENANCY_TRANSFER_WINDOW_SECONDS);
+        env.storage().persistent().set(
+            &DataKey::TenancyTransfer(agreement_id.clone()),
+            &TenancyTransfer {
+                new_tenant: new_tenant.clone(),
+                expires_at,
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "TenancyTransferProposed"),),
+            (agreement_id, current_tenant, new_tenant, expires_at),
+        );
     }
-}
 
-#[cfg(test)]
+    /// Payment history (`months_paid`, `total_rent_paid`) stays with the agreement.
+    pub fn accept_tenancy_transfer(env: Env, new_tenant: Address, agreement_id: BytesN<32>) {
+        Self::check_not_paused(&env);
+
+        new_tenant.require_auth();
+
+        let key = DataKey::TenancyTransfer(agreement_id.clone());
+        let transfer: TenancyTransfer = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoPendingTenancyTransfer));
+        if transfer.new_tenant != new_tenant {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        if env.ledger().timestamp() > transfer.expires_at {
+            panic_with_error!(&env, Error::TenancyTransferExpired);
+        }
+        env.storage().persistent().remove(&key);
+
+        let mut agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        if agreement.status != AgreementStatus::Active {
+            panic_with_error!(&env, Error::InvalidState);
+        }
+        let previous_tenant = agreement.tenant.clone();
+        agreement.tenant = new_tenant.clone();
+        // The guarantee and any sublet were arranged for the previous tenant.
+        agreement.co_signer = None;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+        Self::reindex_tenant(&env, &agreement_id, &previous_tenant, &new_tenant);
+
+        let storage = env.storage().persistent();
+        storage.remove(&DataKey::CoSignerSigned(agreement_id.clone()));
+        storage.remove(&DataKey::SubletRequest(agreement_id.clone()));
+        storage.remove(&DataKey::CurrentSubtenant(agreement_id.clone()));
+
+        if let Some(escrow_addr) = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::EscrowContract)
+        {
+            EscrowManagerClient::new(&env, &escrow_addr)
+                .update_escrow_tenant(&agreement_id, &new_tenant);
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "TenancyTransferred"),),
+            (agreement_id, previous_tenant, new_tenant),
+        );
+    }
+
+    /// Tenant asks to sublet an active agreement; only one request may be pending.
+    pub fn request_sublet(
+        env: Env,
+        tenant: Address,
+        agreement_id: BytesN<32>,
+        proposed_subtenant: Address,
+    ) {
+        Self::check_not_paused(&env);
+
+        tenant.require_auth();
+
+        let agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        if tenant != agreement.tenant {
+            panic_with_error!(&env, Error::NotAgreementParty);
+        }
+        if !agreement.subletting_allowed {
+            panic_with_error!(&env, Error::SubletNotAllowed);
+        }
+        if agreement.status != AgreementStatus::Active {
+            panic_with_error!(&env, Error::InvalidState);
+        }
+        if proposed_subtenant == agreement.tenant || proposed_subtenant == agreement.landlord {
+            panic_with_error!(&env, Error::InvalidState);
+        }
+        if let Some(existing) = Self::get_sublet_request(env.clone(), agreement_id.clone()) {
+            if existing.pending {
+                panic_with_error!(&env, Error::SubletAlreadyRequested);
+            }
+        }
+
+        let request = SubletRequest {
+            proposed_subtenant: proposed_subtenant.clone(),
+            requested_at: env.ledger().timestamp(),
+            pending: true,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::SubletRequest(agreement_id.clone()), &request);
+
+        env.events().publish(
+            (Symbol::new(&env, "SubletRequested"),),
+            (agreement_id, tenant, proposed_subtenant),
+        );
+    }
+
+    pub fn approve_sublet(env: Env, landlord: Address, agreement_id: BytesN<32>) {
+        let request = Self::take_pending_sublet(&env, &landlord, &agreement_id);
+
+        env.storage().persistent().set(
+            &DataKey::CurrentSubtenant(agreement_id.clone()),
+            &request.proposed_subtenant,
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "SubletApproved"),),
+            (agreement_id, request.proposed_subtenant),
+        );
+    }
+
+    pub fn reject_sublet(env: Env, landlord: Address, agreement_id: BytesN<32>) {
+        let request = Self::take_pending_sublet(&env, &landlord, &agreement_id);
+
+        env.events().publish(
+            (Symbol::new(&env, "SubletRejected"),),
+            (agreement_id, request.proposed_subtenant),
+        );
+    }
+
+    pub fn get_sublet_request(env: Env, agreement_id: BytesN<32>) -> Option<SubletRequest> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SubletRequest(agreement_id))
+    }
+
+    pub fn get_current_subtenant(env: Env, agreement_id: BytesN<32>) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CurrentSubtenant(agreement_id))
+    }
+
+    /// Stats over the landlord's `limit` most recent agreements (capped at 50).
+    pub fn get_landlord_statistics(env: Env, landlord: Address, limit: u32) -> LandlordStats {
+        let ids: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ByLandlord(landlord))
+            .unwrap_or(Vec::new(&env));
+        let count = limit.min(MAX_STATS_AGREEMENTS).min(ids.len());
+        let recent = ids.slice(ids.len() - count..);
+
+        let mut stats = LandlordStats {
+            total_agreements: 0,
+            active_agreements: 0,
+            completed_agreements: 0,
+            cancelled_agreements: 0,
+            total_rent_collected: 0,
+            total_months_paid: 0,
+            average_tenancy_months: 0,
+        };
+        let mut funded: u32 = 0;
+        for a in Self::hydrate_agreements(&env, recent).iter() {
+            stats.total_agreements += 1;
+            match a.status {
+                AgreementStatus::Active => stats.active_agreements += 1,
+                AgreementStatus::Completed => stats.completed_agreements += 1,
+                AgreementStatus::Cancelled => stats.cancelled_agreements += 1,
+                _ => {}
+            }
+            stats.total_rent_collected =
+                stats.total_rent_collected.saturating_add(a.total_rent_paid);
+            stats.total_months_paid = stats.total_months_paid.saturating_add(a.months_paid);
+            if a.deposit_paid {
+                funded += 1;
+            }
+        }
+        stats.average_tenancy_months = stats.total_months_paid.checked_div(funded).unwrap_or(0);
+        stats
+    }
+
+    pub fn get_payment_schedule(env: Env, agreement_id: BytesN<32>) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PaymentSchedule(agreement_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Due date of the next unpaid month, or None once the schedule is covered.
+    pub fn get_next_payment_due(env: Env, agreement_id: BytesN<32>) -> Option<u64> {
+        let agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        let schedule = Self::get_payment_schedule(env, agreement_id);
+        // schedule[i] falls due once i + 1 months have been paid.
+        schedule.get(agreement.months_paid.saturating_sub(1))
+    }
+
+    pub fn get_total_agreement_count(env: Env) -> u32 {
+        Self::agreement_list(&env).len()
+    }
+
+    /// Page through every agreement in creation order. `limit` is capped at 30.
+    pub fn get_all_agreements_paginated(env: Env, offset: u32, limit: u32) -> Vec<RentalAgreement> {
+        let ids = Self::page_ids(&env, offset, limit);
+        Self::hydrate_agreements(&env, ids)
+    }
+
+    /// Same page window as `get_all_agreements_paginated`, keeping only agreements
+    /// in `status`, so a page may hold fewer than `limit` results.
+    /// (Named `_paged` because contract function names are limited to 32 chars.)
+    pub fn get_agreements_by_status_paged(
+        env: Env,
+        status: AgreementStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<RentalAgreement> {
+        let ids = Self::page_ids(&env, offset, limit);
+        let mut out = Vec::<RentalAgreement>::new(&env);
+        for id in ids.iter() {
+            if let Some(a) = env
+                .storage()
+                .persistent()
+                .get::<_, RentalAgreement>(&DataKey::Agreement(id))
+            {
+                if a.status == status {
+                    out.push_back(a);
+                }
+            }
+        }
+        out
+    }
+
+    /// Agreements whose term overlaps `[from_timestamp, to_timestamp]`, at most 50.
+    /// Admin-only because it walks the full global list.
+    pub fn get_agreements_active_between(
+        env: Env,
+        from_timestamp: u64,
+        to_timestamp: u64,
+    ) -> Vec<RentalAgreement> {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        Self::scan_agreements(&env, |a| {
+            a.start_date <= to_timestamp && a.end_date >= from_timestamp
+        })
+    }
+
+    /// Agreements created within `[from, to]`, at most 50. Admin-only.
+    pub fn get_agreements_created_between(env: Env, from: u64, to: u64) -> Vec<RentalAgreement> {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        Self::scan_agreements(&env, |a| a.created_at >= from && a.created_at <= to)
+    }
+
+    fn check_not_paused(env: &Env) {
+        let paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        if paused {
+            panic_with_error!(env, Error::ContractPaused);
+        }
+    }
+
+    fn clear_pending_admin(env: &Env) {
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        env.storage()
+            .instance()
+            .remove(&DataKey::AdminTransferExpiry);
+    }
+
+    fn require_admin(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized))
+    }
+
+    fn maybe_record_outcome(env: &Env, agreement: &RentalAgreement, completed: bool) {
+        if let Some(credit_addr) = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::CreditContract)
+        {
+            TenantCreditClient::new(env, &credit_addr).update_agreement_outcome(
+                &agreement.id,
+                &agreement.tenant,
+                &completed,
+            );
+        }
+    }
+
+    // This contract has no view of the review system, so the badge is
+    // issued unrated.
+    fn maybe_issue_badge(env: &Env, agreement: &RentalAgreement) {
+        if let Some(token_addr) = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::BadgeContract)
+        {
+            BadgeTokenClient::new(env, &token_addr).issue_rental_badge(
+                &agreement.id,
+                &agreement.tenant,
+                &agreement.months_paid,
+                &0,
+            );
+        }
+    }
+
+    // The agreement is passed along in full because the escrow contract
+    // can't call back into this contract during the handshake.
+    fn notify_escrow_init(env: &Env, agreement: &RentalAgreement) {
+        if let Some(escrow_addr) = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::EscrowContract)
+        {
+            EscrowManagerClient::new(env, &escrow_addr).initialize_escrow_account(
+                &agreement.id,
+                &agreement.landlord,
+                &agreement.tenant,
+                &agreement.security_deposit,
+                &agreement.monthly_rent,
+            );
+        }
+    }
+
+    fn registry_client(env: &Env) -> PropertyRegistryClient<'_> {
+        let registry: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PropertyRegistry)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        PropertyRegistryClient::new(env, &registry)
+    }
+
+    fn fetch_property(env: &Env, property_id: BytesN<32>) -> Property {
+        // If the registry panics for not found, we treat it as inactive/not found.
+        Self::registry_client(env).get_property(&property_id)
+    }
+
+    fn validate_dates_and_duration(
+        env: &Env,
+        start_date: u64,
+        end_date: u64,
+        min_days: u32,
+        max_days: u32,
+    ) {
+        if end_date <= start_date {
+            panic_with_error!(env, Error::InvalidDates);
+        }
+
+        let duration_seconds = end_date - start_date;
+        let duration_days = (duration_seconds / SECONDS_PER_DAY) as u32;
+
+        if duration_days < min_days {
+            panic_with_error!(env, Error::DurationBelowMinimum);
+        }
+        if duration_days > max_days {
+            panic_with_error!(env, Error::DurationAboveMaximum);
+        }
+    }
+
+    fn notice_elapsed(a: &RentalAgreement, now: u64) -> bool {
+        if a.notice_served_by.is_none() {
+            return false;
+        }
+        let notice_seconds = (a.notice_period_days as u64) * SECONDS_PER_DAY;
+        now >= a.notice_served_at.saturating_add(notice_seconds)
+    }
+
+    fn expected_months(a: &RentalAgreement) -> u32 {
+        let duration = a.end_date.saturating_sub(a.start_date);
+        duration.div_ceil(SECONDS_PER_MONTH) as u32
+    }
+
+    // Whole months elapsed since start, capped at the agreement's expected months
+    // so that completed agreements aren't reported as overdue forever.
+    fn months_elapsed(env: &Env, a: &RentalAgreement) -> u32 {
+        let elapsed = env.ledger().timestamp().saturating_sub(a.start_date);
+        let months = (elapsed / SECONDS_PER_MONTH) as u32;
+        months.min(Self::expected_months(a))
+    }
+
+    fn is_overdue(env: &Env, a: &RentalAgreement) -> bool {
+        a.months_paid < Self::months_elapsed(env, a)
+    }
+
+    fn next_status_after_signature(env: &Env, a: &RentalAgreement) -> AgreementStatus {
+        match (a.tenant_signed, a.landlord_signed) {
+            (true, true) if a.co_signer.is_some() && !Self::co_signer_signed(env, &a.id) => {
+                AgreementStatus::PendingCoSignerSign
+            }
+            (true, true) => AgreementStatus::PendingPayment,
+            (true, false) => AgreementStatus::PendingLandlordSign,
+            (false, true) => AgreementStatus::PendingTenantSign,
+            (false, false) => AgreementStatus::Draft,
+        }
+    }
+
+    fn check_landlord_agreement_limit(env: &Env, landlord: &Address) {
+        let max = Self::get_max_agreements_per_landlord(env.clone());
+        if Self::active_count(env, landlord) >= max {
+            panic_with_error!(env, Error::LandlordAgreementLimitReached);
+        }
+    }
+
+    // Number of the landlord's agreements currently Active, kept up to date on
+    // activation, completion and landlord changes so the limit check doesn't
+    // have to load the landlord's whole history.
+    fn active_count(env: &Env, landlord: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ActiveAgreementCount(landlord.clone()))
+            .unwrap_or(0)
+    }
+
+    fn adjust_active_count(env: &Env, landlord: &Address, delta: i32) {
+        let count = Self::active_count(env, landlord).saturating_add_signed(delta);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ActiveAgreementCount(landlord.clone()), &count);
+    }
+
+    fn reindex_tenant(
+        env: &Env,
+        agreement_id: &BytesN<32>,
+        previous_tenant: &Address,
+        new_tenant: &Address,
+    ) {
+        let old_key = DataKey::ByTenant(previous_tenant.clone());
+        let mut old_ids: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&old_key)
+            .unwrap_or(Vec::new(env));
+        if let Some(index) = old_ids.first_index_of(agreement_id) {
+            old_ids.remove(index);
+            env.storage().persistent().set(&old_key, &old_ids);
+        }
+
+        let new_key = DataKey::ByTenant(new_tenant.clone());
+        let mut new_ids: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&new_key)
+            .unwrap_or(Vec::new(env));
+        new_ids.push_back(agreement_id.clone());
+        env.storage().persistent().set(&new_key, &new_ids);
+    }
+
+    fn unlist_tenant(env: &Env, landlord: &Address, tenant: &Address) {
+        let mut list = Self::get_blacklisted_tenants(env.clone(), landlord.clone());
+        let index = list
+            .first_index_of(tenant)
+            .unwrap_or_else(|| panic_with_error!(env, Error::NotBlacklisted));
+        list.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::LandlordBlacklist(landlord.clone()), &list);
+
+        env.events().publish(
+            (Symbol::new(env, "TenantUnblacklisted"),),
+            (landlord.clone(), tenant.clone()),
+        );
+    }
+
+    fn editable_agreement(
+        env: &Env,
+        landlord: &Address,
+        agreement_id: &BytesN<32>,
+    ) -> RentalAgreement {
+        Self::check_not_paused(env);
+
+        landlord.require_auth();
+
+        let agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        if *landlord != agreement.landlord {
+            panic_with_error!(env, Error::Unauthorized);
+        }
+        match agreement.status {
+            AgreementStatus::Draft
+            | AgreementStatus::PendingTenantSign
+            | AgreementStatus::PendingLandlordSign => agreement,
+            _ => panic_with_error!(env, Error::InvalidState),
+        }
+    }
+
+    fn reset_signatures(env: &Env, agreement: &mut RentalAgreement) {
+        agreement.tenant_signed = false;
+        agreement.tenant_signed_at = 0;
+        agreement.landlord_signed = false;
+        agreement.landlord_signed_at = 0;
+        agreement.status = AgreementStatus::Draft;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CoSignerSigned(agreement.id.clone()));
+    }
+
+    fn validate_clause(env: &Env, clause: &String) {
+        if clause.is_empty() || clause.len() > MAX_CLAUSE_LEN {
+            panic_with_error!(env, Error::InvalidClause);
+        }
+    }
+
+    // Landlord-side checks shared by approve/reject; marks the request decided.
+    fn take_pending_sublet(
+        env: &Env,
+        landlord: &Address,
+        agreement_id: &BytesN<32>,
+    ) -> SubletRequest {
+        Self::check_not_paused(env);
+
+        landlord.require_auth();
+
+        let agreement = Self::get_agreement(env.clone(), agreement_id.clone());
+        if *landlord != agreement.landlord {
+            panic_with_error!(env, Error::NotAgreementParty);
+        }
+        let mut request = Self::get_sublet_request(env.clone(), agreement_id.clone())
+            .filter(|r| r.pending)
+            .unwrap_or_else(|| panic_with_error!(env, Error::NoPendingSublet));
+
+        request.pending = false;
+        env.storage()
+            .persistent()
+            .set(&DataKey::SubletRequest(agreement_id.clone()), &request);
+        request
+    }
+
+    fn co_signer_signed(env: &Env, agreement_id: &BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::CoSignerSigned(agreement_id.clone()))
+    }
+
+    fn agreement_list(env: &Env) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AgreementList)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn scan_agreements(env: &Env, keep: impl Fn(&RentalAgreement) -> bool) -> Vec<RentalAgreement> {
+        let mut out = Vec::<RentalAgreement>::new(env);
+        for id in Self::agreement_list(env).iter() {
+            if out.len() >= MAX_RANGE_RESULTS {
+                break;
+            }
+            if let Some(a) = env
+                .storage()
+                .persistent()
+                .get::<_, RentalAgreement>(&DataKey::Agreement(id))
+            {
+                if keep(&a) {
+                    out.push_back(a);
+                }
+            }
+        }
+        out
+    }
+
+    fn page_ids(env: &Env, offset: u32, limit: u32) -> Vec<BytesN<32>> {
+        let list = Self::agreement_list(env);
+        let start = offset.min(list.len());
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(list.len());
+        list.slice(start..end)
+    }
+
+    fn index_agreement(env: &Env, a: &RentalAgreement) {
+        let mut list = Self::agreement_list(env);
+        list.push_back(a.id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::AgreementList, &list);
+
+        let mut by_tenant: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ByTenant(a.tenant.clone()))
+            .unwrap_or(Vec::new(env));
+        by_tenant.push_back(a.id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::ByTenant(a.tenant.clone()), &by_tenant);
+
+        let mut by_landlord: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ByLandlord(a.landlord.clone()))
+            .unwrap_or(Vec::new(env));
+        by_landlord.push_back(a.id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::ByLandlord(a.landlord.clone()), &by_landlord);
+
+        let mut by_property: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ByProperty(a.property_id.clone()))
+            .unwrap_or(Vec::new(env));
+        by_property.push_back(a.id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::ByProperty(a.property_id.clone()), &by_property);
+    }
+
+    fn hydrate_agreements(env: &Env, ids: Vec<BytesN<32>>) -> Vec<RentalAgreement> {
+        let mut out = Vec::<RentalAgreement>::new(env);
+        for id in ids.iter() {
+            if let Some(a) = env
+                .storage()
+                .persistent()
+                .get::<_, RentalAgreement>(&DataKey::Agreement(id))
+            {
+                out.push_back(a);
+            }
+        }
+        out
+    }
+
+    fn new_id(env: &Env) -> BytesN<32> {
+        env.prng().gen::<BytesN<32>>()
+    }
+}
+
+#[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke};
+    use soroban_sdk::IntoVal;
+
+    #[contract]
+    struct MockRegistry;
+
+    #[contractimpl]
+    impl MockRegistry {
+        pub fn set_property(env: Env, property: Property) {
+            env.storage().instance().set(&property.id, &property);
+        }
+
+        pub fn get_property(env: Env, property_id: BytesN<32>) -> Property {
+            env.storage().instance().get(&property_id).unwrap()
+        }
+
+        pub fn update_availability_by_contract(
+            env: Env,
+            property_id: BytesN<32>,
+            is_available: bool,
+        ) {
+            let mut p = Self::get_property(env.clone(), property_id);
+            p.is_available = is_available;
+            env.storage().instance().set(&p.id, &p);
+        }
+    }
+
+    #[contract]
+    struct MockBadge;
+
+    #[contractimpl]
+    impl MockBadge {
+        pub fn issue_rental_badge(
+            env: Env,
+            agreement_id: BytesN<32>,
+            tenant: Address,
+            months_completed: u32,
+            _final_rating: u32,
+        ) {
+            env.storage()
+                .instance()
+                .set(&agreement_id, &(tenant, months_completed));
+        }
+
+        pub fn get_badge(env: Env, agreement_id: BytesN<32>) -> Option<(Address, u32)> {
+            env.storage().instance().get(&agreement_id)
+        }
+    }
+
+    fn setup(env: &Env) -> (RentalAgreementContractClient<'_>, Property) {
+        let (client, registry) = setup_with_registry(env);
+        let property = list_property(env, &registry, &Address::generate(env), 7);
+        (client, property)
+    }
+
+    fn setup_with_registry(
+        env: &Env,
+    ) -> (RentalAgreementContractClient<'_>, MockRegistryClient<'_>) {
+        env.mock_all_auths();
+
+        let registry_id = env.register_contract(None, MockRegistry);
+        let contract_id = env.register_contract(None, RentalAgreementContract);
+        let client = RentalAgreementContractClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &registry_id);
+
+        (client, MockRegistryClient::new(env, &registry_id))
+    }
+
+    fn list_property(
+        env: &Env,
+        registry: &MockRegistryClient<'_>,
+        owner: &Address,
+        seed: u8,
+    ) -> Property {
+        let property = Property {
+            id: BytesN::from_array(env, &[seed; 32]),
+            owner: owner.clone(),
+            title: String::from_str(env, "Loft"),
+            description: String::from_str(env, ""),
+            location: String::from_str(env, "NYC"),
+            price_per_month: 1_000,
+            security_deposit: 500,
+            min_stay_days: 30,
+            max_stay_days: 365,
+            image_url: String::from_str(env, ""),
+            amenities: Vec::new(env),
+            property_type: PropertyType::Apartment,
+            latitude: 0,
+            longitude: 0,
+            floor_area_sqft: 0,
+            furnished: false,
+            parking_spaces: 0,
+            pet_policy: PetPolicy::NoPets,
+            is_verified: false,
+            is_available: true,
+            is_active: true,
+            created_at: 0,
+            updated_at: 0,
+        };
+        registry.set_property(&property);
+        property
+    }
+
+    // Created, signed by both parties and funded.
+    fn active_agreement(
+        env: &Env,
+        client: &RentalAgreementContractClient<'_>,
+        property: &Property,
+        tenant: &Address,
+        start: u64,
+        end: u64,
+    ) -> BytesN<32> {
+        let id = client.create_agreement(
+            &property.owner,
+            &property.id,
+            tenant,
+            &start,
+            &end,
+            &14,
+            &None,
+            &false,
+            &Vec::new(env),
+        );
+        client.landlord_sign(&property.owner, &id);
+        client.tenant_sign(tenant, &id);
+        client.mark_deposit_paid(&id);
+        id
+    }
+
+    #[test]
+    fn admin_force_complete_after_end_date() {
+        let env = Env::default();
+        let (client, property) = setup(&env);
+        let tenant = Address::generate(&env);
+
+        let start = 1_000u64;
+        let end = start + 60 * SECONDS_PER_DAY;
+        let id = active_agreement(&env, &client, &property, &tenant, start, end);
+        let badges = MockBadgeClient::new(&env, &env.register_contract(None, MockBadge));
+        client.set_badge_contract(&badges.address);
+
+        env.ledger().set_timestamp(end);
+        assert_eq!(
+            client.try_admin_force_complete(&id),
+            Err(Ok(Error::InvalidDates.into()))
+        );
+
+        env.ledger().set_timestamp(end + 1);
+        client.admin_force_complete(&id);
+        let agreement = client.get_agreement(&id);
+        assert_eq!(agreement.status, AgreementStatus::Completed);
+        assert_eq!(agreement.completed_at, end + 1);
+        assert_eq!(
+            badges.get_badge(&id),
+            Some((tenant.clone(), agreement.months_paid))
+        );
+        assert_eq!(
+            client.try_admin_force_complete(&id),
+            Err(Ok(Error::InvalidState.into()))
+        );
+    }
+
+    #[test]
+    fn overdue_agreements_for_landlord() {
+        let env = Env::default();
+        let (client, registry) = setup_with_registry(&env);
+        let landlord = Address::generate(&env);
+        let loft = list_property(&env, &registry, &landlord, 1);
+        let studio = list_property(&env, &registry, &landlord, 2);
+
+        let start = 1_000u64;
+        let end = start + 90 * SECONDS_PER_DAY;
+        let behind = active_agreement(&env, &client, &loft, &Address::generate(&env), start, end);
+        let current =
+            active_agreement(&env, &client, &studio, &Address::generate(&env), start, end);
+        client.record_rent_payment(&current, &1_000);
+        client.record_rent_payment(&behind, &1_000);
+        assert!(client.get_overdue_agreements(&landlord, &20).is_empty());
+
+        env.ledger().set_timestamp(start + 2 * SECONDS_PER_MONTH);
+        client.record_rent_payment(&current, &1_000);
+        let overdue = client.get_overdue_agreements(&landlord, &20);
+        assert_eq!(overdue.len(), 1);
+        assert_eq!(overdue.get_unchecked(0).id, behind);
+        assert!(client.get_overdue_agreements(&landlord, &0).is_empty());
+    }
+
+    #[test]
+    fn document_hash_locked_after_draft() {
+        let env = Env::default();
+        let (client, property) = setup(&env);
+        let tenant = Address::generate(&env);
+
+        let start = 1_000u64;
+        let id = client.create_agreement(
+            &property.owner,
+            &property.id,
+            &tenant,
+            &start,
+            &(start + 60 * SECONDS_PER_DAY),
+            &14,
+            &None,
+            &false,
+            &Vec::new(&env),
+        );
+        let hash = BytesN::from_array(&env, &[9; 32]);
+        assert_eq!(
+            client.try_set_document_hash(&tenant, &id, &hash),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+        client.set_document_hash(&property.owner, &id, &hash);
+        assert!(client.verify_document_hash(&id, &hash));
+        assert!(!client.verify_document_hash(&id, &BytesN::from_array(&env, &[0; 32])));
+
+        client.tenant_sign(&tenant, &id);
+        assert_eq!(
+            client.try_set_document_hash(&property.owner, &id, &BytesN::from_array(&env, &[8; 32])),
+            Err(Ok(Error::InvalidState.into()))
+        );
+    }
+
+    #[test]
+    fn agreements_paginate_in_creation_order() {
+        let env = Env::default();
+        let (client, registry) = setup_with_registry(&env);
+        let landlord = Address::generate(&env);
+
+        let start = 1_000u64;
+        let end = start + 60 * SECONDS_PER_DAY;
+        let mut ids = Vec::<BytesN<32>>::new(&env);
+        for seed in 1..=3u8 {
+            let property = list_property(&env, &registry, &landlord, seed);
+            ids.push_back(client.create_agreement(
+                &landlord,
+                &property.id,
+                &Address::generate(&env),
+                &start,
+                &end,
+                &14,
+                &None,
+                &false,
+                &Vec::new(&env),
+            ));
+        }
+        client.cancel_agreement(&landlord, &ids.get_unchecked(1));
+
+        assert_eq!(client.get_total_agreement_count(), 3);
+        let page = client.get_all_agreements_paginated(&1, &5);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.get_unchecked(0).id, ids.get_unchecked(1));
+        assert!(client.get_all_agreements_paginated(&3, &5).is_empty());
+
+        let drafts = client.get_agreements_by_status_paged(&AgreementStatus::Draft, &0, &3);
+        assert_eq!(drafts.len(), 2);
+        let cancelled = client.get_agreements_by_status_paged(&AgreementStatus::Cancelled, &0, &1);
+        assert!(cancelled.is_empty());
+    }
+
+    #[test]
+    fn agreements_by_date_range() {
+        let env = Env::default();
+        let (client, registry) = setup_with_registry(&env);
+        let landlord = Address::generate(&env);
+        let early = list_property(&env, &registry, &landlord, 1);
+        let late = list_property(&env, &registry, &landlord, 2);
+
+        env.ledger().set_timestamp(100);
+        let first = client.create_agreement(
+            &landlord,
+            &early.id,
+            &Address::generate(&env),
+            &1_000,
+            &(1_000 + 60 * SECONDS_PER_DAY),
+            &14,
+            &None,
+            &false,
+            &Vec::new(&env),
+        );
+        env.ledger().set_timestamp(500);
+        let late_start = 1_000 + 90 * SECONDS_PER_DAY;
+        let second = client.create_agreement(
+            &landlord,
+            &late.id,
+            &Address::generate(&env),
+            &late_start,
+            &(late_start + 60 * SECONDS_PER_DAY),
+            &14,
+            &None,
+            &false,
+            &Vec::new(&env),
+        );
+
+        let overlapping = client.get_agreements_active_between(&2_000, &(2_000 + SECONDS_PER_DAY));
+        assert_eq!(overlapping.len(), 1);
+        assert_eq!(overlapping.get_unchecked(0).id, first);
+        assert_eq!(
+            client.get_agreements_active_between(&0, &late_start).len(),
+            2
+        );
+
+        let created = client.get_agreements_created_between(&200, &600);
+        assert_eq!(created.len(), 1);
+        assert_eq!(created.get_unchecked(0).id, second);
+    }
+
+    #[test]
+    fn landlord_statistics() {
+        let env = Env::default();
+        let (client, registry) = setup_with_registry(&env);
+        let landlord = Address::generate(&env);
+
+        let start = 1_000u64;
+        let end = start + 90 * SECONDS_PER_DAY;
+        let active = active_agreement(
+            &env,
+            &client,
+            &list_property(&env, &registry, &landlord, 1),
+            &Address::generate(&env),
+            start,
+            end,
+        );
+        client.record_rent_payment(&active, &1_000);
+        client.record_rent_payment(&active, &1_000);
+        let funded = active_agreement(
+            &env,
+            &client,
+            &list_property(&env, &registry, &landlord, 2),
+            &Address::generate(&env),
+            start,
+            end,
+        );
+        client.record_rent_payment(&funded, &1_000);
+        let draft = client.create_agreement(
+            &landlord,
+            &list_property(&env, &registry, &landlord, 3).id,
+            &Address::generate(&env),
+            &start,
+            &end,
+            &14,
+            &None,
+            &false,
+            &Vec::new(&env),
+        );
+        client.cancel_agreement(&landlord, &draft);
+
+        let stats = client.get_landlord_statistics(&landlord, &50);
+        assert_eq!(stats.total_agreements, 3);
+        assert_eq!(stats.active_agreements, 2);
+        assert_eq!(stats.cancelled_agreements, 1);
+        assert_eq!(stats.total_rent_collected, 3_000);
+        assert_eq!(stats.total_months_paid, 3);
+        assert_eq!(stats.average_tenancy_months, 1);
+
+        // Only the most recent agreement.
+        let recent = client.get_landlord_statistics(&landlord, &1);
+        assert_eq!(recent.total_agreements, 1);
+        assert_eq!(recent.cancelled_agreements, 1);
+        assert_eq!(recent.average_tenancy_months, 0);
+    }
+
+    #[test]
+    fn sublet_request_approve_and_reject() {
+        let env = Env::default();
+        let (client, property) = setup(&env);
+        let tenant = Address::generate(&env);
+        let subtenant = Address::generate(&env);
+
+        let start = 1_000u64;
+        let id = client.create_agreement(
+            &property.owner,
+            &property.id,
+            &tenant,
+            &start,
+            &(start + 90 * SECONDS_PER_DAY),
+            &14,
+            &None,
+            &true,
+            &Vec::new(&env),
+        );
+        client.landlord_sign(&property.owner, &id);
+        client.tenant_sign(&tenant, &id);
+        client.mark_deposit_paid(&id);
+
+        client.request_sublet(&tenant, &id, &subtenant);
+        assert_eq!(
+            client.try_request_sublet(&tenant, &id, &subtenant),
+            Err(Ok(Error::SubletAlreadyRequested.into()))
+        );
+        client.reject_sublet(&property.owner, &id);
+        assert!(!client.get_sublet_request(&id).unwrap().pending);
+        assert_eq!(client.get_current_subtenant(&id), None);
+        assert_eq!(
+            client.try_approve_sublet(&property.owner, &id),
+            Err(Ok(Error::NoPendingSublet.into()))
+        );
+
+        client.request_sublet(&tenant, &id, &subtenant);
+        client.approve_sublet(&property.owner, &id);
+        assert_eq!(client.get_current_subtenant(&id), Some(subtenant));
+    }
+
+    #[test]
+    fn sublet_requires_permission() {
+        let env = Env::default();
+        let (client, property) = setup(&env);
+        let tenant = Address::generate(&env);
+
+        let start = 1_000u64;
+        let id = active_agreement(
+            &env,
+            &client,
+            &property,
+            &tenant,
+            start,
+            start + 90 * SECONDS_PER_DAY,
+        );
+        assert_eq!(
+            client.try_request_sublet(&tenant, &id, &Address::generate(&env)),
+            Err(Ok(Error::SubletNotAllowed.into()))
+        );
+    }
+
+    #[test]
+    fn blacklisted_tenant_cannot_rent() {
+        let env = Env::default();
+        let (client, property) = setup(&env);
+        let tenant = Address::generate(&env);
+
+        let start = 1_000u64;
+        let end = start + 60 * SECONDS_PER_DAY;
+        client.blacklist_tenant(&property.owner, &tenant, &String::from_str(&env, "damage"));
+        assert!(client.is_tenant_blacklisted(&property.owner, &tenant));
+        assert_eq!(
+            client.try_create_agreement(
+                &property.owner,
+                &property.id,
+                &tenant,
+                &start,
+                &end,
+                &14,
+                &None,
+                &false,
+                &Vec::new(&env),
+            ),
+            Err(Ok(Error::TenantBlacklisted.into()))
+        );
+        let request_id = BytesN::from_array(&env, &[5; 32]);
+        assert_eq!(
+            client.try_request_rental(&tenant, &request_id, &property.id, &start, &end),
+            Err(Ok(Error::TenantBlacklisted.into()))
+        );
+
+        client.admin_override_blacklist(&property.owner, &tenant);
+        assert!(client.get_blacklisted_tenants(&property.owner).is_empty());
+        assert_eq!(
+            client.try_remove_from_blacklist(&property.owner, &tenant),
+            Err(Ok(Error::NotBlacklisted.into()))
+        );
+        client.request_rental(&tenant, &request_id, &property.id, &start, &end);
+    }
+
+    #[test]
+    fn clause_edit_resets_signatures() {
+        let env = Env::default();
+        let (client, property) = setup(&env);
+        let tenant = Address::generate(&env);
+
+        let start = 1_000u64;
+        let id = client.create_agreement(
+            &property.owner,
+            &property.id,
+            &tenant,
+            &start,
+            &(start + 60 * SECONDS_PER_DAY),
+            &14,
+            &None,
+            &false,
+            &Vec::new(&env),
+        );
+        let doc = BytesN::from_array(&env, &[9; 32]);
+        client.set_document_hash(&property.owner, &id, &doc);
+        assert!(client.verify_document_hash(&id, &doc));
+
+        client.tenant_sign(&tenant, &id);
+        client.add_custom_clause(&property.owner, &id, &String::from_str(&env, "No smoking"));
+        let agreement = client.get_agreement(&id);
+        assert!(!agreement.tenant_signed);
+        assert_eq!(agreement.status, AgreementStatus::Draft);
+        assert!(!client.verify_document_hash(&id, &doc));
+        assert!(client.verify_document_hash(&id, &client.get_terms_hash(&id)));
+
+        client.tenant_sign(&tenant, &id);
+        client.landlord_sign(&property.owner, &id);
+        assert_eq!(
+            client.try_remove_custom_clause(&property.owner, &id, &0),
+            Err(Ok(Error::InvalidState.into()))
+        );
+    }
+
+    #[test]
+    fn tenancy_transfer_propose_accept_and_expire() {
+        let env = Env::default();
+        let (client, property) = setup(&env);
+        let tenant = Address::generate(&env);
+        let co_signer = Address::generate(&env);
+        let new_tenant = Address::generate(&env);
+
+        let start = 1_000u64;
+        env.ledger().set_timestamp(start);
+        let id = client.create_agreement(
+            &property.owner,
+            &property.id,
+            &tenant,
+            &start,
+            &(start + 90 * SECONDS_PER_DAY),
+            &14,
+            &Some(co_signer.clone()),
+            &true,
+            &Vec::new(&env),
+        );
+        client.landlord_sign(&property.owner, &id);
+        client.tenant_sign(&tenant, &id);
+        client.co_signer_sign(&co_signer, &id);
+        client.mark_deposit_paid(&id);
+        client.request_sublet(&tenant, &id, &Address::generate(&env));
+
+        assert_eq!(
+            client.try_accept_tenancy_transfer(&new_tenant, &id),
+            Err(Ok(Error::NoPendingTenancyTransfer.into()))
+        );
+        client.transfer_tenancy(&tenant, &new_tenant, &id);
+        env.ledger().set_timestamp(start + 7 * SECONDS_PER_DAY + 1);
+        assert_eq!(
+            client.try_accept_tenancy_transfer(&new_tenant, &id),
+            Err(Ok(Error::TenancyTransferExpired.into()))
+        );
+
+        client.transfer_tenancy(&tenant, &new_tenant, &id);
+        assert_eq!(
+            client.try_accept_tenancy_transfer(&tenant, &id),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+        client.accept_tenancy_transfer(&new_tenant, &id);
+
+        let agreement = client.get_agreement(&id);
+        assert_eq!(agreement.tenant, new_tenant);
+        assert_eq!(agreement.co_signer, None);
+        assert!(!client.is_co_signer_signed(&id));
+        assert_eq!(client.get_sublet_request(&id), None);
+        assert!(client.get_agreements_by_tenant(&tenant).is_empty());
+        assert_eq!(client.get_agreements_by_tenant(&new_tenant).len(), 1);
+    }
+
+    #[test]
+    fn landlord_active_agreement_limit() {
+        let env = Env::default();
+        let (client, registry) = setup_with_registry(&env);
+        let landlord = Address::generate(&env);
+        let first = list_property(&env, &registry, &landlord, 1);
+        let second = list_property(&env, &registry, &landlord, 2);
+        client.set_max_agreements_per_landlord(&1);
+
+        let start = 1_000u64;
+        let end = start + 60 * SECONDS_PER_DAY;
+        let id = active_agreement(&env, &client, &first, &Address::generate(&env), start, end);
+
+        assert_eq!(
+            client.try_create_agreement(
+                &landlord,
+                &second.id,
+                &Address::generate(&env),
+                &start,
+                &end,
+                &14,
+                &None,
+                &false,
+                &Vec::new(&env),
+            ),
+            Err(Ok(Error::LandlordAgreementLimitReached.into()))
+        );
+        let request_id = BytesN::from_array(&env, &[3; 32]);
+        client.request_rental(
+            &Address::generate(&env),
+            &request_id,
+            &second.id,
+            &start,
+            &end,
+        );
+        assert_eq!(
+            client.try_approve_request(&landlord, &request_id),
+            Err(Ok(Error::LandlordAgreementLimitReached.into()))
+        );
+
+        env.ledger().set_timestamp(end);
+        client.complete_agreement(&landlord, &id);
+        client.approve_request(&landlord, &request_id);
+    }
+
+    #[test]
+    fn agreement_follows_property_to_new_landlord() {
+        let env = Env::default();
+        let (client, registry) = setup_with_registry(&env);
+        let landlord = Address::generate(&env);
+        let mut property = list_property(&env, &registry, &landlord, 1);
+
+        let start = 1_000u64;
+        let id = active_agreement(
+            &env,
+            &client,
+            &property,
+            &Address::generate(&env),
+            start,
+            start + 60 * SECONDS_PER_DAY,
+        );
+
+        let buyer = Address::generate(&env);
+        assert_eq!(
+            client.try_update_agreement_landlord(&buyer, &id),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+        property.owner = buyer.clone();
+        registry.set_property(&property);
+        client.update_agreement_landlord(&buyer, &id);
+
+        assert_eq!(client.get_agreement(&id).landlord, buyer);
+        assert!(client.get_agreements_by_landlord(&landlord).is_empty());
+        assert_eq!(client.get_agreements_by_landlord(&buyer).len(), 1);
+
+        // The active agreement now counts against the buyer's limit.
+        client.set_max_agreements_per_landlord(&1);
+        let other = list_property(&env, &registry, &landlord, 2);
+        active_agreement(
+            &env,
+            &client,
+            &other,
+            &Address::generate(&env),
+            start,
+            start + 60 * SECONDS_PER_DAY,
+        );
+    }
+
+    #[test]
+    fn payment_progress_and_notice() {
+        let env = Env::default();
+        let (client, property) = setup(&env);
+        let tenant = Address::generate(&env);
+
+        let start = 1_000u64;
+        let end = start + 90 * SECONDS_PER_DAY;
+        let id = client.create_agreement(
+            &property.owner,
+            &property.id,
+            &tenant,
+            &start,
+            &end,
+            &14,
+            &None,
+            &false,
+            &Vec::new(&env),
+        );
+        client.landlord_sign(&property.owner, &id);
+        client.tenant_sign(&tenant, &id);
+        client.mark_deposit_paid(&id);
+        client.record_rent_payment(&id, &1_000);
+
+        let progress = client.get_payment_progress(&id);
+        assert_eq!(progress.expected_months, 3);
+        assert_eq!(progress.expected_total_rent, 3_000);
+        assert_eq!(progress.months_paid, 1);
+        assert!(!progress.is_overdue);
+        assert_eq!(client.get_payment_schedule(&id).len(), 2);
+        assert_eq!(
+            client.get_next_payment_due(&id),
+            Some(start + SECONDS_PER_MONTH)
+        );
+
+        env.ledger().set_timestamp(start + 2 * SECONDS_PER_MONTH);
+        assert!(client.get_payment_progress(&id).is_overdue);
+
+        client.serve_notice(&tenant, &id);
+        assert!(client.try_complete_agreement(&tenant, &id).is_err());
+        env.ledger()
+            .set_timestamp(start + 2 * SECONDS_PER_MONTH + 14 * SECONDS_PER_DAY);
+        client.complete_agreement(&tenant, &id);
+        assert_eq!(client.get_agreement(&id).status, AgreementStatus::Completed);
+    }
+
+    #[test]
+    fn prorated_payment_only_from_escrow() {
+        let env = Env::default();
+        let (client, property) = setup(&env);
+        let tenant = Address::generate(&env);
+
+        let start = 1_000u64;
+        let end = start + 90 * SECONDS_PER_DAY;
+        let id = active_agreement(&env, &client, &property, &tenant, start, end);
+        assert_eq!(
+            client.try_record_prorated_payment(&id, &250),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+
+        let escrow = Address::generate(&env);
+        client.set_escrow_contract(&escrow);
+        env.mock_auths(&[MockAuth {
+            address: &tenant,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "record_prorated_payment",
+                args: (id.clone(), 250i128).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client.try_record_prorated_payment(&id, &250).is_err());
+        assert_eq!(client.get_agreement(&id).total_rent_paid, 0);
+
+        env.mock_all_auths();
+        client.record_prorated_payment(&id, &250);
+        assert_eq!(env.auths()[0].0, escrow);
+        assert_eq!(client.get_agreement(&id).total_rent_paid, 250);
+    }
+
+    #[test]
+    fn co_signer_must_sign_before_payment() {
+        let env = Env::default();
+        let (client, property) = setup(&env);
+        let tenant = Address::generate(&env);
+        let co_signer = Address::generate(&env);
+
+        let start = 1_000u64;
+        let end = start + 90 * SECONDS_PER_DAY;
+        let id = client.create_agreement(
+            &property.owner,
+            &property.id,
+            &tenant,
+            &start,
+            &end,
+            &14,
+            &Some(co_signer.clone()),
+            &false,
+            &Vec::new(&env),
+        );
+        client.landlord_sign(&property.owner, &id);
+        client.tenant_sign(&tenant, &id);
+        assert_eq!(
+            client.get_agreement(&id).status,
+            AgreementStatus::PendingCoSignerSign
+        );
+
+        assert_eq!(
+            client.try_co_signer_sign(&tenant, &id),
+            Err(Ok(Error::NotCoSigner.into()))
+        );
+        client.co_signer_sign(&co_signer, &id);
+        assert!(client.is_co_signer_signed(&id));
+        assert_eq!(
+            client.get_agreement(&id).status,
+            AgreementStatus::PendingPayment
+        );
+    }
 
     #[test]
     fn create_and_sign_agreement() {
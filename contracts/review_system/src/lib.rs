@@ -12,9 +12,12 @@ use soroban_sdk::{
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AgreementStatus {
+    PendingLandlordApproval,
+    Rejected,
     Draft,
     PendingTenantSign,
     PendingLandlordSign,
+    PendingCoSignerSign,
     PendingPayment,
     Active,
     Completed,
@@ -43,11 +46,21 @@ pub struct RentalAgreement {
     pub months_paid: u32,
     pub created_at: u64,
     pub completed_at: u64,
+    pub notice_period_days: u32,
+    pub notice_served_at: u64,
+    pub notice_served_by: Option<Address>,
+    pub document_hash: BytesN<32>,
+    pub co_signer: Option<Address>,
+    pub subletting_allowed: bool,
+    pub custom_clauses: Vec<String>,
 }
 
 #[contractclient(name = "RentalAgreementClient")]
 pub trait RentalAgreementContract {
     fn get_agreement(agreement_id: BytesN<32>) -> RentalAgreement;
+    fn get_agreements_by_property(property_id: BytesN<32>) -> Vec<RentalAgreement>;
+    fn get_agreements_by_tenant(tenant: Address) -> Vec<RentalAgreement>;
+    fn get_agreements_by_landlord(landlord: Address) -> Vec<RentalAgreement>;
 }
 
 // -----------------------------
@@ -56,14 +69,29 @@ pub trait RentalAgreementContract {
 
 #[contractclient(name = "RewardTokenClient")]
 pub trait RewardToken {
-    fn reward_review(agreement_id: BytesN<32>, reviewer: Address);
-    fn reward_mutual_review(agreement_id: BytesN<32>);
+    fn reward_review(caller: Address, agreement_id: BytesN<32>, reviewer: Address);
+    fn reward_mutual_review(caller: Address, agreement_id: BytesN<32>);
 }
 
 // -----------------------------
 // ReviewSystem contract
 // -----------------------------
 
+const MAX_REVIEW_LEN: u32 = 2000;
+const DEFAULT_MIN_REVIEW_LEN: u32 = 20;
+const MAX_MIN_REVIEW_LEN: u32 = 200;
+const MAX_RESPONSE_LEN: u32 = 1000;
+const MAX_PAGE_SIZE: u32 = 20;
+const MAX_BATCH_SIZE: u32 = 20;
+const MAX_FLAG_REASON_LEN: u32 = 200;
+const FLAG_THRESHOLD: u32 = 3;
+const DEFAULT_EDIT_WINDOW_SECONDS: u64 = 48 * 60 * 60;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+const DEFAULT_ELIGIBILITY_DELAY_SECONDS: u64 = 30 * SECONDS_PER_DAY;
+const MAX_ELIGIBILITY_DELAY_SECONDS: u64 = 365 * SECONDS_PER_DAY;
+const DEFAULT_REVIEW_WINDOW_DAYS: u32 = 60;
+const ADMIN_TRANSFER_WINDOW_SECONDS: u64 = 7 * SECONDS_PER_DAY;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
@@ -79,6 +107,16 @@ pub enum Error {
     InvalidRating = 404,
     ReviewTooLong = 405,
     InvalidAgreementState = 406,
+    EditWindowClosed = 407,
+    AlreadyResponded = 408,
+    InvalidDelay = 409,
+    AlreadyFlagged = 410,
+    ReviewTooShort = 411,
+    InvalidMinLength = 412,
+    ReviewWindowClosed = 413,
+    BatchTooLarge = 414,
+    NoPendingAdminTransfer = 415,
+    AdminTransferExpired = 416,
 }
 
 #[contracttype]
@@ -88,6 +126,32 @@ pub enum ReviewerType {
     Landlord,
 }
 
+/// Optional per-dimension ratings, each 1–5. Kept under
+/// `DataKey::SubRatings(review_id)` rather than as an `Option` field on
+/// `Review`, since soroban-sdk 21 can't derive the test XDR conversion for an
+/// optional custom struct inside a contracttype.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubRatings {
+    pub cleanliness: u32,
+    pub communication: u32,
+    pub location: u32,
+    pub value_for_money: u32,
+    pub maintenance: u32,
+}
+
+/// Per-dimension averages × 100 over reviews that included sub-ratings.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AverageSubRatings {
+    pub review_count: u32,
+    pub cleanliness: u32,
+    pub communication: u32,
+    pub location: u32,
+    pub value_for_money: u32,
+    pub maintenance: u32,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Review {
@@ -99,6 +163,66 @@ pub struct Review {
     pub rating: u32,
     pub review_text: String,
     pub created_at: u64,
+    pub is_removed: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewResponse {
+    pub review_id: BytesN<32>,
+    pub responder: Address,
+    pub response_text: String,
+    pub created_at: u64,
+}
+
+/// `average_rating_scaled` is the mean rating × 100 (460 = 4.60).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AverageRating {
+    pub total_rating: u64,
+    pub review_count: u32,
+    pub average_rating_scaled: u32,
+}
+
+/// Both values are averages × 100; the weighted one weights each review by
+/// the agreement's `months_paid`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WeightedScore {
+    pub raw_average_scaled: u32,
+    pub weighted_average_scaled: u32,
+}
+
+/// Checks a stored review against the agreement it claims to be about.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewVerification {
+    pub agreement_exists: bool,
+    pub reviewer_was_party: bool,
+    // Judged on the agreement's current status. Shortened from
+    // `agreement_was_active_or_completed` to fit the 30-character field limit.
+    pub agreement_active_or_completed: bool,
+    // Eligibility delay and review window, evaluated at the review's creation
+    // time. Shortened from `review_within_eligibility_window` for the same reason.
+    pub within_eligibility_window: bool,
+    pub overall_authentic: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformReviewStats {
+    pub total_reviews: u32,
+    pub total_mutual_reviews: u32,
+    pub average_platform_rating_scaled: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewFlag {
+    pub review_id: BytesN<32>,
+    pub flagger: Address,
+    pub reason: String,
+    pub timestamp: u64,
 }
 
 #[contracttype]
@@ -110,6 +234,28 @@ pub enum DataKey {
     Review(BytesN<32>),
     ReviewsByAgreement(BytesN<32>),
     ReviewsByUser(Address),
+    ReviewEditWindowSeconds,
+    ReviewResponse(BytesN<32>),
+    RemovedReview(BytesN<32>),
+    ReviewsAboutUser(Address),
+    ReviewEligibilityDelaySeconds,
+    SubRatings(BytesN<32>),
+    // Optional content hash, kept beside the review like SubRatings.
+    ReviewAttachment(BytesN<32>),
+    AllReviews,
+    TotalReviews,
+    TotalRatingSum,
+    TotalMutualReviews,
+    ReviewFlags(BytesN<32>),
+    FlaggedReviews,
+    MinReviewLength,
+    ReviewWindowDays,
+    ReviewWindowExtension(BytesN<32>),
+    PendingAdmin,
+    AdminTransferExpiry,
+    // Admin-removed reviews per user, subtracted from the index lengths.
+    RemovedGivenCount(Address),
+    RemovedReceivedCount(Address),
 }
 
 #[contract]
@@ -153,6 +299,134 @@ impl ReviewSystem {
         );
     }
 
+    pub fn set_review_edit_window(env: Env, seconds: u64) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ReviewEditWindowSeconds, &seconds);
+
+        env.events()
+            .publish((Symbol::new(&env, "ReviewEditWindowSet"),), seconds);
+    }
+
+    pub fn get_review_edit_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReviewEditWindowSeconds)
+            .unwrap_or(DEFAULT_EDIT_WINDOW_SECONDS)
+    }
+
+    /// Time after agreement start before either party may review (0–365 days).
+    pub fn set_review_eligibility_delay(env: Env, delay_seconds: u64) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        if delay_seconds > MAX_ELIGIBILITY_DELAY_SECONDS {
+            panic_with_error!(&env, Error::InvalidDelay);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ReviewEligibilityDelaySeconds, &delay_seconds);
+
+        env.events().publish(
+            (Symbol::new(&env, "ReviewEligibilityDelaySet"),),
+            delay_seconds,
+        );
+    }
+
+    pub fn get_review_eligibility_delay(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReviewEligibilityDelaySeconds)
+            .unwrap_or(DEFAULT_ELIGIBILITY_DELAY_SECONDS)
+    }
+
+    /// Minimum review text length in characters (at most 200).
+    pub fn set_min_review_length(env: Env, min_length: u32) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        if min_length > MAX_MIN_REVIEW_LEN {
+            panic_with_error!(&env, Error::InvalidMinLength);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MinReviewLength, &min_length);
+
+        env.events()
+            .publish((Symbol::new(&env, "MinReviewLengthSet"),), min_length);
+    }
+
+    pub fn get_min_review_length(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinReviewLength)
+            .unwrap_or(DEFAULT_MIN_REVIEW_LEN)
+    }
+
+    /// Days after `end_date` during which reviews are still accepted.
+    pub fn set_review_window_days(env: Env, days: u32) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ReviewWindowDays, &days);
+
+        env.events()
+            .publish((Symbol::new(&env, "ReviewWindowDaysSet"),), days);
+    }
+
+    pub fn get_review_window_days(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReviewWindowDays)
+            .unwrap_or(DEFAULT_REVIEW_WINDOW_DAYS)
+    }
+
+    /// Gives one agreement extra time to be reviewed. Extensions accumulate.
+    pub fn extend_review_window(env: Env, agreement_id: BytesN<32>, extension_seconds: u64) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let key = DataKey::ReviewWindowExtension(agreement_id.clone());
+        let current: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+        let total = current.saturating_add(extension_seconds);
+        env.storage().persistent().set(&key, &total);
+
+        env.events().publish(
+            (Symbol::new(&env, "ReviewWindowExtended"),),
+            (agreement_id, total),
+        );
+    }
+
+    /// Emits `ReviewWindowClosed` for indexers once the window has elapsed.
+    pub fn close_review_window(env: Env, agreement_id: BytesN<32>) {
+        let agreement = Self::fetch_agreement(&env, agreement_id.clone());
+        if Self::review_window_open(&env, &agreement) {
+            panic_with_error!(&env, Error::NotEligibleYet);
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "ReviewWindowClosed"),),
+            (agreement_id, env.ledger().timestamp()),
+        );
+    }
+
     pub fn pause(env: Env) {
         let admin = Self::require_admin(&env);
         admin.require_auth();
@@ -169,7 +443,89 @@ impl ReviewSystem {
             .publish((Symbol::new(&env, "Unpaused"),), env.ledger().timestamp());
     }
 
-    pub fn can_submit_review(env: Env, agreement_id: BytesN<32>, reviewer: Address) -> bool {
+    /// Starts a two-step admin handover. The proposed admin has 7 days to
+    /// call `accept_admin_transfer`.
+    pub fn propose_admin_transfer(env: Env, current_admin: Address, proposed_admin: Address) {
+        if current_admin != Self::require_admin(&env) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        current_admin.require_auth();
+
+        let expiry = env.ledger().timestamp() + ADMIN_TRANSFER_WINDOW_SECONDS;
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &proposed_admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::AdminTransferExpiry, &expiry);
+
+        env.events().publish(
+            (Symbol::new(&env, "AdminTransferProposed"),),
+            (current_admin, proposed_admin, expiry),
+        );
+    }
+
+    pub fn accept_admin_transfer(env: Env, proposed_admin: Address) {
+        proposed_admin.require_auth();
+
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoPendingAdminTransfer));
+        if pending != proposed_admin {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        let expiry: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AdminTransferExpiry)
+            .unwrap_or(0);
+        if env.ledger().timestamp() > expiry {
+            panic_with_error!(&env, Error::AdminTransferExpired);
+        }
+
+        let previous_admin = Self::require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::Admin, &proposed_admin);
+        Self::clear_pending_admin(&env);
+
+        env.events().publish(
+            (Symbol::new(&env, "AdminTransferred"),),
+            (previous_admin, proposed_admin),
+        );
+    }
+
+    pub fn cancel_admin_transfer(env: Env, current_admin: Address) {
+        if current_admin != Self::require_admin(&env) {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        current_admin.require_auth();
+
+        if !env.storage().instance().has(&DataKey::PendingAdmin) {
+            panic_with_error!(&env, Error::NoPendingAdminTransfer);
+        }
+        Self::clear_pending_admin(&env);
+
+        env.events().publish(
+            (Symbol::new(&env, "AdminTransferCancelled"),),
+            current_admin,
+        );
+    }
+
+    pub fn can_submit_review(
+        env: Env,
+        agreement_id: BytesN<32>,
+        reviewer: Address,
+        review_text_len: u32,
+    ) -> bool {
+        if review_text_len < Self::get_min_review_length(env.clone())
+            || review_text_len > MAX_REVIEW_LEN
+        {
+            return false;
+        }
+
         let agreement = Self::fetch_agreement(&env, agreement_id.clone());
 
         // Must be tenant or landlord.
@@ -183,28 +539,15 @@ impl ReviewSystem {
             _ => return false,
         }
 
-        // 30 day rule since agreement start.
-        let now = env.ledger().timestamp();
-        let thirty_days: u64 = 30 * 24 * 60 * 60;
-        if now < agreement.start_date.saturating_add(thirty_days) {
+        // Eligibility delay since agreement start, and window after end.
+        if !Self::eligibility_elapsed(&env, &agreement)
+            || !Self::review_window_open(&env, &agreement)
+        {
             return false;
         }
 
         // Only one review per reviewer per agreement.
-        let ids = Self::review_ids_by_agreement(&env, &agreement_id);
-        for rid in ids.iter() {
-            if let Some(r) = env
-                .storage()
-                .persistent()
-                .get::<_, Review>(&DataKey::Review(rid))
-            {
-                if r.reviewer == reviewer {
-                    return false;
-                }
-            }
-        }
-
-        true
+        !Self::has_reviewed(&env, &agreement_id, &reviewer)
     }
 
     pub fn submit_review(
@@ -213,17 +556,29 @@ impl ReviewSystem {
         agreement_id: BytesN<32>,
         rating: u32,
         review_text: String,
+        sub_ratings: Option<SubRatings>,
+        attachment_hash: Option<BytesN<32>>,
     ) -> BytesN<32> {
         Self::check_not_paused(&env);
 
         reviewer.require_auth();
 
-        if rating < 1 || rating > 5 {
+        if !(1..=5).contains(&rating) {
             panic_with_error!(&env, Error::InvalidRating);
         }
-        if review_text.len() > 2000 {
-            panic_with_error!(&env, Error::ReviewTooLong);
+        if let Some(sr) = &sub_ratings {
+            let all = [
+                sr.cleanliness,
+                sr.communication,
+                sr.location,
+                sr.value_for_money,
+                sr.maintenance,
+            ];
+            if all.iter().any(|r| !(1..=5).contains(r)) {
+                panic_with_error!(&env, Error::InvalidRating);
+            }
         }
+        Self::validate_review_text(&env, &review_text);
 
         let agreement = Self::fetch_agreement(&env, agreement_id.clone());
         if reviewer != agreement.tenant && reviewer != agreement.landlord {
@@ -236,24 +591,18 @@ impl ReviewSystem {
         }
 
         let now = env.ledger().timestamp();
-        let thirty_days: u64 = 30 * 24 * 60 * 60;
-        if now < agreement.start_date.saturating_add(thirty_days) {
+        if !Self::eligibility_elapsed(&env, &agreement) {
             panic_with_error!(&env, Error::NotEligibleYet);
         }
+        if !Self::review_window_open(&env, &agreement) {
+            panic_with_error!(&env, Error::ReviewWindowClosed);
+        }
 
         // Enforce one-review-per-agreement-per-reviewer.
-        let ids = Self::review_ids_by_agreement(&env, &agreement_id);
-        for rid in ids.iter() {
-            if let Some(r) = env
-                .storage()
-                .persistent()
-                .get::<_, Review>(&DataKey::Review(rid))
-            {
-                if r.reviewer == reviewer {
-                    panic_with_error!(&env, Error::AlreadyReviewed);
-                }
-            }
+        if Self::has_reviewed(&env, &agreement_id, &reviewer) {
+            panic_with_error!(&env, Error::AlreadyReviewed);
         }
+        let ids = Self::review_ids_by_agreement(&env, &agreement_id);
 
         let (reviewee, reviewer_type) = if reviewer == agreement.tenant {
             (agreement.landlord.clone(), ReviewerType::Tenant)
@@ -271,11 +620,28 @@ impl ReviewSystem {
             rating,
             review_text,
             created_at: now,
+            is_removed: false,
         };
 
         env.storage()
             .persistent()
             .set(&DataKey::Review(review_id.clone()), &review);
+        if let Some(sr) = sub_ratings {
+            env.storage()
+                .persistent()
+                .set(&DataKey::SubRatings(review_id.clone()), &sr);
+        }
+        if let Some(hash) = &attachment_hash {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ReviewAttachment(review_id.clone()), hash);
+        }
+
+        // Global index + platform counters
+        let mut all = Self::all_review_ids(&env);
+        all.push_back(review_id.clone());
+        env.storage().persistent().set(&DataKey::AllReviews, &all);
+        Self::adjust_platform_totals(&env, 1, rating as i64);
 
         // Index by agreement
         let mut by_agreement = ids;
@@ -296,6 +662,13 @@ impl ReviewSystem {
             .persistent()
             .set(&DataKey::ReviewsByUser(reviewer.clone()), &by_user);
 
+        // Index by user (reviewee)
+        let mut about_user = Self::review_ids_about_user(&env, &reviewee);
+        about_user.push_back(review_id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReviewsAboutUser(reviewee.clone()), &about_user);
+
         env.events().publish(
             (Symbol::new(&env, "ReviewSubmitted"),),
             (
@@ -304,6 +677,7 @@ impl ReviewSystem {
                 reviewer,
                 reviewee,
                 rating,
+                attachment_hash,
             ),
         );
 
@@ -316,71 +690,699 @@ impl ReviewSystem {
                 (Symbol::new(&env, "MutualReviewCompleted"),),
                 (agreement_id.clone(), now),
             );
+            let mutual: u32 = env
+                .storage()
+                .instance()
+                .get(&DataKey::TotalMutualReviews)
+                .unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&DataKey::TotalMutualReviews, &(mutual + 1));
             Self::maybe_reward_mutual(&env, agreement_id.clone());
         }
 
         review_id
     }
 
-    pub fn get_review(env: Env, review_id: BytesN<32>) -> Review {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Review(review_id))
-            .unwrap_or_else(|| panic_with_error!(&env, Error::AgreementNotFound))
-    }
+    /// Reviewers can fix their text and rating shortly after submitting.
+    pub fn edit_review(
+        env: Env,
+        reviewer: Address,
+        review_id: BytesN<32>,
+        new_text: String,
+        new_rating: u32,
+    ) {
+        Self::check_not_paused(&env);
 
-    pub fn get_reviews_for_agreement(env: Env, agreement_id: BytesN<32>) -> Vec<Review> {
-        let ids = Self::review_ids_by_agreement(&env, &agreement_id);
-        let mut out = Vec::<Review>::new(&env);
-        for rid in ids.iter() {
-            if let Some(r) = env
-                .storage()
-                .persistent()
-                .get::<_, Review>(&DataKey::Review(rid))
-            {
-                out.push_back(r);
-            }
+        reviewer.require_auth();
+
+        if !(1..=5).contains(&new_rating) {
+            panic_with_error!(&env, Error::InvalidRating);
         }
-        out
-    }
+        Self::validate_review_text(&env, &new_text);
 
-    pub fn get_reviews_by_user(env: Env, user: Address) -> Vec<Review> {
-        let ids: Vec<BytesN<32>> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ReviewsByUser(user))
-            .unwrap_or(Vec::new(&env));
-        let mut out = Vec::<Review>::new(&env);
-        for rid in ids.iter() {
-            if let Some(r) = env
-                .storage()
-                .persistent()
-                .get::<_, Review>(&DataKey::Review(rid))
-            {
-                out.push_back(r);
-            }
+        let mut review = Self::get_review(env.clone(), review_id.clone());
+        if review.reviewer != reviewer {
+            panic_with_error!(&env, Error::Unauthorized);
         }
-        out
+        if !Self::within_edit_window(&env, &review) {
+            panic_with_error!(&env, Error::EditWindowClosed);
+        }
+
+        let old_rating = review.rating;
+        if !review.is_removed {
+            Self::adjust_platform_totals(&env, 0, new_rating as i64 - old_rating as i64);
+        }
+        review.rating = new_rating;
+        review.review_text = new_text;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Review(review_id.clone()), &review);
+
+        env.events().publish(
+            (Symbol::new(&env, "ReviewEdited"),),
+            (review_id, reviewer, old_rating, new_rating),
+        );
     }
 
-    fn review_ids_by_agreement(env: &Env, agreement_id: &BytesN<32>) -> Vec<BytesN<32>> {
+    pub fn is_review_editable(env: Env, review_id: BytesN<32>) -> bool {
         env.storage()
             .persistent()
-            .get(&DataKey::ReviewsByAgreement(agreement_id.clone()))
-            .unwrap_or(Vec::new(env))
+            .get::<_, Review>(&DataKey::Review(review_id))
+            .map(|r| Self::within_edit_window(&env, &r))
+            .unwrap_or(false)
     }
 
-    fn has_mutual_reviews(env: &Env, agreement_id: &BytesN<32>) -> bool {
-        let ids = Self::review_ids_by_agreement(env, agreement_id);
-        let mut has_tenant = false;
-        let mut has_landlord = false;
+    /// The reviewee gets one public response per review.
+    pub fn submit_review_response(
+        env: Env,
+        responder: Address,
+        review_id: BytesN<32>,
+        response_text: String,
+    ) {
+        Self::check_not_paused(&env);
 
-        for rid in ids.iter() {
-            if let Some(r) = env
-                .storage()
-                .persistent()
-                .get::<_, Review>(&DataKey::Review(rid))
-            {
+        responder.require_auth();
+
+        if response_text.len() > MAX_RESPONSE_LEN {
+            panic_with_error!(&env, Error::ReviewTooLong);
+        }
+
+        let review = Self::get_review(env.clone(), review_id.clone());
+        if review.reviewee != responder {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        let key = DataKey::ReviewResponse(review_id.clone());
+        if env.storage().persistent().has(&key) {
+            panic_with_error!(&env, Error::AlreadyResponded);
+        }
+
+        let response = ReviewResponse {
+            review_id: review_id.clone(),
+            responder: responder.clone(),
+            response_text,
+            created_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &response);
+
+        env.events().publish(
+            (Symbol::new(&env, "ReviewResponseSubmitted"),),
+            (review_id, responder),
+        );
+    }
+
+    pub fn get_review_response(env: Env, review_id: BytesN<32>) -> Option<ReviewResponse> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReviewResponse(review_id))
+    }
+
+    /// Hides an abusive review from listings. The review itself stays readable
+    /// through `get_review` for audit purposes.
+    pub fn admin_remove_review(env: Env, review_id: BytesN<32>, reason: String) {
+        Self::check_not_paused(&env);
+
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+
+        let mut review = Self::get_review(env.clone(), review_id.clone());
+        if !review.is_removed {
+            Self::adjust_platform_totals(&env, -1, -(review.rating as i64));
+            Self::bump_removed_count(&env, &DataKey::RemovedGivenCount(review.reviewer.clone()));
+            Self::bump_removed_count(
+                &env,
+                &DataKey::RemovedReceivedCount(review.reviewee.clone()),
+            );
+        }
+        review.is_removed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Review(review_id.clone()), &review);
+        env.storage()
+            .persistent()
+            .set(&DataKey::RemovedReview(review_id.clone()), &reason);
+
+        env.events().publish(
+            (Symbol::new(&env, "ReviewRemoved"),),
+            (review_id, admin, reason),
+        );
+    }
+
+    /// Any user can report a review once. Reviews reaching 3 flags are listed
+    /// by `get_flagged_reviews`.
+    pub fn flag_review(env: Env, flagger: Address, review_id: BytesN<32>, reason: String) {
+        Self::check_not_paused(&env);
+
+        flagger.require_auth();
+
+        if reason.len() > MAX_FLAG_REASON_LEN {
+            panic_with_error!(&env, Error::ReviewTooLong);
+        }
+        // Panics if the review doesn't exist.
+        Self::get_review(env.clone(), review_id.clone());
+
+        let mut flags = Self::get_review_flags(env.clone(), review_id.clone());
+        if flags.iter().any(|f| f.flagger == flagger) {
+            panic_with_error!(&env, Error::AlreadyFlagged);
+        }
+        flags.push_back(ReviewFlag {
+            review_id: review_id.clone(),
+            flagger: flagger.clone(),
+            reason,
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReviewFlags(review_id.clone()), &flags);
+
+        if flags.len() == FLAG_THRESHOLD {
+            let mut flagged = Self::flagged_review_ids(&env);
+            flagged.push_back(review_id.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::FlaggedReviews, &flagged);
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "ReviewFlagged"),),
+            (review_id, flagger, flags.len()),
+        );
+    }
+
+    pub fn get_review_flags(env: Env, review_id: BytesN<32>) -> Vec<ReviewFlag> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReviewFlags(review_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Reviews with 3+ flags that haven't been removed yet.
+    pub fn get_flagged_reviews(env: Env) -> Vec<BytesN<32>> {
+        let mut out = Vec::new(&env);
+        for rid in Self::flagged_review_ids(&env).iter() {
+            if let Some(r) = env
+                .storage()
+                .persistent()
+                .get::<_, Review>(&DataKey::Review(rid.clone()))
+            {
+                if !r.is_removed {
+                    out.push_back(rid);
+                }
+            }
+        }
+        out
+    }
+
+    /// Empty if the review hasn't been removed.
+    pub fn get_removal_reason(env: Env, review_id: BytesN<32>) -> String {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RemovedReview(review_id))
+            .unwrap_or(String::from_str(&env, ""))
+    }
+
+    /// Completed agreements (as tenant or landlord) that `user` is eligible to
+    /// review but hasn't yet. This makes two cross-contract list calls plus a
+    /// storage read per existing review, so clients should cache the result
+    /// off-chain rather than poll it.
+    pub fn get_pending_review_agreements(env: Env, user: Address) -> Vec<BytesN<32>> {
+        let client = Self::agreement_client(&env);
+        let mut agreements = client.get_agreements_by_tenant(&user);
+        agreements.append(&client.get_agreements_by_landlord(&user));
+
+        let mut out = Vec::new(&env);
+        for a in agreements.iter() {
+            if a.status == AgreementStatus::Completed
+                && Self::eligibility_elapsed(&env, &a)
+                && Self::review_window_open(&env, &a)
+                && !Self::has_reviewed(&env, &a.id, &user)
+            {
+                out.push_back(a.id);
+            }
+        }
+        out
+    }
+
+    pub fn get_review(env: Env, review_id: BytesN<32>) -> Review {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Review(review_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::AgreementNotFound))
+    }
+
+    /// Read-only anti-fraud check for dispute escalation.
+    pub fn verify_review_authenticity(env: Env, review_id: BytesN<32>) -> ReviewVerification {
+        let review = Self::get_review(env.clone(), review_id);
+
+        let mut verification = ReviewVerification {
+            agreement_exists: false,
+            reviewer_was_party: false,
+            agreement_active_or_completed: false,
+            within_eligibility_window: false,
+            overall_authentic: false,
+        };
+        let Ok(Ok(agreement)) =
+            Self::agreement_client(&env).try_get_agreement(&review.agreement_id)
+        else {
+            return verification;
+        };
+
+        verification.agreement_exists = true;
+        verification.reviewer_was_party = match review.reviewer_type {
+            ReviewerType::Tenant => {
+                review.reviewer == agreement.tenant && review.reviewee == agreement.landlord
+            }
+            ReviewerType::Landlord => {
+                review.reviewer == agreement.landlord && review.reviewee == agreement.tenant
+            }
+        };
+        verification.agreement_active_or_completed = matches!(
+            agreement.status,
+            AgreementStatus::Active | AgreementStatus::Completed
+        );
+        verification.within_eligibility_window =
+            Self::eligibility_elapsed_at(&env, &agreement, review.created_at)
+                && Self::review_window_open_at(&env, &agreement, review.created_at);
+        verification.overall_authentic = verification.reviewer_was_party
+            && verification.agreement_active_or_completed
+            && verification.within_eligibility_window;
+        verification
+    }
+
+    /// Hydrates up to 20 reviews at once, skipping missing or removed ones.
+    pub fn batch_get_reviews(env: Env, review_ids: Vec<BytesN<32>>) -> Vec<Review> {
+        if review_ids.len() > MAX_BATCH_SIZE {
+            panic_with_error!(&env, Error::BatchTooLarge);
+        }
+
+        let mut out = Vec::<Review>::new(&env);
+        for rid in review_ids.iter() {
+            if let Some(r) = env
+                .storage()
+                .persistent()
+                .get::<_, Review>(&DataKey::Review(rid))
+            {
+                if !r.is_removed {
+                    out.push_back(r);
+                }
+            }
+        }
+        out
+    }
+
+    pub fn get_reviews_for_agreement(env: Env, agreement_id: BytesN<32>) -> Vec<Review> {
+        let ids = Self::review_ids_by_agreement(&env, &agreement_id);
+        let mut out = Vec::<Review>::new(&env);
+        for rid in ids.iter() {
+            if let Some(r) = env
+                .storage()
+                .persistent()
+                .get::<_, Review>(&DataKey::Review(rid))
+            {
+                if !r.is_removed {
+                    out.push_back(r);
+                }
+            }
+        }
+        out
+    }
+
+    /// Deprecated: returns only reviews `user` wrote. Use
+    /// `get_reviews_given_by_user` or `get_reviews_received_by_user`.
+    pub fn get_reviews_by_user(env: Env, user: Address) -> Vec<Review> {
+        Self::get_reviews_given_by_user(env, user)
+    }
+
+    /// Reviews `user` wrote, excluding removed ones.
+    pub fn get_reviews_given_by_user(env: Env, user: Address) -> Vec<Review> {
+        let ids: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReviewsByUser(user))
+            .unwrap_or(Vec::new(&env));
+        Self::visible_reviews(&env, ids)
+    }
+
+    /// Reviews written about `user`, excluding removed ones.
+    pub fn get_reviews_received_by_user(env: Env, user: Address) -> Vec<Review> {
+        let ids = Self::review_ids_about_user(&env, &user);
+        Self::visible_reviews(&env, ids)
+    }
+
+    /// Totals exclude removed reviews.
+    pub fn get_platform_review_stats(env: Env) -> PlatformReviewStats {
+        let total_reviews: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalReviews)
+            .unwrap_or(0);
+        let rating_sum: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalRatingSum)
+            .unwrap_or(0);
+        let average_platform_rating_scaled = if total_reviews == 0 {
+            0
+        } else {
+            (rating_sum * 100 / total_reviews as u64) as u32
+        };
+
+        PlatformReviewStats {
+            total_reviews,
+            total_mutual_reviews: env
+                .storage()
+                .instance()
+                .get(&DataKey::TotalMutualReviews)
+                .unwrap_or(0),
+            average_platform_rating_scaled,
+        }
+    }
+
+    /// Number of reviews created after `since_timestamp` (linear scan).
+    pub fn get_review_activity_since(env: Env, since_timestamp: u64) -> u32 {
+        let mut count = 0;
+        for rid in Self::all_review_ids(&env).iter() {
+            if let Some(r) = env
+                .storage()
+                .persistent()
+                .get::<_, Review>(&DataKey::Review(rid))
+            {
+                if r.created_at > since_timestamp {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// Number of reviews `user` has written, read from the index without
+    /// loading them. Excludes reviews removed by an admin.
+    pub fn get_review_count_for_user(env: Env, user: Address) -> u32 {
+        let written = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<BytesN<32>>>(&DataKey::ReviewsByUser(user.clone()))
+            .map(|ids| ids.len())
+            .unwrap_or(0);
+        written.saturating_sub(Self::removed_count(&env, &DataKey::RemovedGivenCount(user)))
+    }
+
+    /// Number of reviews `user` has received, excluding removed ones. Named
+    /// `get_received_review_count` because `get_reviews_received_count_for_user`
+    /// is over Soroban's 32-character function name limit.
+    pub fn get_received_review_count(env: Env, user: Address) -> u32 {
+        Self::review_ids_about_user(&env, &user)
+            .len()
+            .saturating_sub(Self::removed_count(
+                &env,
+                &DataKey::RemovedReceivedCount(user),
+            ))
+    }
+
+    /// Pages over the user's review index before loading anything, so a page
+    /// holds fewer than `limit` reviews when some in it were removed.
+    pub fn get_reviews_by_user_paginated(
+        env: Env,
+        user: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Review> {
+        let ids: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReviewsByUser(user))
+            .unwrap_or(Vec::new(&env));
+        Self::visible_reviews(&env, Self::page_ids(ids, offset, limit))
+    }
+
+    /// Same paging rule as `get_reviews_by_user_paginated`.
+    pub fn get_agreement_reviews_paged(
+        env: Env,
+        agreement_id: BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Review> {
+        let ids = Self::review_ids_by_agreement(&env, &agreement_id);
+        Self::visible_reviews(&env, Self::page_ids(ids, offset, limit))
+    }
+
+    /// Reviews written by `user`, ordered by rating. Ties keep submission order.
+    pub fn get_reviews_sorted_by_rating(env: Env, user: Address, descending: bool) -> Vec<Review> {
+        let mut sorted = Vec::<Review>::new(&env);
+        for review in Self::get_reviews_given_by_user(env.clone(), user).iter() {
+            // Insertion sort; per-user review counts are small.
+            let mut pos = sorted.len();
+            while pos > 0 {
+                let prev = sorted.get_unchecked(pos - 1).rating;
+                let in_order = if descending {
+                    prev >= review.rating
+                } else {
+                    prev <= review.rating
+                };
+                if in_order {
+                    break;
+                }
+                pos -= 1;
+            }
+            sorted.insert(pos, review);
+        }
+        sorted
+    }
+
+    /// Average of the non-removed reviews `user` has received.
+    pub fn get_average_rating_for_user(env: Env, user: Address) -> AverageRating {
+        let ids = Self::review_ids_about_user(&env, &user);
+        Self::average_rating(&env, ids, |r| r.reviewee == user)
+    }
+
+    /// Average of tenant reviews across every agreement for the property.
+    pub fn get_average_rating_for_property(env: Env, property_id: BytesN<32>) -> AverageRating {
+        let agreements = Self::agreement_client(&env).get_agreements_by_property(&property_id);
+
+        let mut ids = Vec::<BytesN<32>>::new(&env);
+        for a in agreements.iter() {
+            ids.append(&Self::review_ids_by_agreement(&env, &a.id));
+        }
+        Self::average_rating(&env, ids, |r| r.reviewer_type == ReviewerType::Tenant)
+    }
+
+    /// Opaque content hash (e.g. an IPFS CID digest) committed at submission.
+    /// Stored under its own key for the same reason as `SubRatings`.
+    pub fn get_review_attachment(env: Env, review_id: BytesN<32>) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReviewAttachment(review_id))
+    }
+
+    pub fn get_review_sub_ratings(env: Env, review_id: BytesN<32>) -> Option<SubRatings> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SubRatings(review_id))
+    }
+
+    /// Recomputed on every call with one cross-contract agreement lookup per
+    /// review received, so this gets expensive for users with many reviews.
+    pub fn get_weighted_score_for_user(env: Env, user: Address) -> WeightedScore {
+        let client = Self::agreement_client(&env);
+        let mut weighted_sum: u64 = 0;
+        let mut total_months: u64 = 0;
+        for rid in Self::review_ids_about_user(&env, &user).iter() {
+            if let Some(r) = env
+                .storage()
+                .persistent()
+                .get::<_, Review>(&DataKey::Review(rid))
+            {
+                if r.is_removed || r.reviewee != user {
+                    continue;
+                }
+                let months = client.get_agreement(&r.agreement_id).months_paid as u64;
+                weighted_sum += r.rating as u64 * months;
+                total_months += months;
+            }
+        }
+
+        let weighted_average_scaled =
+            (weighted_sum * 100).checked_div(total_months).unwrap_or(0) as u32;
+        WeightedScore {
+            raw_average_scaled: Self::get_average_rating_for_user(env.clone(), user)
+                .average_rating_scaled,
+            weighted_average_scaled,
+        }
+    }
+
+    pub fn get_sub_rating_averages_for_user(env: Env, user: Address) -> AverageSubRatings {
+        let mut count: u32 = 0;
+        let mut sums = [0u64; 5];
+        for rid in Self::review_ids_about_user(&env, &user).iter() {
+            if let Some(r) = env
+                .storage()
+                .persistent()
+                .get::<_, Review>(&DataKey::Review(rid))
+            {
+                if r.is_removed || r.reviewee != user {
+                    continue;
+                }
+                if let Some(sr) = env
+                    .storage()
+                    .persistent()
+                    .get::<_, SubRatings>(&DataKey::SubRatings(r.id))
+                {
+                    count += 1;
+                    for (sum, v) in sums.iter_mut().zip([
+                        sr.cleanliness,
+                        sr.communication,
+                        sr.location,
+                        sr.value_for_money,
+                        sr.maintenance,
+                    ]) {
+                        *sum += v as u64;
+                    }
+                }
+            }
+        }
+
+        let avg = |sum: u64| {
+            if count == 0 {
+                0
+            } else {
+                (sum * 100 / count as u64) as u32
+            }
+        };
+        AverageSubRatings {
+            review_count: count,
+            cleanliness: avg(sums[0]),
+            communication: avg(sums[1]),
+            location: avg(sums[2]),
+            value_for_money: avg(sums[3]),
+            maintenance: avg(sums[4]),
+        }
+    }
+
+    fn average_rating(
+        env: &Env,
+        ids: Vec<BytesN<32>>,
+        include: impl Fn(&Review) -> bool,
+    ) -> AverageRating {
+        let mut total_rating: u64 = 0;
+        let mut review_count: u32 = 0;
+        for rid in ids.iter() {
+            if let Some(r) = env
+                .storage()
+                .persistent()
+                .get::<_, Review>(&DataKey::Review(rid))
+            {
+                if !r.is_removed && include(&r) {
+                    total_rating += r.rating as u64;
+                    review_count += 1;
+                }
+            }
+        }
+
+        let average_rating_scaled = if review_count == 0 {
+            0
+        } else {
+            (total_rating * 100 / review_count as u64) as u32
+        };
+        AverageRating {
+            total_rating,
+            review_count,
+            average_rating_scaled,
+        }
+    }
+
+    fn flagged_review_ids(env: &Env) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FlaggedReviews)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn all_review_ids(env: &Env) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AllReviews)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn adjust_platform_totals(env: &Env, review_delta: i32, rating_delta: i64) {
+        let total: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalReviews)
+            .unwrap_or(0);
+        let sum: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalRatingSum)
+            .unwrap_or(0);
+        env.storage().instance().set(
+            &DataKey::TotalReviews,
+            &total.saturating_add_signed(review_delta),
+        );
+        env.storage().instance().set(
+            &DataKey::TotalRatingSum,
+            &sum.saturating_add_signed(rating_delta),
+        );
+    }
+
+    fn page_ids(ids: Vec<BytesN<32>>, offset: u32, limit: u32) -> Vec<BytesN<32>> {
+        let start = offset.min(ids.len());
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(ids.len());
+        ids.slice(start..end)
+    }
+
+    fn removed_count(env: &Env, key: &DataKey) -> u32 {
+        env.storage().persistent().get(key).unwrap_or(0)
+    }
+
+    fn bump_removed_count(env: &Env, key: &DataKey) {
+        let count = Self::removed_count(env, key) + 1;
+        env.storage().persistent().set(key, &count);
+    }
+
+    fn review_ids_about_user(env: &Env, user: &Address) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReviewsAboutUser(user.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn visible_reviews(env: &Env, ids: Vec<BytesN<32>>) -> Vec<Review> {
+        let mut out = Vec::<Review>::new(env);
+        for rid in ids.iter() {
+            if let Some(r) = env
+                .storage()
+                .persistent()
+                .get::<_, Review>(&DataKey::Review(rid))
+            {
+                if !r.is_removed {
+                    out.push_back(r);
+                }
+            }
+        }
+        out
+    }
+
+    fn review_ids_by_agreement(env: &Env, agreement_id: &BytesN<32>) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReviewsByAgreement(agreement_id.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn has_mutual_reviews(env: &Env, agreement_id: &BytesN<32>) -> bool {
+        let ids = Self::review_ids_by_agreement(env, agreement_id);
+        let mut has_tenant = false;
+        let mut has_landlord = false;
+
+        for rid in ids.iter() {
+            if let Some(r) = env
+                .storage()
+                .persistent()
+                .get::<_, Review>(&DataKey::Review(rid))
+            {
                 match r.reviewer_type {
                     ReviewerType::Tenant => has_tenant = true,
                     ReviewerType::Landlord => has_landlord = true,
@@ -391,6 +1393,59 @@ impl ReviewSystem {
         has_tenant && has_landlord
     }
 
+    fn has_reviewed(env: &Env, agreement_id: &BytesN<32>, reviewer: &Address) -> bool {
+        Self::review_ids_by_agreement(env, agreement_id)
+            .iter()
+            .filter_map(|rid| {
+                env.storage()
+                    .persistent()
+                    .get::<_, Review>(&DataKey::Review(rid))
+            })
+            .any(|r| r.reviewer == *reviewer)
+    }
+
+    fn validate_review_text(env: &Env, text: &String) {
+        if text.len() < Self::get_min_review_length(env.clone()) {
+            panic_with_error!(env, Error::ReviewTooShort);
+        }
+        if text.len() > MAX_REVIEW_LEN {
+            panic_with_error!(env, Error::ReviewTooLong);
+        }
+    }
+
+    fn eligibility_elapsed(env: &Env, agreement: &RentalAgreement) -> bool {
+        Self::eligibility_elapsed_at(env, agreement, env.ledger().timestamp())
+    }
+
+    fn eligibility_elapsed_at(env: &Env, agreement: &RentalAgreement, at: u64) -> bool {
+        let delay = Self::get_review_eligibility_delay(env.clone());
+        at >= agreement.start_date.saturating_add(delay)
+    }
+
+    fn review_window_open(env: &Env, agreement: &RentalAgreement) -> bool {
+        Self::review_window_open_at(env, agreement, env.ledger().timestamp())
+    }
+
+    fn review_window_open_at(env: &Env, agreement: &RentalAgreement, at: u64) -> bool {
+        let window = Self::get_review_window_days(env.clone()) as u64 * SECONDS_PER_DAY;
+        let extension: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReviewWindowExtension(agreement.id.clone()))
+            .unwrap_or(0);
+        at <= agreement
+            .end_date
+            .saturating_add(window)
+            .saturating_add(extension)
+    }
+
+    fn within_edit_window(env: &Env, review: &Review) -> bool {
+        let window = Self::get_review_edit_window(env.clone());
+        env.ledger().timestamp() < review.created_at.saturating_add(window)
+    }
+
+    // Reward failures (e.g. this contract not yet registered as a BRIQ minter
+    // after an upgrade) must not block the review itself.
     fn maybe_reward_review(env: &Env, agreement_id: BytesN<32>, reviewer: &Address) {
         if let Some(token_addr) = env
             .storage()
@@ -398,7 +1453,8 @@ impl ReviewSystem {
             .get::<_, Address>(&DataKey::RewardToken)
         {
             let client = RewardTokenClient::new(env, &token_addr);
-            client.reward_review(&agreement_id, reviewer);
+            let _ =
+                client.try_reward_review(&env.current_contract_address(), &agreement_id, reviewer);
         }
     }
 
@@ -409,18 +1465,21 @@ impl ReviewSystem {
             .get::<_, Address>(&DataKey::RewardToken)
         {
             let client = RewardTokenClient::new(env, &token_addr);
-            client.reward_mutual_review(&agreement_id);
+            let _ = client.try_reward_mutual_review(&env.current_contract_address(), &agreement_id);
         }
     }
 
-    fn fetch_agreement(env: &Env, agreement_id: BytesN<32>) -> RentalAgreement {
+    fn agreement_client(env: &Env) -> RentalAgreementClient<'_> {
         let addr: Address = env
             .storage()
             .instance()
             .get(&DataKey::AgreementContract)
             .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
-        let client = RentalAgreementClient::new(env, &addr);
-        client.get_agreement(&agreement_id)
+        RentalAgreementClient::new(env, &addr)
+    }
+
+    fn fetch_agreement(env: &Env, agreement_id: BytesN<32>) -> RentalAgreement {
+        Self::agreement_client(env).get_agreement(&agreement_id)
     }
 
     fn check_not_paused(env: &Env) {
@@ -434,6 +1493,13 @@ impl ReviewSystem {
         }
     }
 
+    fn clear_pending_admin(env: &Env) {
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        env.storage()
+            .instance()
+            .remove(&DataKey::AdminTransferExpiry);
+    }
+
     fn require_admin(env: &Env) -> Address {
         env.storage()
             .instance()
@@ -449,7 +1515,105 @@ impl ReviewSystem {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    #[contract]
+    struct MockAgreement;
+
+    #[contractimpl]
+    impl MockAgreement {
+        pub fn set_agreement(env: Env, agreement: RentalAgreement) {
+            env.storage().instance().set(&agreement.id, &agreement);
+        }
+
+        pub fn get_agreement(env: Env, agreement_id: BytesN<32>) -> RentalAgreement {
+            env.storage().instance().get(&agreement_id).unwrap()
+        }
+    }
+
+    fn setup(env: &Env) -> (ReviewSystemClient<'_>, RentalAgreement) {
+        let (client, _, agreement) = setup_with_agreements(env);
+        (client, agreement)
+    }
+
+    fn setup_with_agreements(
+        env: &Env,
+    ) -> (
+        ReviewSystemClient<'_>,
+        MockAgreementClient<'_>,
+        RentalAgreement,
+    ) {
+        env.mock_all_auths();
+
+        let agreement_contract = env.register_contract(None, MockAgreement);
+        let agreement = RentalAgreement {
+            id: BytesN::from_array(env, &[1; 32]),
+            property_id: BytesN::from_array(env, &[2; 32]),
+            landlord: Address::generate(env),
+            tenant: Address::generate(env),
+            monthly_rent: 1_000,
+            security_deposit: 500,
+            start_date: 0,
+            end_date: 365 * 24 * 60 * 60,
+            status: AgreementStatus::Active,
+            landlord_signed: true,
+            landlord_signed_at: 0,
+            tenant_signed: true,
+            tenant_signed_at: 0,
+            deposit_paid: true,
+            deposit_paid_at: 0,
+            total_rent_paid: 1_000,
+            months_paid: 1,
+            created_at: 0,
+            completed_at: 0,
+            notice_period_days: 30,
+            notice_served_at: 0,
+            notice_served_by: None,
+            document_hash: BytesN::from_array(env, &[0; 32]),
+            co_signer: None,
+            subletting_allowed: false,
+            custom_clauses: Vec::new(env),
+        };
+        let agreements = MockAgreementClient::new(env, &agreement_contract);
+        agreements.set_agreement(&agreement);
+
+        let contract_id = env.register_contract(None, ReviewSystem);
+        let client = ReviewSystemClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &agreement_contract);
+
+        env.ledger().with_mut(|l| l.timestamp = 31 * 24 * 60 * 60);
+        (client, agreements, agreement)
+    }
+
+    // Another agreement between the same parties.
+    fn copy_agreement(
+        env: &Env,
+        agreements: &MockAgreementClient<'_>,
+        agreement: &RentalAgreement,
+        seed: u8,
+    ) -> RentalAgreement {
+        let mut copy = agreement.clone();
+        copy.id = BytesN::from_array(env, &[seed; 32]);
+        agreements.set_agreement(&copy);
+        copy
+    }
+
+    fn submit(
+        env: &Env,
+        client: &ReviewSystemClient<'_>,
+        reviewer: &Address,
+        agreement: &RentalAgreement,
+        rating: u32,
+    ) -> BytesN<32> {
+        client.submit_review(
+            reviewer,
+            &agreement.id,
+            &rating,
+            &String::from_str(env, "Would rent here again"),
+            &None,
+            &None,
+        )
+    }
 
     #[test]
     fn initialize_smoke() {
@@ -466,4 +1630,358 @@ mod tests {
         client.pause();
         client.unpause();
     }
+
+    #[test]
+    fn edit_review_within_window() {
+        let env = Env::default();
+        let (client, agreement) = setup(&env);
+
+        let review_id = client.submit_review(
+            &agreement.tenant,
+            &agreement.id,
+            &3,
+            &String::from_str(&env, "Nice plcae, quiet street"),
+            &None,
+            &None,
+        );
+        client.edit_review(
+            &agreement.tenant,
+            &review_id,
+            &String::from_str(&env, "Nice place, quiet street"),
+            &4,
+        );
+        assert_eq!(client.get_review(&review_id).rating, 4);
+
+        env.ledger()
+            .with_mut(|l| l.timestamp += DEFAULT_EDIT_WINDOW_SECONDS);
+        assert!(!client.is_review_editable(&review_id));
+        assert_eq!(
+            client.try_edit_review(
+                &agreement.tenant,
+                &review_id,
+                &String::from_str(&env, "Great place, quiet street"),
+                &5,
+            ),
+            Err(Ok(Error::EditWindowClosed.into()))
+        );
+    }
+
+    #[test]
+    fn paged_queries_slice_the_index_first() {
+        let env = Env::default();
+        let (client, agreements, agreement) = setup_with_agreements(&env);
+        let tenant = agreement.tenant.clone();
+
+        let first = submit(&env, &client, &tenant, &agreement, 5);
+        let second_agreement = copy_agreement(&env, &agreements, &agreement, 3);
+        let second = submit(&env, &client, &tenant, &second_agreement, 4);
+        let third_agreement = copy_agreement(&env, &agreements, &agreement, 4);
+        let third = submit(&env, &client, &tenant, &third_agreement, 3);
+        client.admin_remove_review(&second, &String::from_str(&env, "Spam"));
+
+        // The removed review still occupies its slot in the page.
+        let page = client.get_reviews_by_user_paginated(&tenant, &0, &2);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get_unchecked(0).id, first);
+        let page = client.get_reviews_by_user_paginated(&tenant, &2, &2);
+        assert_eq!(page.get_unchecked(0).id, third);
+        assert!(client
+            .get_reviews_by_user_paginated(&tenant, &5, &2)
+            .is_empty());
+
+        let landlord_review = submit(&env, &client, &agreement.landlord, &agreement, 4);
+        let page = client.get_agreement_reviews_paged(&agreement.id, &1, &20);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get_unchecked(0).id, landlord_review);
+    }
+
+    #[test]
+    fn reviewee_responds_once() {
+        let env = Env::default();
+        let (client, agreement) = setup(&env);
+
+        let review_id = submit(&env, &client, &agreement.tenant, &agreement, 3);
+        assert_eq!(client.get_review_response(&review_id), None);
+
+        let reply = String::from_str(&env, "Thanks!");
+        assert_eq!(
+            client.try_submit_review_response(&agreement.tenant, &review_id, &reply),
+            Err(Ok(Error::Unauthorized.into()))
+        );
+        client.submit_review_response(&agreement.landlord, &review_id, &reply);
+        let response = client.get_review_response(&review_id).unwrap();
+        assert_eq!(response.responder, agreement.landlord);
+        assert_eq!(response.response_text, reply);
+        assert_eq!(
+            client.try_submit_review_response(&agreement.landlord, &review_id, &reply),
+            Err(Ok(Error::AlreadyResponded.into()))
+        );
+    }
+
+    #[test]
+    fn admin_removal_hides_review_and_keeps_reason() {
+        let env = Env::default();
+        let (client, agreement) = setup(&env);
+
+        let review_id = submit(&env, &client, &agreement.tenant, &agreement, 1);
+        assert_eq!(
+            client.get_removal_reason(&review_id),
+            String::from_str(&env, "")
+        );
+
+        let reason = String::from_str(&env, "Spam");
+        client.admin_remove_review(&review_id, &reason);
+        assert!(client.get_review(&review_id).is_removed);
+        assert_eq!(client.get_removal_reason(&review_id), reason);
+        assert!(client.get_reviews_for_agreement(&agreement.id).is_empty());
+        assert!(client
+            .batch_get_reviews(&Vec::from_array(&env, [review_id]))
+            .is_empty());
+        assert!(client
+            .get_reviews_received_by_user(&agreement.landlord)
+            .is_empty());
+        assert_eq!(client.get_platform_review_stats().total_reviews, 0);
+        assert_eq!(client.get_review_activity_since(&0), 1);
+    }
+
+    #[test]
+    fn average_rating_skips_removed_reviews() {
+        let env = Env::default();
+        let (client, agreements, agreement) = setup_with_agreements(&env);
+        let tenant = agreement.tenant.clone();
+
+        submit(&env, &client, &tenant, &agreement, 3);
+        let second = copy_agreement(&env, &agreements, &agreement, 3);
+        submit(&env, &client, &tenant, &second, 4);
+        let third = copy_agreement(&env, &agreements, &agreement, 4);
+        let spam = submit(&env, &client, &tenant, &third, 1);
+        assert_eq!(
+            client.get_average_rating_for_user(&agreement.landlord),
+            AverageRating {
+                total_rating: 8,
+                review_count: 3,
+                average_rating_scaled: 266,
+            }
+        );
+
+        client.admin_remove_review(&spam, &String::from_str(&env, "Spam"));
+        let average = client.get_average_rating_for_user(&agreement.landlord);
+        assert_eq!(
+            (average.review_count, average.average_rating_scaled),
+            (2, 350)
+        );
+    }
+
+    #[test]
+    fn sub_rating_averages_skip_reviews_without_them() {
+        let env = Env::default();
+        let (client, agreements, agreement) = setup_with_agreements(&env);
+        let tenant = agreement.tenant.clone();
+        let text = String::from_str(&env, "Would rent here again");
+        let subs = SubRatings {
+            cleanliness: 5,
+            communication: 4,
+            location: 3,
+            value_for_money: 4,
+            maintenance: 2,
+        };
+
+        let rated = client.submit_review(
+            &tenant,
+            &agreement.id,
+            &4,
+            &text,
+            &Some(subs.clone()),
+            &None,
+        );
+        assert_eq!(client.get_review_sub_ratings(&rated), Some(subs.clone()));
+        let second = copy_agreement(&env, &agreements, &agreement, 3);
+        let plain = submit(&env, &client, &tenant, &second, 2);
+        assert_eq!(client.get_review_sub_ratings(&plain), None);
+        let third = copy_agreement(&env, &agreements, &agreement, 4);
+        let mut better = subs.clone();
+        better.maintenance = 5;
+        client.submit_review(&tenant, &third.id, &5, &text, &Some(better), &None);
+
+        assert_eq!(
+            client.get_sub_rating_averages_for_user(&agreement.landlord),
+            AverageSubRatings {
+                review_count: 2,
+                cleanliness: 500,
+                communication: 400,
+                location: 300,
+                value_for_money: 400,
+                maintenance: 350,
+            }
+        );
+
+        let fourth = copy_agreement(&env, &agreements, &agreement, 5);
+        let mut invalid = subs;
+        invalid.location = 6;
+        assert_eq!(
+            client.try_submit_review(&tenant, &fourth.id, &4, &text, &Some(invalid), &None),
+            Err(Ok(Error::InvalidRating.into()))
+        );
+    }
+
+    #[test]
+    fn reviews_with_three_flags_are_listed() {
+        let env = Env::default();
+        let (client, agreement) = setup(&env);
+
+        let review_id = submit(&env, &client, &agreement.tenant, &agreement, 1);
+        let reason = String::from_str(&env, "Fake");
+        let first = Address::generate(&env);
+        client.flag_review(&first, &review_id, &reason);
+        assert_eq!(
+            client.try_flag_review(&first, &review_id, &reason),
+            Err(Ok(Error::AlreadyFlagged.into()))
+        );
+        client.flag_review(&Address::generate(&env), &review_id, &reason);
+        assert!(client.get_flagged_reviews().is_empty());
+
+        client.flag_review(&Address::generate(&env), &review_id, &reason);
+        assert_eq!(client.get_review_flags(&review_id).len(), FLAG_THRESHOLD);
+        assert_eq!(
+            client.get_flagged_reviews(),
+            Vec::from_array(&env, [review_id.clone()])
+        );
+
+        // A fourth flag doesn't list it twice; removal clears it.
+        client.flag_review(&Address::generate(&env), &review_id, &reason);
+        assert_eq!(client.get_flagged_reviews().len(), 1);
+        client.admin_remove_review(&review_id, &reason);
+        assert!(client.get_flagged_reviews().is_empty());
+    }
+
+    #[test]
+    fn reviews_close_after_the_window() {
+        let env = Env::default();
+        let (client, agreement) = setup(&env);
+        let window_end = agreement.end_date + DEFAULT_REVIEW_WINDOW_DAYS as u64 * SECONDS_PER_DAY;
+
+        env.ledger().with_mut(|l| l.timestamp = window_end);
+        assert_eq!(
+            client.try_close_review_window(&agreement.id),
+            Err(Ok(Error::NotEligibleYet.into()))
+        );
+        submit(&env, &client, &agreement.tenant, &agreement, 4);
+
+        env.ledger().with_mut(|l| l.timestamp = window_end + 1);
+        client.close_review_window(&agreement.id);
+        assert_eq!(
+            client.try_submit_review(
+                &agreement.landlord,
+                &agreement.id,
+                &4,
+                &String::from_str(&env, "Would rent here again"),
+                &None,
+                &None,
+            ),
+            Err(Ok(Error::ReviewWindowClosed.into()))
+        );
+
+        client.extend_review_window(&agreement.id, &SECONDS_PER_DAY);
+        submit(&env, &client, &agreement.landlord, &agreement, 4);
+    }
+
+    #[test]
+    fn attachment_hash_is_optional() {
+        let env = Env::default();
+        let (client, agreement) = setup(&env);
+
+        let hash = BytesN::from_array(&env, &[9; 32]);
+        let with_hash = client.submit_review(
+            &agreement.tenant,
+            &agreement.id,
+            &4,
+            &String::from_str(&env, "Would rent here again"),
+            &None,
+            &Some(hash.clone()),
+        );
+        assert_eq!(client.get_review_attachment(&with_hash), Some(hash));
+
+        let without = submit(&env, &client, &agreement.landlord, &agreement, 4);
+        assert_eq!(client.get_review_attachment(&without), None);
+    }
+
+    #[test]
+    fn review_counts_skip_removed_reviews() {
+        let env = Env::default();
+        let (client, agreements, agreement) = setup_with_agreements(&env);
+        let tenant = agreement.tenant.clone();
+        let landlord = agreement.landlord.clone();
+
+        submit(&env, &client, &tenant, &agreement, 5);
+        let second_agreement = copy_agreement(&env, &agreements, &agreement, 3);
+        let second = submit(&env, &client, &tenant, &second_agreement, 2);
+        submit(&env, &client, &landlord, &agreement, 4);
+        assert_eq!(client.get_review_count_for_user(&tenant), 2);
+        assert_eq!(client.get_received_review_count(&landlord), 2);
+        assert_eq!(client.get_received_review_count(&tenant), 1);
+
+        client.admin_remove_review(&second, &String::from_str(&env, "Spam"));
+        // Removing twice must not count twice.
+        client.admin_remove_review(&second, &String::from_str(&env, "Spam"));
+        assert_eq!(client.get_review_count_for_user(&tenant), 1);
+        assert_eq!(client.get_received_review_count(&landlord), 1);
+        assert_eq!(client.get_received_review_count(&tenant), 1);
+    }
+
+    #[test]
+    fn given_and_received_reviews_are_kept_apart() {
+        let env = Env::default();
+        let (client, agreement) = setup(&env);
+        let tenant = agreement.tenant.clone();
+        let landlord = agreement.landlord.clone();
+
+        let by_tenant = submit(&env, &client, &tenant, &agreement, 5);
+        let by_landlord = submit(&env, &client, &landlord, &agreement, 3);
+
+        let given = client.get_reviews_given_by_user(&tenant);
+        assert_eq!(given.len(), 1);
+        assert_eq!(given.get_unchecked(0).id, by_tenant);
+        let received = client.get_reviews_received_by_user(&tenant);
+        assert_eq!(received.len(), 1);
+        assert_eq!(received.get_unchecked(0).id, by_landlord);
+        assert_eq!(
+            client
+                .get_reviews_received_by_user(&landlord)
+                .get_unchecked(0)
+                .id,
+            by_tenant
+        );
+        // The deprecated query still returns what the user wrote.
+        assert_eq!(client.get_reviews_by_user(&tenant), given);
+
+        client.admin_remove_review(&by_landlord, &String::from_str(&env, "Spam"));
+        assert!(client.get_reviews_received_by_user(&tenant).is_empty());
+        assert!(client.get_reviews_given_by_user(&landlord).is_empty());
+    }
+
+    #[test]
+    fn verification_follows_the_agreement() {
+        let env = Env::default();
+        let (client, agreements, agreement) = setup_with_agreements(&env);
+
+        let review_id = submit(&env, &client, &agreement.tenant, &agreement, 4);
+        assert_eq!(
+            client.verify_review_authenticity(&review_id),
+            ReviewVerification {
+                agreement_exists: true,
+                reviewer_was_party: true,
+                agreement_active_or_completed: true,
+                within_eligibility_window: true,
+                overall_authentic: true,
+            }
+        );
+
+        let mut cancelled = agreement.clone();
+        cancelled.status = AgreementStatus::Cancelled;
+        agreements.set_agreement(&cancelled);
+        let verification = client.verify_review_authenticity(&review_id);
+        assert!(verification.reviewer_was_party);
+        assert!(!verification.agreement_active_or_completed);
+        assert!(!verification.overall_authentic);
+    }
 }
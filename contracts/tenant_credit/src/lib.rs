@@ -0,0 +1,263 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, Address, BytesN, Env,
+    Symbol,
+};
+
+// -----------------------------
+// TenantCredit contract
+// -----------------------------
+
+const BASE_SCORE: i64 = 500;
+const MAX_SCORE: i64 = 1000;
+const ON_TIME_WEIGHT: i64 = 10;
+const LATE_WEIGHT: i64 = 20;
+const VIOLATION_WEIGHT: i64 = 50;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    ContractPaused = 1,
+    Unauthorized = 2,
+    AlreadyInitialized = 3,
+}
+
+/// `credit_score` runs from 0 to 1000; a tenant with no history scores 500.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreditProfile {
+    pub tenant: Address,
+    pub total_agreements: u32,
+    pub completed_agreements: u32,
+    pub cancelled_agreements: u32,
+    pub on_time_payments: u32,
+    pub late_payments: u32,
+    pub violations: u32,
+    pub credit_score: u32,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Paused,
+    EscrowContract,
+    AgreementContract,
+    Profile(Address),
+}
+
+#[contract]
+pub struct TenantCredit;
+
+#[contractimpl]
+impl TenantCredit {
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        agreement_contract: Address,
+        escrow_contract: Address,
+    ) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.storage()
+            .instance()
+            .set(&DataKey::AgreementContract, &agreement_contract);
+        env.storage()
+            .instance()
+            .set(&DataKey::EscrowContract, &escrow_contract);
+
+        env.events().publish(
+            (Symbol::new(&env, "Initialized"),),
+            (
+                admin,
+                agreement_contract,
+                escrow_contract,
+                env.ledger().timestamp(),
+            ),
+        );
+    }
+
+    pub fn pause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.events()
+            .publish((Symbol::new(&env, "Paused"),), env.ledger().timestamp());
+    }
+
+    pub fn unpause(env: Env) {
+        let admin = Self::require_admin(&env);
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.events()
+            .publish((Symbol::new(&env, "Unpaused"),), env.ledger().timestamp());
+    }
+
+    // Called by EscrowManager when rent is paid on schedule.
+    pub fn update_on_time_payment(env: Env, agreement_id: BytesN<32>, tenant: Address) {
+        Self::check_not_paused(&env);
+        Self::require_caller(&env, DataKey::EscrowContract);
+
+        let mut profile = Self::get_credit_profile(env.clone(), tenant);
+        profile.on_time_payments += 1;
+        Self::save_profile(&env, &mut profile, agreement_id);
+    }
+
+    // Called by EscrowManager when rent is paid behind schedule.
+    pub fn update_late_payment(env: Env, agreement_id: BytesN<32>, tenant: Address) {
+        Self::check_not_paused(&env);
+        Self::require_caller(&env, DataKey::EscrowContract);
+
+        let mut profile = Self::get_credit_profile(env.clone(), tenant);
+        profile.late_payments += 1;
+        Self::save_profile(&env, &mut profile, agreement_id);
+    }
+
+    // Called by RentalAgreement.
+    pub fn update_violation(env: Env, agreement_id: BytesN<32>, tenant: Address) {
+        Self::check_not_paused(&env);
+        Self::require_caller(&env, DataKey::AgreementContract);
+
+        let mut profile = Self::get_credit_profile(env.clone(), tenant);
+        profile.violations += 1;
+        Self::save_profile(&env, &mut profile, agreement_id);
+    }
+
+    // Called by RentalAgreement when an agreement ends. Agreements count
+    // towards `total_agreements` once they are completed or cancelled.
+    pub fn update_agreement_outcome(
+        env: Env,
+        agreement_id: BytesN<32>,
+        tenant: Address,
+        completed: bool,
+    ) {
+        Self::check_not_paused(&env);
+        Self::require_caller(&env, DataKey::AgreementContract);
+
+        let mut profile = Self::get_credit_profile(env.clone(), tenant);
+        profile.total_agreements += 1;
+        if completed {
+            profile.completed_agreements += 1;
+        } else {
+            profile.cancelled_agreements += 1;
+        }
+        Self::save_profile(&env, &mut profile, agreement_id);
+    }
+
+    pub fn get_credit_score(env: Env, tenant: Address) -> u32 {
+        Self::get_credit_profile(env, tenant).credit_score
+    }
+
+    pub fn get_credit_profile(env: Env, tenant: Address) -> CreditProfile {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Profile(tenant.clone()))
+            .unwrap_or(CreditProfile {
+                tenant,
+                total_agreements: 0,
+                completed_agreements: 0,
+                cancelled_agreements: 0,
+                on_time_payments: 0,
+                late_payments: 0,
+                violations: 0,
+                credit_score: BASE_SCORE as u32,
+            })
+    }
+
+    // -----------------------------
+    // Internal helpers
+    // -----------------------------
+
+    // base 500 + 10 per on-time payment - 20 per late payment - 50 per violation
+    fn score(profile: &CreditProfile) -> u32 {
+        let score = BASE_SCORE + profile.on_time_payments as i64 * ON_TIME_WEIGHT
+            - profile.late_payments as i64 * LATE_WEIGHT
+            - profile.violations as i64 * VIOLATION_WEIGHT;
+        score.clamp(0, MAX_SCORE) as u32
+    }
+
+    fn save_profile(env: &Env, profile: &mut CreditProfile, agreement_id: BytesN<32>) {
+        profile.credit_score = Self::score(profile);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Profile(profile.tenant.clone()), profile);
+
+        env.events().publish(
+            (Symbol::new(env, "CreditScoreUpdated"),),
+            (profile.tenant.clone(), agreement_id, profile.credit_score),
+        );
+    }
+
+    fn require_caller(env: &Env, key: DataKey) {
+        let caller: Address = env
+            .storage()
+            .instance()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+        caller.require_auth();
+    }
+
+    fn check_not_paused(env: &Env) {
+        let paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        if paused {
+            panic_with_error!(env, Error::ContractPaused);
+        }
+    }
+
+    fn require_admin(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn score_follows_payment_history() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, TenantCredit);
+        let client = TenantCreditClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let agreement_contract = Address::generate(&env);
+        let escrow_contract = Address::generate(&env);
+        client.initialize(&admin, &agreement_contract, &escrow_contract);
+
+        let tenant = Address::generate(&env);
+        let agreement_id = BytesN::from_array(&env, &[1; 32]);
+        assert_eq!(client.get_credit_score(&tenant), 500);
+
+        client.update_on_time_payment(&agreement_id, &tenant);
+        client.update_on_time_payment(&agreement_id, &tenant);
+        client.update_late_payment(&agreement_id, &tenant);
+        assert_eq!(client.get_credit_score(&tenant), 500);
+
+        for _ in 0..10 {
+            client.update_violation(&agreement_id, &tenant);
+        }
+        assert_eq!(client.get_credit_score(&tenant), 0);
+
+        client.update_agreement_outcome(&agreement_id, &tenant, &true);
+        let profile = client.get_credit_profile(&tenant);
+        assert_eq!(profile.total_agreements, 1);
+        assert_eq!(profile.completed_agreements, 1);
+        assert_eq!(profile.violations, 10);
+    }
+}
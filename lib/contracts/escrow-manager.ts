@@ -101,25 +101,73 @@ export async function buildPayRentTx(
 }
 
 /**
- * Build transaction to release security deposit back to tenant
- * Only works after agreement is completed
+ * Build transaction to request the security deposit be released to tenant
+ * Either party can request once the agreement is completed
  */
-export async function buildReleaseDepositTx(
-  callerAddress: string,
+export async function buildRequestDepositReleaseTx(
+  requesterAddress: string,
   agreementId: string,
 ): Promise<StellarSdk.Transaction> {
   const agreementIdBytes = Buffer.from(agreementId, "hex");
 
   const args = [
-    new StellarSdk.Address(callerAddress).toScVal(),
+    new StellarSdk.Address(requesterAddress).toScVal(),
     StellarSdk.nativeToScVal(agreementIdBytes, { type: "bytes" }),
   ];
 
+  const transaction = await buildContractCall(
+    CONTRACT_ID,
+    "request_deposit_release",
+    args,
+    requesterAddress,
+  );
+
+  return transaction;
+}
+
+/**
+ * Build transaction to confirm a pending deposit release request
+ * Must be signed by the party that did not request it; releases the deposit
+ */
+export async function buildConfirmDepositReleaseTx(
+  confirmerAddress: string,
+  agreementId: string,
+): Promise<StellarSdk.Transaction> {
+  const agreementIdBytes = Buffer.from(agreementId, "hex");
+
+  const args = [
+    new StellarSdk.Address(confirmerAddress).toScVal(),
+    StellarSdk.nativeToScVal(agreementIdBytes, { type: "bytes" }),
+  ];
+
+  const transaction = await buildContractCall(
+    CONTRACT_ID,
+    "confirm_deposit_release",
+    args,
+    confirmerAddress,
+  );
+
+  return transaction;
+}
+
+/**
+ * Build transaction to release security deposit back to tenant without the
+ * other party's confirmation. Admin-only override for dispute resolution;
+ * the normal path is buildRequestDepositReleaseTx + buildConfirmDepositReleaseTx
+ */
+export async function buildAdminReleaseDepositTx(
+  adminAddress: string,
+  agreementId: string,
+): Promise<StellarSdk.Transaction> {
+  const agreementIdBytes = Buffer.from(agreementId, "hex");
+
+  const args = [StellarSdk.nativeToScVal(agreementIdBytes, { type: "bytes" })];
+
   const transaction = await buildContractCall(
     CONTRACT_ID,
     "release_deposit_to_tenant",
     args,
-    callerAddress,
+    adminAddress,
   );
 
   return transaction;
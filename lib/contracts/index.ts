@@ -34,7 +34,9 @@ export { PaymentType } from "./escrow-manager";
 export {
   buildDepositAndRentTx,
   buildPayRentTx,
-  buildReleaseDepositTx,
+  buildRequestDepositReleaseTx,
+  buildConfirmDepositReleaseTx,
+  buildAdminReleaseDepositTx,
   getEscrowAccount,
   getPaymentHistory,
 } from "./escrow-manager";
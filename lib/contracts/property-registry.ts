@@ -5,6 +5,21 @@ import {
   simulateContractCall,
 } from "./stellar-client";
 
+export type PropertyType =
+  | "Apartment"
+  | "House"
+  | "Condo"
+  | "Studio"
+  | "Commercial"
+  | "Other";
+
+export type PetPolicy =
+  | "NoPets"
+  | "CatsOnly"
+  | "SmallDogsOnly"
+  | "AllPets"
+  | "NegotiableCase";
+
 // Property type matching the Soroban contract struct
 export interface Property {
   id: string; // hex string of BytesN<32>
@@ -17,6 +32,15 @@ export interface Property {
   minStayDays: number;
   maxStayDays: number;
   imageUrl: string;
+  amenities: string[];
+  propertyType: PropertyType;
+  latitude: number; // microdegrees
+  longitude: number;
+  floorAreaSqft: number; // 0 = unknown
+  furnished: boolean;
+  parkingSpaces: number;
+  petPolicy: PetPolicy;
+  isVerified: boolean;
   isAvailable: boolean;
   isActive: boolean;
   createdAt: number; // unix timestamp
@@ -46,6 +70,16 @@ function parseProperty(scVal: StellarSdk.xdr.ScVal): Property {
     minStayDays: StellarSdk.scValToNative(getField("min_stay_days")!),
     maxStayDays: StellarSdk.scValToNative(getField("max_stay_days")!),
     imageUrl: StellarSdk.scValToNative(getField("image_url")!),
+    amenities: StellarSdk.scValToNative(getField("amenities")!) ?? [],
+    // Unit enum variants decode as a single-element vec: ["Apartment"]
+    propertyType: StellarSdk.scValToNative(getField("property_type")!)[0],
+    latitude: Number(StellarSdk.scValToNative(getField("latitude")!)),
+    longitude: Number(StellarSdk.scValToNative(getField("longitude")!)),
+    floorAreaSqft: StellarSdk.scValToNative(getField("floor_area_sqft")!),
+    furnished: StellarSdk.scValToNative(getField("furnished")!),
+    parkingSpaces: StellarSdk.scValToNative(getField("parking_spaces")!),
+    petPolicy: StellarSdk.scValToNative(getField("pet_policy")!)[0],
+    isVerified: StellarSdk.scValToNative(getField("is_verified")!),
     isAvailable: StellarSdk.scValToNative(getField("is_available")!),
     isActive: StellarSdk.scValToNative(getField("is_active")!),
     createdAt: Number(StellarSdk.scValToNative(getField("created_at")!)),
@@ -53,6 +87,75 @@ function parseProperty(scVal: StellarSdk.xdr.ScVal): Property {
   };
 }
 
+/**
+ * Encode the PropertyDetails struct argument. Soroban structs are maps keyed by
+ * field name, and entries must stay sorted by key.
+ */
+function encodePropertyDetails(details: {
+  imageUrl: string;
+  amenities: string[];
+  propertyType: PropertyType;
+  latitude: number;
+  longitude: number;
+  floorAreaSqft: number;
+  furnished: boolean;
+  parkingSpaces: number;
+  petPolicy: PetPolicy;
+}): StellarSdk.xdr.ScVal {
+  const entry = (key: string, val: StellarSdk.xdr.ScVal) =>
+    new StellarSdk.xdr.ScMapEntry({
+      key: StellarSdk.nativeToScVal(key, { type: "symbol" }),
+      val,
+    });
+
+  return StellarSdk.xdr.ScVal.scvMap([
+    entry(
+      "amenities",
+      StellarSdk.xdr.ScVal.scvVec(
+        details.amenities.map((a) =>
+          StellarSdk.nativeToScVal(a, { type: "string" }),
+        ),
+      ),
+    ),
+    entry(
+      "floor_area_sqft",
+      StellarSdk.nativeToScVal(details.floorAreaSqft, { type: "u32" }),
+    ),
+    entry(
+      "furnished",
+      StellarSdk.nativeToScVal(details.furnished, { type: "bool" }),
+    ),
+    entry(
+      "image_url",
+      StellarSdk.nativeToScVal(details.imageUrl, { type: "string" }),
+    ),
+    entry(
+      "latitude",
+      StellarSdk.nativeToScVal(details.latitude, { type: "i64" }),
+    ),
+    entry(
+      "longitude",
+      StellarSdk.nativeToScVal(details.longitude, { type: "i64" }),
+    ),
+    entry(
+      "parking_spaces",
+      StellarSdk.nativeToScVal(details.parkingSpaces, { type: "u32" }),
+    ),
+    entry(
+      "pet_policy",
+      StellarSdk.xdr.ScVal.scvVec([
+        StellarSdk.nativeToScVal(details.petPolicy, { type: "symbol" }),
+      ]),
+    ),
+    entry(
+      "property_type",
+      StellarSdk.xdr.ScVal.scvVec([
+        StellarSdk.nativeToScVal(details.propertyType, { type: "symbol" }),
+      ]),
+    ),
+  ]);
+}
+
 /**
  * Get all available properties (read-only)
  */
@@ -122,6 +225,14 @@ export async function buildCreatePropertyTx(
     minStayDays: number;
     maxStayDays: number;
     imageUrl: string;
+    amenities?: string[];
+    propertyType?: PropertyType;
+    latitude?: number;
+    longitude?: number;
+    floorAreaSqft?: number;
+    furnished?: boolean;
+    parkingSpaces?: number;
+    petPolicy?: PetPolicy;
   },
 ): Promise<{ transaction: StellarSdk.Transaction; propertyId: Uint8Array }> {
   // Generate 32-byte random property ID client-side
@@ -144,7 +255,17 @@ export async function buildCreatePropertyTx(
     StellarSdk.nativeToScVal(params.securityDeposit, { type: "i128" }),
     StellarSdk.nativeToScVal(params.minStayDays, { type: "u32" }),
     StellarSdk.nativeToScVal(params.maxStayDays, { type: "u32" }),
-    StellarSdk.nativeToScVal(params.imageUrl, { type: "string" }),
+    encodePropertyDetails({
+      imageUrl: params.imageUrl,
+      amenities: params.amenities ?? [],
+      propertyType: params.propertyType ?? "Apartment",
+      latitude: params.latitude ?? 0,
+      longitude: params.longitude ?? 0,
+      floorAreaSqft: params.floorAreaSqft ?? 0,
+      furnished: params.furnished ?? false,
+      parkingSpaces: params.parkingSpaces ?? 0,
+      petPolicy: params.petPolicy ?? "NoPets",
+    }),
   ];
 
   const transaction = await buildContractCall(
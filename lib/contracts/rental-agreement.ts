@@ -20,6 +20,7 @@ export enum AgreementStatus {
   Draft = "Draft",
   PendingTenantSign = "PendingTenantSign",
   PendingLandlordSign = "PendingLandlordSign",
+  PendingCoSignerSign = "PendingCoSignerSign",
   PendingPayment = "PendingPayment",
   Active = "Active",
   Completed = "Completed",